@@ -0,0 +1,85 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Query, State, rejection::QueryRejection},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use sea_orm::{DatabaseConnection, prelude::DateTimeWithTimeZone};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth,
+        response::types::{audit_event::AuditEvent, page::Page},
+        state::types::app::AppState,
+        types::{
+            audit::{AuditEventType, AuditOutcome},
+            realm::Realm,
+        },
+    },
+    db::repo::audit_events::{AuditEventFilter, AuditEventsRepo},
+    utils::error::AllForOneError,
+};
+
+const DEFAULT_PER_PAGE: u64 = 20;
+const MAX_PER_PAGE: u64 = 100;
+
+#[derive(Deserialize, Debug)]
+struct ListAuditEventsQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub event_type: Option<AuditEventType>,
+    pub outcome: Option<AuditOutcome>,
+    pub actor_id: Option<Uuid>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    /// RFC 3339. 이 시각을 포함
+    pub from: Option<DateTimeWithTimeZone>,
+    /// RFC 3339. 이 시각은 제외
+    pub to: Option<DateTimeWithTimeZone>,
+}
+
+/// 최근 이벤트부터. `page` 는 1 부터 시작
+async fn list_audit_events(
+    _: AdminAuth,
+    query: Result<Query<ListAuditEventsQuery>, QueryRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Query(query) = query?;
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(DEFAULT_PER_PAGE)
+        .clamp(1, MAX_PER_PAGE);
+    let filter = AuditEventFilter {
+        event_type: query.event_type,
+        outcome: query.outcome,
+        actor_id: query.actor_id,
+        client_id: query.client_id,
+        ip_address: query.ip_address,
+        from: query.from,
+        to: query.to,
+    };
+
+    let (events, total) = AuditEventsRepo::new(db_client.as_ref())
+        .list_audit_events(&realm.name, &filter, page - 1, per_page)
+        .await?;
+
+    Ok(Json(Page {
+        items: events.into_iter().map(AuditEvent::from).collect(),
+        page,
+        per_page,
+        total,
+    })
+    .into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_audit_events))
+        .with_state(app_state)
+}
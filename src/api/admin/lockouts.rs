@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State, rejection::PathRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth,
+        response::types::lockout::Lockout,
+        state::types::{app::AppState, lockout_tracker::LockoutTracker},
+    },
+    utils::error::AllForOneError,
+};
+
+/// 잠겨 있는 계정과 클라이언트
+async fn list_lockouts(
+    _: AdminAuth,
+    State(lockout_tracker): State<Arc<LockoutTracker>>,
+) -> Result<Response, AllForOneError> {
+    let lockouts = lockout_tracker
+        .list()
+        .await?
+        .into_iter()
+        .map(|(subject, record)| Lockout::new(subject, record))
+        .collect::<Vec<_>>();
+    Ok(Json(lockouts).into_response())
+}
+
+/// 잠기기 전의 실패 횟수와 지연도 볼 수 있음
+async fn get_lockout(
+    _: AdminAuth,
+    path: Result<Path<String>, PathRejection>,
+    State(lockout_tracker): State<Arc<LockoutTracker>>,
+) -> Result<Response, AllForOneError> {
+    let Path(subject) = path?;
+    let record = lockout_tracker
+        .get(&subject)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("lockout is not found".to_string()))?;
+    Ok(Json(Lockout::new(subject, record)).into_response())
+}
+
+async fn clear_lockout(
+    _: AdminAuth,
+    path: Result<Path<String>, PathRejection>,
+    State(lockout_tracker): State<Arc<LockoutTracker>>,
+) -> Result<Response, AllForOneError> {
+    let Path(subject) = path?;
+    lockout_tracker.clear(&subject).await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_lockouts))
+        .route("/{subject}", get(get_lockout).delete(clear_lockout))
+        .with_state(app_state)
+}
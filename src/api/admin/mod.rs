@@ -0,0 +1,23 @@
+use axum::Router;
+
+use crate::api::state::types::app::AppState;
+
+mod audit_events;
+mod lockouts;
+mod permissions;
+mod roles;
+mod users;
+mod webhooks;
+
+pub async fn router(app_state: AppState) -> Router {
+    Router::new()
+        .nest("/users", users::router(app_state.clone()).await)
+        .nest("/roles", roles::router(app_state.clone()).await)
+        .nest("/permissions", permissions::router(app_state.clone()).await)
+        .nest("/lockouts", lockouts::router(app_state.clone()).await)
+        .nest(
+            "/audit-events",
+            audit_events::router(app_state.clone()).await,
+        )
+        .nest("/webhooks", webhooks::router(app_state).await)
+}
@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth, response::types::role::Permission,
+        state::types::app::AppState, types::realm::Realm,
+    },
+    db::repo::permissions::PermissionsRepo,
+    utils::error::AllForOneError,
+};
+
+async fn list_permissions(
+    _: AdminAuth,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let permissions = PermissionsRepo::new(db_client.as_ref())
+        .list_permissions(&realm.name)
+        .await?
+        .into_iter()
+        .map(Permission::from)
+        .collect::<Vec<_>>();
+    Ok(Json(permissions).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct CreatePermissionBody {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+async fn create_permission(
+    _: AdminAuth,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    body: Result<Json<CreatePermissionBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Json(body) = body?;
+    let name = body.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AllForOneError::BadRequest(
+            "permission name cannot be empty".to_string(),
+        ));
+    }
+
+    let permissions_repo = PermissionsRepo::new(db_client.as_ref());
+    if permissions_repo
+        .get_permission_by_name(&realm.name, &name)
+        .await?
+        .is_some()
+    {
+        return Err(AllForOneError::Conflict(format!(
+            "permission {} already exists",
+            name
+        )));
+    }
+
+    let permission = permissions_repo
+        .create_permission(&realm.name, name, body.description)
+        .await?;
+    Ok((StatusCode::CREATED, Json(Permission::from(permission))).into_response())
+}
+
+async fn delete_permission(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(permission_id) = path?;
+
+    let deleted = PermissionsRepo::new(db_client.as_ref())
+        .delete_permission(&realm.name, permission_id)
+        .await?;
+    if deleted == 0 {
+        return Err(AllForOneError::NotFound(
+            "permission is not found".to_string(),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_permissions).post(create_permission))
+        .route("/{id}", delete(delete_permission))
+        .with_state(app_state)
+}
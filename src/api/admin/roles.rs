@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, put},
+};
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth,
+        response::types::role::{Permission, Role},
+        state::types::app::AppState,
+        types::realm::Realm,
+    },
+    db::repo::{permissions::PermissionsRepo, roles::RolesRepo},
+    utils::error::AllForOneError,
+};
+
+async fn list_roles(
+    _: AdminAuth,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let roles = RolesRepo::new(db_client.as_ref())
+        .list_roles(&realm.name)
+        .await?
+        .into_iter()
+        .map(Role::from)
+        .collect::<Vec<_>>();
+    Ok(Json(roles).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct CreateRoleBody {
+    pub name: String,
+    pub audience: Option<String>,
+    pub description: Option<String>,
+}
+
+/// 같은 realm 과 audience 안에서 역할 이름은 유일
+async fn create_role(
+    _: AdminAuth,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    body: Result<Json<CreateRoleBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Json(body) = body?;
+    let name = body.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AllForOneError::BadRequest(
+            "role name cannot be empty".to_string(),
+        ));
+    }
+
+    let roles_repo = RolesRepo::new(db_client.as_ref());
+    if roles_repo
+        .get_role_by_name_and_audience(&realm.name, &name, body.audience.as_deref())
+        .await?
+        .is_some()
+    {
+        return Err(AllForOneError::Conflict(format!(
+            "role {} already exists",
+            name
+        )));
+    }
+
+    let role = roles_repo
+        .create_role(&realm.name, name, body.audience, body.description)
+        .await?;
+    Ok((StatusCode::CREATED, Json(Role::from(role))).into_response())
+}
+
+async fn delete_role(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(role_id) = path?;
+
+    let deleted = RolesRepo::new(db_client.as_ref())
+        .delete_role(&realm.name, role_id)
+        .await?;
+    if deleted == 0 {
+        return Err(AllForOneError::NotFound("role is not found".to_string()));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn list_role_permissions(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(role_id) = path?;
+    let roles_repo = RolesRepo::new(db_client.as_ref());
+    roles_repo
+        .get_role_by_id(&realm.name, role_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("role is not found".to_string()))?;
+
+    let permissions = roles_repo
+        .list_permissions_by_role_id(&realm.name, role_id)
+        .await?
+        .into_iter()
+        .map(Permission::from)
+        .collect::<Vec<_>>();
+    Ok(Json(permissions).into_response())
+}
+
+async fn grant_permission(
+    _: AdminAuth,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path((role_id, permission_id)) = path?;
+    let db_client = db_client.as_ref();
+    let roles_repo = RolesRepo::new(db_client);
+
+    roles_repo
+        .get_role_by_id(&realm.name, role_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("role is not found".to_string()))?;
+    PermissionsRepo::new(db_client)
+        .get_permission_by_id(&realm.name, permission_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("permission is not found".to_string()))?;
+
+    roles_repo.grant_permission(role_id, permission_id).await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn revoke_permission(
+    _: AdminAuth,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path((role_id, permission_id)) = path?;
+    let roles_repo = RolesRepo::new(db_client.as_ref());
+    roles_repo
+        .get_role_by_id(&realm.name, role_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("role is not found".to_string()))?;
+
+    let revoked = roles_repo.revoke_permission(role_id, permission_id).await?;
+    if revoked == 0 {
+        return Err(AllForOneError::NotFound(
+            "permission is not granted to role".to_string(),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_roles).post(create_role))
+        .route("/{id}", delete(delete_role))
+        .route("/{id}/permissions", get(list_role_permissions))
+        .route(
+            "/{id}/permissions/{permission_id}",
+            put(grant_permission).delete(revoke_permission),
+        )
+        .with_state(app_state)
+}
@@ -0,0 +1,352 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, Query, State,
+        rejection::{JsonRejection, PathRejection, QueryRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+};
+use sea_orm::{
+    ConnectionTrait, DatabaseConnection, TransactionTrait, prelude::DateTimeWithTimeZone,
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth,
+        response::types::{identity::Identity, page::Page, role::Role, user::User},
+        state::types::{app::AppState, logout_notifier::LogoutNotifier},
+        types::realm::Realm,
+    },
+    db::repo::{
+        refresh_tokens::RefreshTokensRepo, roles::RolesRepo, user_identities::UserIdentitiesRepo,
+        users::UsersRepo,
+    },
+    entity::users,
+    provider::types::idp::OAuthProvider,
+    utils::error::AllForOneError,
+};
+
+const DEFAULT_PER_PAGE: u64 = 20;
+const MAX_PER_PAGE: u64 = 100;
+
+#[derive(Deserialize, Debug)]
+struct ListUsersQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub q: Option<String>,
+}
+
+/// `page` 는 1 부터 시작
+async fn list_users(
+    _: AdminAuth,
+    query: Result<Query<ListUsersQuery>, QueryRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Query(query) = query?;
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(DEFAULT_PER_PAGE)
+        .clamp(1, MAX_PER_PAGE);
+    let search = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+
+    let (users, total) = UsersRepo::new(db_client.as_ref())
+        .list_users(&realm.name, page - 1, per_page, search)
+        .await?;
+
+    Ok(Json(Page {
+        items: users.into_iter().map(User::from).collect(),
+        page,
+        per_page,
+        total,
+    })
+    .into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct LookupUserQuery {
+    pub email: Option<String>,
+    pub idp: Option<OAuthProvider>,
+    pub idp_uid: Option<String>,
+}
+
+async fn lookup_user(
+    _: AdminAuth,
+    query: Result<Query<LookupUserQuery>, QueryRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Query(query) = query?;
+    let db_client = db_client.as_ref();
+
+    let user = match (query.email, query.idp, query.idp_uid) {
+        (Some(email), None, None) => {
+            UsersRepo::new(db_client)
+                .get_user_by_email(&realm.name, &email)
+                .await?
+        }
+        (None, Some(idp), Some(idp_uid)) => {
+            match UserIdentitiesRepo::new(db_client)
+                .get_identity_by_idp_and_idp_uid(&realm.name, idp, idp_uid)
+                .await?
+            {
+                Some(identity) => {
+                    UsersRepo::new(db_client)
+                        .get_user_by_id(identity.user_id)
+                        .await?
+                }
+                None => None,
+            }
+        }
+        _ => {
+            return Err(AllForOneError::BadRequest(
+                "lookup requires either email or idp with idp_uid".to_string(),
+            ));
+        }
+    };
+
+    let user = user.ok_or_else(|| AllForOneError::NotFound("user is not found".to_string()))?;
+    Ok(Json(User::from(user)).into_response())
+}
+
+async fn get_user(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let user = find_user(db_client.as_ref(), &realm, user_id).await?;
+    Ok(Json(User::from(user)).into_response())
+}
+
+async fn list_user_identities(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let db_client = db_client.as_ref();
+    find_user(db_client, &realm, user_id).await?;
+
+    let identities = UserIdentitiesRepo::new(db_client)
+        .list_identities_by_user_id(user_id)
+        .await?
+        .into_iter()
+        .map(Identity::from)
+        .collect::<Vec<_>>();
+    Ok(Json(identities).into_response())
+}
+
+async fn list_user_roles(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let db_client = db_client.as_ref();
+    find_user(db_client, &realm, user_id).await?;
+
+    let roles = RolesRepo::new(db_client)
+        .list_roles_by_user_id(&realm.name, user_id)
+        .await?
+        .into_iter()
+        .map(Role::from)
+        .collect::<Vec<_>>();
+    Ok(Json(roles).into_response())
+}
+
+/// 다음 토큰 발급부터 역할이 반영됨
+async fn assign_user_role(
+    _: AdminAuth,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path((user_id, role_id)) = path?;
+    let db_client = db_client.as_ref();
+    find_user(db_client, &realm, user_id).await?;
+
+    let roles_repo = RolesRepo::new(db_client);
+    roles_repo
+        .get_role_by_id(&realm.name, role_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("role is not found".to_string()))?;
+    roles_repo.assign_role(user_id, role_id).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn unassign_user_role(
+    _: AdminAuth,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path((user_id, role_id)) = path?;
+    let db_client = db_client.as_ref();
+    find_user(db_client, &realm, user_id).await?;
+
+    let roles_repo = RolesRepo::new(db_client);
+    roles_repo
+        .get_role_by_id(&realm.name, role_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("role is not found".to_string()))?;
+    let unassigned = roles_repo.unassign_role(user_id, role_id).await?;
+    if unassigned == 0 {
+        return Err(AllForOneError::NotFound(
+            "role is not assigned to user".to_string(),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn activate_user(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let db_client = db_client.as_ref();
+    let user = find_user(db_client, &realm, user_id).await?;
+
+    let user = UsersRepo::new(db_client)
+        .set_user_active(user, true)
+        .await?;
+    Ok(Json(User::from(user)).into_response())
+}
+
+/// 비활성화하면 발급된 refresh token 도 모두 폐기하고 클라이언트에 back-channel 로그아웃을 보냄
+async fn deactivate_user(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    State(logout_notifier): State<Arc<LogoutNotifier>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+
+    let txn = db_client.begin().await?;
+    let user = find_user(&txn, &realm, user_id).await?;
+    let user = UsersRepo::new(&txn).set_user_active(user, false).await?;
+    RefreshTokensRepo::new(&txn)
+        .revoke_refresh_tokens_by_user_id(user_id)
+        .await?;
+    txn.commit().await?;
+    logout_notifier.notify_logout(user_id, None).await?;
+
+    Ok(Json(User::from(user)).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct SuspendUserBody {
+    pub reason: String,
+    pub until: Option<DateTimeWithTimeZone>,
+}
+
+/// 정지하면 비활성화와 같이 refresh token 을 폐기하고 back-channel 로그아웃을 보냄
+async fn suspend_user(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    State(logout_notifier): State<Arc<LogoutNotifier>>,
+    body: Result<Json<SuspendUserBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let Json(body) = body?;
+
+    let txn = db_client.begin().await?;
+    let user = find_user(&txn, &realm, user_id).await?;
+    let user = UsersRepo::new(&txn)
+        .suspend_user(user, body.reason, body.until)
+        .await?;
+    RefreshTokensRepo::new(&txn)
+        .revoke_refresh_tokens_by_user_id(user_id)
+        .await?;
+    txn.commit().await?;
+    logout_notifier.notify_logout(user_id, None).await?;
+
+    Ok(Json(User::from(user)).into_response())
+}
+
+async fn lift_user_suspension(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+    let db_client = db_client.as_ref();
+    let user = find_user(db_client, &realm, user_id).await?;
+
+    let user = UsersRepo::new(db_client).lift_user_suspension(user).await?;
+    Ok(Json(User::from(user)).into_response())
+}
+
+/// 세션 기록은 사용자와 함께 지워지지 않으므로 삭제한 뒤에도 back-channel 로그아웃을 보낼 수 있음
+async fn delete_user(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    State(logout_notifier): State<Arc<LogoutNotifier>>,
+) -> Result<Response, AllForOneError> {
+    let Path(user_id) = path?;
+
+    let txn = db_client.begin().await?;
+    find_user(&txn, &realm, user_id).await?;
+
+    let deleted = UsersRepo::new(&txn).delete_user(user_id).await?;
+    if deleted == 0 {
+        return Err(AllForOneError::NotFound("user is not found".to_string()));
+    }
+    txn.commit().await?;
+    logout_notifier.notify_logout(user_id, None).await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// 다른 realm 의 사용자는 없는 것으로 취급
+async fn find_user<C: ConnectionTrait>(
+    conn: &C,
+    realm: &Realm,
+    user_id: Uuid,
+) -> Result<users::Model, AllForOneError> {
+    UsersRepo::new(conn)
+        .get_user_by_id(user_id)
+        .await?
+        .filter(|user| user.realm == realm.name)
+        .ok_or_else(|| AllForOneError::NotFound("user is not found".to_string()))
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_users))
+        .route("/lookup", get(lookup_user))
+        .route("/{id}", get(get_user).delete(delete_user))
+        .route("/{id}/identities", get(list_user_identities))
+        .route("/{id}/roles", get(list_user_roles))
+        .route(
+            "/{id}/roles/{role_id}",
+            put(assign_user_role).delete(unassign_user_role),
+        )
+        .route("/{id}/activate", post(activate_user))
+        .route("/{id}/deactivate", post(deactivate_user))
+        .route(
+            "/{id}/suspension",
+            post(suspend_user).delete(lift_user_suspension),
+        )
+        .with_state(app_state)
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, Query, State,
+        rejection::{PathRejection, QueryRejection},
+    },
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::admin_auth::AdminAuth,
+        response::types::{page::Page, webhook_delivery::WebhookDelivery},
+        state::types::app::AppState,
+        types::{
+            realm::Realm,
+            webhook::{WebhookDeliveryStatus, WebhookEventType},
+        },
+    },
+    db::repo::webhooks::{WebhookDeliveryFilter, WebhooksRepo},
+    entity::webhook_deliveries,
+    utils::error::AllForOneError,
+};
+
+const DEFAULT_PER_PAGE: u64 = 20;
+const MAX_PER_PAGE: u64 = 100;
+
+#[derive(Deserialize, Debug)]
+struct ListDeliveriesQuery {
+    pub page: Option<u64>,
+    pub per_page: Option<u64>,
+    pub status: Option<WebhookDeliveryStatus>,
+    pub endpoint: Option<String>,
+    /// `user.registered` 처럼 payload 의 type 값
+    pub event_type: Option<String>,
+}
+
+/// 최근 전송부터. `page` 는 1 부터 시작
+async fn list_deliveries(
+    _: AdminAuth,
+    query: Result<Query<ListDeliveriesQuery>, QueryRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Query(query) = query?;
+    let page = query.page.unwrap_or(1).max(1);
+    let per_page = query
+        .per_page
+        .unwrap_or(DEFAULT_PER_PAGE)
+        .clamp(1, MAX_PER_PAGE);
+    let event_type = query
+        .event_type
+        .as_deref()
+        .map(|event_type| {
+            WebhookEventType::parse(event_type).ok_or_else(|| {
+                AllForOneError::BadRequest(format!("unknown webhook event: {}", event_type))
+            })
+        })
+        .transpose()?;
+    let filter = WebhookDeliveryFilter {
+        status: query.status,
+        endpoint: query.endpoint,
+        event_type,
+    };
+
+    let (deliveries, total) = WebhooksRepo::new(db_client.as_ref())
+        .list_deliveries(&realm.name, &filter, page - 1, per_page)
+        .await?;
+
+    Ok(Json(Page {
+        items: deliveries.into_iter().map(WebhookDelivery::from).collect(),
+        page,
+        per_page,
+        total,
+    })
+    .into_response())
+}
+
+async fn get_delivery(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(delivery_id) = path?;
+    let delivery = find_delivery(db_client.as_ref(), &realm, delivery_id).await?;
+    Ok(Json(WebhookDelivery::from(delivery)).into_response())
+}
+
+/// 끝난 전송을 시도 횟수를 초기화해서 다시 보냄
+async fn retry_delivery(
+    _: AdminAuth,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let Path(delivery_id) = path?;
+    let db_client = db_client.as_ref();
+    let delivery = find_delivery(db_client, &realm, delivery_id).await?;
+    if delivery.status == WebhookDeliveryStatus::Pending.as_str() {
+        return Err(AllForOneError::Conflict(
+            "webhook delivery is already pending".to_string(),
+        ));
+    }
+
+    let delivery = WebhooksRepo::new(db_client)
+        .retry_delivery(delivery)
+        .await?;
+    Ok(Json(WebhookDelivery::from(delivery)).into_response())
+}
+
+/// 다른 realm 의 전송은 없는 것으로 취급
+async fn find_delivery<C: ConnectionTrait>(
+    conn: &C,
+    realm: &Realm,
+    delivery_id: Uuid,
+) -> Result<webhook_deliveries::Model, AllForOneError> {
+    WebhooksRepo::new(conn)
+        .get_delivery(delivery_id)
+        .await?
+        .filter(|delivery| delivery.realm == realm.name)
+        .ok_or_else(|| AllForOneError::NotFound("webhook delivery is not found".to_string()))
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/deliveries", get(list_deliveries))
+        .route("/deliveries/{id}", get(get_delivery))
+        .route("/deliveries/{id}/retry", post(retry_delivery))
+        .with_state(app_state)
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::request::Parts,
+};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    api::{
+        extractor::auth_user::bearer_token,
+        state::types::jwt_issuer::JwtIssuer,
+        types::admin::{ADMIN_SCOPE, AdminCredential},
+    },
+    db::repo::users::UsersRepo,
+    utils::error::AllForOneError,
+};
+
+/// 고정 admin credential 이나 `admin` scope 를 가진 access token 으로 인증된 요청
+/// access token 은 아직 로그인할 수 있는 사용자의 것이어야 함
+pub struct AdminAuth;
+
+impl<S> FromRequestParts<S> for AdminAuth
+where
+    S: Send + Sync,
+    Arc<JwtIssuer>: FromRef<S>,
+    Arc<AdminCredential>: FromRef<S>,
+    Arc<DatabaseConnection>: FromRef<S>,
+{
+    type Rejection = AllForOneError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)
+            .ok_or_else(|| AllForOneError::Auth("bearer token is not found".to_string()))?;
+
+        if Arc::<AdminCredential>::from_ref(state).matches(token) {
+            return Ok(AdminAuth);
+        }
+
+        let claims = Arc::<JwtIssuer>::from_ref(state)
+            .verify_jwt(token)
+            .map_err(|e| AllForOneError::Auth(format!("invalid bearer token: {}", e)))?;
+        if !claims.has_scope(ADMIN_SCOPE) {
+            return Err(AllForOneError::Forbidden(
+                "admin scope is required".to_string(),
+            ));
+        }
+
+        let db_client = Arc::<DatabaseConnection>::from_ref(state);
+        let user = UsersRepo::new(db_client.as_ref())
+            .get_user_by_id(claims.sub)
+            .await?
+            .ok_or_else(|| AllForOneError::Forbidden("admin user is not found".to_string()))?;
+        // 토큰 엔드포인트와 같은 기준으로 비활성/정지된 사용자를 막음
+        if let Some(reason) = user.sign_in_denial_reason(chrono::Utc::now().into()) {
+            return Err(AllForOneError::Forbidden(reason));
+        }
+
+        Ok(AdminAuth)
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{header::AUTHORIZATION, request::Parts},
+};
+use uuid::Uuid;
+
+use crate::{api::state::types::jwt_issuer::JwtIssuer, utils::error::AllForOneError};
+
+/// `Authorization: Bearer <jwt>` 헤더를 검증한 사용자
+pub struct AuthUser {
+    pub user_id: Uuid,
+}
+
+impl<S> FromRequestParts<S> for AuthUser
+where
+    S: Send + Sync,
+    Arc<JwtIssuer>: FromRef<S>,
+{
+    type Rejection = AllForOneError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let token = bearer_token(parts)
+            .ok_or_else(|| AllForOneError::Auth("bearer token is not found".to_string()))?;
+
+        let jwt_issuer = Arc::<JwtIssuer>::from_ref(state);
+        let claims = jwt_issuer
+            .verify_jwt(token)
+            .map_err(|e| AllForOneError::Auth(format!("invalid bearer token: {}", e)))?;
+
+        Ok(AuthUser {
+            user_id: claims.sub,
+        })
+    }
+}
+
+pub fn bearer_token(parts: &Parts) -> Option<&str> {
+    parts
+        .headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+}
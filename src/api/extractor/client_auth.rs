@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{FromRef, FromRequestParts},
+    http::{HeaderMap, header::AUTHORIZATION, request::Parts},
+};
+use base64::{Engine, prelude::BASE64_STANDARD};
+
+use crate::{
+    api::{
+        extractor::device_info::DeviceInfo,
+        state::types::lockout_tracker::LockoutTracker,
+        types::{client::ClientRegistry, client_ip::TrustedProxies, lockout::LockoutSubject},
+    },
+    utils::error::AllForOneError,
+};
+
+/// `Authorization: Basic base64(client_id:client_secret)` 로 인증된 confidential client
+/// 틀린 secret 은 토큰 엔드포인트와 같은 잠금 카운터에 셈
+pub struct ClientAuth;
+
+impl<S> FromRequestParts<S> for ClientAuth
+where
+    S: Send + Sync,
+    Arc<ClientRegistry>: FromRef<S>,
+    Arc<LockoutTracker>: FromRef<S>,
+    Arc<TrustedProxies>: FromRef<S>,
+{
+    type Rejection = AllForOneError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let (client_id, client_secret) = basic_credentials(&parts.headers)
+            .ok_or_else(|| AllForOneError::Auth("client credentials are not found".to_string()))?;
+
+        let clients = Arc::<ClientRegistry>::from_ref(state);
+        let Ok(device) = DeviceInfo::from_request_parts(parts, state).await;
+        let subject = LockoutSubject::client(&clients, Some(&client_id), device.ip_address);
+        let lockout_tracker = Arc::<LockoutTracker>::from_ref(state);
+        lockout_tracker.check(&[&subject]).await?;
+
+        match clients.get(&client_id) {
+            Some(client) if client.authenticate(&client_secret) => Ok(ClientAuth),
+            _ => {
+                lockout_tracker.record_failure(&[&subject]).await;
+                Err(AllForOneError::Auth(
+                    "client authentication failed".to_string(),
+                ))
+            }
+        }
+    }
+}
+
+/// `Authorization: Basic` 헤더의 client_id 와 client_secret
+pub fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let encoded = headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Basic ")?;
+    let decoded = String::from_utf8(BASE64_STANDARD.decode(encoded.trim()).ok()?).ok()?;
+    let (client_id, client_secret) = decoded.split_once(':')?;
+    Some((client_id.to_string(), client_secret.to_string()))
+}
@@ -0,0 +1,46 @@
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, FromRef, FromRequestParts},
+    http::{header::USER_AGENT, request::Parts},
+};
+
+use crate::api::types::client_ip::TrustedProxies;
+
+const MAX_USER_AGENT_LEN: usize = 512;
+
+/// 토큰을 발급받는 기기 정보. 사용자의 세션 목록에 보여줌
+#[derive(Clone, Debug, Default)]
+pub struct DeviceInfo {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+impl<S> FromRequestParts<S> for DeviceInfo
+where
+    S: Send + Sync,
+    Arc<TrustedProxies>: FromRef<S>,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| value.chars().take(MAX_USER_AGENT_LEN).collect());
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let ip_address = Arc::<TrustedProxies>::from_ref(state)
+            .client_ip(&parts.headers, peer)
+            .map(|ip| ip.to_string());
+
+        Ok(DeviceInfo {
+            user_agent,
+            ip_address,
+        })
+    }
+}
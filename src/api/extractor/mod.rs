@@ -0,0 +1,4 @@
+pub mod admin_auth;
+pub mod auth_user;
+pub mod client_auth;
+pub mod device_info;
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use axum::http::{HeaderValue, request::Parts};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::api::types::cors::CorsPolicy;
+
+/// preflight 는 이 layer 가 바로 응답함. 허용하지 않는 origin 이나 제외된 경로에는 CORS 헤더를 붙이지 않음
+pub fn cors_layer(cors_policy: Arc<CorsPolicy>) -> CorsLayer {
+    let layer = CorsLayer::new()
+        .allow_methods(cors_policy.allowed_methods.clone())
+        .allow_headers(cors_policy.allowed_headers.clone())
+        .max_age(cors_policy.max_age)
+        .allow_credentials(cors_policy.allow_credentials);
+
+    layer.allow_origin(AllowOrigin::predicate(
+        move |origin: &HeaderValue, parts: &Parts| {
+            origin
+                .to_str()
+                .is_ok_and(|origin| cors_policy.allows(origin, parts.uri.path()))
+        },
+    ))
+}
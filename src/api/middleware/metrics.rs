@@ -0,0 +1,39 @@
+use std::{sync::Arc, time::Instant};
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::api::{
+    state::types::metrics::Metrics,
+    types::realm::{Realm, strip_realm_prefix},
+};
+
+/// 라우트가 정해진 요청만 셈. 경로 변수 대신 라우트 패턴을 써서 label 이 늘어나지 않게 함
+pub async fn track_http_metrics(
+    State((metrics, realm)): State<(Arc<Metrics>, Arc<Realm>)>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(route) = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|matched_path| strip_realm_prefix(matched_path.as_str()).to_string())
+    else {
+        return next.run(request).await;
+    };
+    let method = request.method().clone();
+
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+    metrics.record_http_request(
+        &realm.name,
+        method.as_str(),
+        &route,
+        response.status().as_u16(),
+        started_at.elapsed(),
+    );
+    response
+}
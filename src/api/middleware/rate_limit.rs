@@ -0,0 +1,40 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use tracing::warn;
+
+use crate::{api::types::rate_limit::RateLimiter, utils::error::AllForOneError};
+
+/// 클라이언트 IP 를 알 수 없는 요청은 제한하지 않음
+pub async fn rate_limit(
+    State(rate_limiter): State<Arc<RateLimiter>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let peer = request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip());
+    let client_ip = rate_limiter
+        .trusted_proxies()
+        .client_ip(request.headers(), peer);
+
+    if let Some(client_ip) = client_ip
+        && let Err(retry_after) = rate_limiter.check(client_ip, request.uri().path()).await
+    {
+        warn!(
+            target: "security",
+            "rate limit exceeded by {} on {}",
+            client_ip,
+            request.uri().path()
+        );
+        return AllForOneError::TooManyRequests(retry_after.as_secs_f64().ceil().max(1.0) as u64)
+            .into_response();
+    }
+
+    next.run(request).await
+}
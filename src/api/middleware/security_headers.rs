@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Request, State},
+    http::{
+        HeaderValue,
+        header::{CACHE_CONTROL, PRAGMA},
+    },
+    middleware::Next,
+    response::Response,
+};
+
+use crate::api::types::security_headers::SecurityHeaders;
+
+/// 핸들러가 직접 정한 헤더는 덮어쓰지 않음
+pub async fn security_headers(
+    State(security_headers): State<Arc<SecurityHeaders>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    for (name, value) in &security_headers.headers {
+        if !headers.contains_key(name) {
+            headers.insert(name.clone(), value.clone());
+        }
+    }
+    response
+}
+
+/// 토큰을 담는 응답은 캐시에 남지 않아야 함 (RFC 6749 5.1). 오류 응답도 같이 막음
+pub async fn no_store(request: Request, next: Next) -> Response {
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    headers.insert(PRAGMA, HeaderValue::from_static("no-cache"));
+    response
+}
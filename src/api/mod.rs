@@ -1,3 +1,6 @@
+pub mod admin;
+pub mod extractor;
+pub mod middleware;
 pub mod response;
 pub mod router;
 pub mod server;
@@ -1,6 +1,6 @@
 use axum::{
     Json,
-    http::StatusCode,
+    http::{StatusCode, header::RETRY_AFTER},
     response::{IntoResponse, Response},
 };
 
@@ -51,7 +51,28 @@ impl IntoResponse for AllForOneError {
                     }]),
                 },
             ),
+            AllForOneError::Form(rejection) => (
+                rejection.status(),
+                ErrorResponse {
+                    code: INVALID_VALUE.to_string(),
+                    message,
+                    status_code: rejection.status().as_u16(),
+                    details: Some(vec![ErrorResponseDetails {
+                        field: "body/form".to_string(),
+                        message: rejection.to_string(),
+                    }]),
+                },
+            ),
 
+            AllForOneError::BadRequest(err) => (
+                StatusCode::BAD_REQUEST,
+                ErrorResponse {
+                    code: INVALID_VALUE.to_string(),
+                    message: err,
+                    status_code: 400,
+                    details: None,
+                },
+            ),
             AllForOneError::Auth(err) => (
                 StatusCode::UNAUTHORIZED,
                 ErrorResponse {
@@ -61,6 +82,56 @@ impl IntoResponse for AllForOneError {
                     details: None,
                 },
             ),
+            AllForOneError::Forbidden(err) => (
+                StatusCode::FORBIDDEN,
+                ErrorResponse {
+                    code: "FORBIDDEN".to_string(),
+                    message: err,
+                    status_code: 403,
+                    details: None,
+                },
+            ),
+            AllForOneError::NotFound(err) => (
+                StatusCode::NOT_FOUND,
+                ErrorResponse {
+                    code: "NOT_FOUND".to_string(),
+                    message: err,
+                    status_code: 404,
+                    details: None,
+                },
+            ),
+            AllForOneError::Conflict(err) => (
+                StatusCode::CONFLICT,
+                ErrorResponse {
+                    code: "CONFLICT".to_string(),
+                    message: err,
+                    status_code: 409,
+                    details: None,
+                },
+            ),
+            AllForOneError::Replay(err) => (
+                StatusCode::UNAUTHORIZED,
+                ErrorResponse {
+                    code: "REPLAY_DETECTED".to_string(),
+                    message: err,
+                    status_code: 401,
+                    details: None,
+                },
+            ),
+            AllForOneError::TooManyRequests(retry_after) => {
+                let body = ErrorResponse {
+                    code: "TOO_MANY_REQUESTS".to_string(),
+                    message,
+                    status_code: 429,
+                    details: None,
+                };
+                return (
+                    StatusCode::TOO_MANY_REQUESTS,
+                    [(RETRY_AFTER, retry_after.to_string())],
+                    Json(body),
+                )
+                    .into_response();
+            }
             AllForOneError::Db(err) => {
                 tracing::error!("{:?}", err);
                 (
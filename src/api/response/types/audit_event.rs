@@ -0,0 +1,34 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::audit_events;
+
+#[derive(Serialize)]
+pub struct AuditEvent {
+    pub id: Uuid,
+    pub event_type: String,
+    pub outcome: String,
+    pub actor_id: Option<Uuid>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub reason: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<audit_events::Model> for AuditEvent {
+    fn from(model: audit_events::Model) -> Self {
+        Self {
+            id: model.id,
+            event_type: model.event_type,
+            outcome: model.outcome,
+            actor_id: model.actor_id,
+            client_id: model.client_id,
+            ip_address: model.ip_address,
+            user_agent: model.user_agent,
+            reason: model.reason,
+            created_at: model.created_at,
+        }
+    }
+}
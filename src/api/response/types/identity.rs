@@ -0,0 +1,26 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::user_identities;
+
+#[derive(Serialize)]
+pub struct Identity {
+    pub id: Uuid,
+    pub idp: String,
+    pub idp_uid: String,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+impl From<user_identities::Model> for Identity {
+    fn from(model: user_identities::Model) -> Self {
+        Self {
+            id: model.id,
+            idp: model.idp,
+            idp_uid: model.idp_uid,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
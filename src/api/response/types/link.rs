@@ -0,0 +1,12 @@
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::provider::types::idp::OAuthProvider;
+
+/// 기존 계정의 사용자가 확인해야 하는 계정 연결 요청
+#[derive(Serialize)]
+pub struct PendingIdentityLinkResponse {
+    pub link_ticket: Uuid,
+    pub idp: OAuthProvider,
+    pub expires_in: u64,
+}
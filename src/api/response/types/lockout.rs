@@ -0,0 +1,24 @@
+use sonic_rs::Serialize;
+
+use crate::state_store::types::LockoutRecord;
+
+#[derive(Serialize)]
+pub struct Lockout {
+    /// `account:{user_id}` 또는 `client:{client_id}@{ip}`
+    pub subject: String,
+    pub failures: u32,
+    pub locked: bool,
+    /// 다시 시도할 수 있는 시각(unix)
+    pub blocked_until: i64,
+}
+
+impl Lockout {
+    pub fn new(subject: String, record: LockoutRecord) -> Self {
+        Self {
+            subject,
+            failures: record.failures,
+            locked: record.locked,
+            blocked_until: record.blocked_until,
+        }
+    }
+}
@@ -1,2 +1,14 @@
+pub mod audit_event;
 pub mod error;
+pub mod identity;
+pub mod introspection;
+pub mod link;
+pub mod lockout;
+pub mod organization;
+pub mod page;
+pub mod role;
+pub mod session;
 pub mod token;
+pub mod user;
+pub mod userinfo;
+pub mod webhook_delivery;
@@ -0,0 +1,125 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::{
+    group_members, groups, organization_invitations, organization_members, organizations,
+};
+
+#[derive(Serialize)]
+pub struct Organization {
+    pub id: Uuid,
+    pub name: String,
+    pub slug: String,
+    /// 요청한 사용자의 역할
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+impl From<organizations::Model> for Organization {
+    fn from(model: organizations::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            slug: model.slug,
+            role: None,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+impl From<(organizations::Model, organization_members::Model)> for Organization {
+    fn from((organization, member): (organizations::Model, organization_members::Model)) -> Self {
+        Self {
+            role: Some(member.role),
+            ..Self::from(organization)
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct OrganizationMember {
+    pub user_id: Uuid,
+    pub role: String,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+impl From<organization_members::Model> for OrganizationMember {
+    fn from(model: organization_members::Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            role: model.role,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct OrganizationInvitation {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub email: String,
+    pub role: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTimeWithTimeZone,
+    pub created_at: DateTimeWithTimeZone,
+    /// 초대를 만든 응답에서만 한 번 내려줌
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+}
+
+impl From<organization_invitations::Model> for OrganizationInvitation {
+    fn from(model: organization_invitations::Model) -> Self {
+        Self {
+            id: model.id,
+            organization_id: model.organization_id,
+            email: model.email,
+            role: model.role,
+            invited_by: model.invited_by,
+            expires_at: model.expires_at,
+            created_at: model.created_at,
+            token: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Group {
+    pub id: Uuid,
+    pub organization_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<groups::Model> for Group {
+    fn from(model: groups::Model) -> Self {
+        Self {
+            id: model.id,
+            organization_id: model.organization_id,
+            name: model.name,
+            description: model.description,
+            created_at: model.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct GroupMember {
+    pub user_id: Uuid,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<group_members::Model> for GroupMember {
+    fn from(model: group_members::Model) -> Self {
+        Self {
+            user_id: model.user_id,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::{permissions, roles};
+
+#[derive(Serialize)]
+pub struct Role {
+    pub id: Uuid,
+    pub name: String,
+    pub audience: Option<String>,
+    pub description: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<roles::Model> for Role {
+    fn from(model: roles::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            audience: model.audience,
+            description: model.description,
+            created_at: model.created_at,
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct Permission {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+}
+
+impl From<permissions::Model> for Permission {
+    fn from(model: permissions::Model) -> Self {
+        Self {
+            id: model.id,
+            name: model.name,
+            description: model.description,
+            created_at: model.created_at,
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::sessions;
+
+#[derive(Serialize)]
+pub struct Session {
+    pub id: Uuid,
+    pub idp: String,
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub last_seen_at: DateTimeWithTimeZone,
+}
+
+impl From<sessions::Model> for Session {
+    fn from(model: sessions::Model) -> Self {
+        Self {
+            id: model.id,
+            idp: model.idp,
+            user_agent: model.user_agent,
+            ip_address: model.ip_address,
+            created_at: model.created_at,
+            last_seen_at: model.last_seen_at,
+        }
+    }
+}
@@ -3,4 +3,10 @@ use sonic_rs::Serialize;
 #[derive(Serialize)]
 pub struct Token {
     pub access_token: String,
+    pub token_type: String,
+    pub expires_in: i64,
+    pub refresh_token: String,
+    /// authorization code 교환에서 `openid` scope 를 요청했을 때만 발급
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id_token: Option<String>,
 }
@@ -0,0 +1,38 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::users;
+
+#[derive(Serialize)]
+pub struct User {
+    pub id: Uuid,
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub is_active: bool,
+    pub suspended_at: Option<DateTimeWithTimeZone>,
+    pub suspended_until: Option<DateTimeWithTimeZone>,
+    pub suspension_reason: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+}
+
+impl From<users::Model> for User {
+    fn from(model: users::Model) -> Self {
+        Self {
+            id: model.id,
+            username: model.username,
+            email: model.email,
+            display_name: model.display_name,
+            avatar_url: model.avatar_url,
+            is_active: model.is_active,
+            suspended_at: model.suspended_at,
+            suspended_until: model.suspended_until,
+            suspension_reason: model.suspension_reason,
+            created_at: model.created_at,
+            updated_at: model.updated_at,
+        }
+    }
+}
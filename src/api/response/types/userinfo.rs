@@ -0,0 +1,29 @@
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::users;
+
+/// OIDC userinfo 응답
+#[derive(Serialize)]
+pub struct UserInfo {
+    pub sub: Uuid,
+    pub preferred_username: Option<String>,
+    pub name: Option<String>,
+    pub email: Option<String>,
+    pub email_verified: bool,
+    pub picture: Option<String>,
+}
+
+impl From<users::Model> for UserInfo {
+    fn from(user: users::Model) -> Self {
+        Self {
+            sub: user.id,
+            preferred_username: user.username,
+            name: user.display_name,
+            // provider 가 검증한 email 만 저장하므로 email 이 있으면 검증된 것
+            email_verified: user.email.is_some(),
+            email: user.email,
+            picture: user.avatar_url,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use sea_orm::prelude::DateTimeWithTimeZone;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::entity::webhook_deliveries;
+
+#[derive(Serialize)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub endpoint: String,
+    /// pending, delivered, failed 중 하나
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: Option<DateTimeWithTimeZone>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTimeWithTimeZone,
+    pub delivered_at: Option<DateTimeWithTimeZone>,
+}
+
+impl From<webhook_deliveries::Model> for WebhookDelivery {
+    fn from(model: webhook_deliveries::Model) -> Self {
+        // 끝난 전송의 next_attempt_at 은 의미가 없으므로 보여주지 않음
+        let next_attempt_at = (model.status == "pending").then_some(model.next_attempt_at);
+        Self {
+            id: model.id,
+            event_id: model.event_id,
+            event_type: model.event_type,
+            endpoint: model.endpoint,
+            status: model.status,
+            attempts: model.attempts,
+            next_attempt_at,
+            last_status_code: model.last_status_code,
+            last_error: model.last_error,
+            created_at: model.created_at,
+            delivered_at: model.delivered_at,
+        }
+    }
+}
@@ -1,17 +1,163 @@
-use axum::{Router, routing::get};
-
-use crate::api::{state::types::app::AppState, v1};
-
-pub async fn make_server_route(app_state: AppState) -> Router {
-    Router::new().nest(
-        "/api",
-        Router::new()
-            .route("/heartbeat", get(heartbeat))
-            .with_state(app_state.clone())
-            .nest("/v1", v1::router(app_state.clone()).await),
-    )
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{
+    Router,
+    extract::{Request, State},
+    http::{
+        HeaderMap, StatusCode,
+        header::{AUTHORIZATION, CONTENT_TYPE, HOST},
+    },
+    middleware,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use sea_orm::DatabaseConnection;
+use tower::ServiceExt;
+use tracing::warn;
+
+use crate::api::{
+    admin,
+    middleware::{
+        cors::cors_layer, metrics::track_http_metrics, rate_limit::rate_limit,
+        security_headers::security_headers,
+    },
+    state::types::{app::AppState, metrics::Metrics},
+    v1,
+};
+
+/// 모든 realm 의 라우트와 `/metrics` 에 요청 한도, CORS, 보안 헤더를 적용
+/// CORS 가 바깥에 있어서 preflight 는 한도에 세지 않고, 429 응답에도 CORS 헤더가 붙음
+/// 보안 헤더는 가장 바깥이라 preflight 와 429 를 포함한 모든 응답에 붙음
+pub async fn make_server_route(
+    app_state: AppState,
+    realm_states: Vec<(Vec<String>, AppState)>,
+    metrics_enabled: bool,
+) -> Router {
+    let rate_limiter = app_state.rate_limiter.clone();
+    let cors_policy = app_state.cors_policy.clone();
+    let security_headers_state = app_state.security_headers.clone();
+    let metrics_route = Router::new()
+        .route("/metrics", get(metrics))
+        .with_state(app_state.clone());
+    let router = make_realm_routes(app_state, realm_states).await;
+    let router = if metrics_enabled {
+        metrics_route.merge(router)
+    } else {
+        router
+    };
+
+    let router = match rate_limiter {
+        Some(rate_limiter) => {
+            router.layer(middleware::from_fn_with_state(rate_limiter, rate_limit))
+        }
+        None => router,
+    };
+
+    let router = match cors_policy {
+        Some(cors_policy) => router.layer(cors_layer(cors_policy)),
+        None => router,
+    };
+
+    router.layer(middleware::from_fn_with_state(
+        security_headers_state,
+        security_headers,
+    ))
+}
+
+/// 기본 realm 은 `/api`, 나머지 realm 은 `/realms/{name}/api` 로 라우팅.
+/// host 가 지정된 realm 은 그 host 로 들어온 요청을 `/api` 에서도 받음
+async fn make_realm_routes(
+    app_state: AppState,
+    realm_states: Vec<(Vec<String>, AppState)>,
+) -> Router {
+    let mut router = make_realm_route(app_state).await;
+    let mut host_routes = HashMap::new();
+
+    for (hosts, realm_state) in realm_states {
+        let prefix = realm_state.realm.path_prefix();
+        let realm_router = make_realm_route(realm_state).await;
+        for host in hosts {
+            host_routes.insert(host.to_lowercase(), realm_router.clone());
+        }
+        router = router.nest(&prefix, realm_router);
+    }
+
+    if host_routes.is_empty() {
+        return router;
+    }
+
+    let host_routes = Arc::new(host_routes);
+    Router::new().fallback(move |request: Request| {
+        let router = request_host(&request)
+            .and_then(|host| host_routes.get(&host))
+            .unwrap_or(&router)
+            .clone();
+        async move { route_request(router, request).await }
+    })
+}
+
+async fn make_realm_route(app_state: AppState) -> Router {
+    let metrics_state = (app_state.metrics.clone(), app_state.realm.clone());
+    Router::new()
+        .nest(
+            "/api",
+            Router::new()
+                .route("/heartbeat", get(heartbeat))
+                .with_state(app_state.clone())
+                .nest("/v1", v1::router(app_state.clone()).await)
+                .nest("/admin", admin::router(app_state.clone()).await),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            metrics_state,
+            track_http_metrics,
+        ))
+}
+
+async fn route_request(router: Router, request: Request) -> Response {
+    match router.oneshot(request).await {
+        Ok(response) => response,
+        Err(never) => match never {},
+    }
+}
+
+/// 포트를 뗀 소문자 host
+fn request_host(request: &Request) -> Option<String> {
+    let host = request
+        .headers()
+        .get(HOST)
+        .and_then(|host| host.to_str().ok())
+        .or_else(|| request.uri().host())?;
+    let host = host.rsplit_once(':').map_or(host, |(host, _)| host);
+    Some(host.to_lowercase())
 }
 
 async fn heartbeat() -> &'static str {
     "My OIDC server is running!"
 }
+
+/// Prometheus text format. token 을 설정했으면 bearer 로 받아야 함
+async fn metrics(
+    State(metrics): State<Arc<Metrics>>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    headers: HeaderMap,
+) -> Response {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if !metrics.allows(token) {
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+
+    match metrics.render(&db_client) {
+        Ok(body) => (
+            [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+            body,
+        )
+            .into_response(),
+        Err(err) => {
+            warn!("fail to render metrics: {:#}", err);
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
@@ -1,20 +1,52 @@
+use std::{net::SocketAddr, sync::Arc};
+
 use anyhow::{Context, Result};
+use sea_orm::DatabaseConnection;
 use tracing::info;
 
 use crate::{
-    api::{router::make_server_route, state::init::make_app_state},
+    api::{
+        router::make_server_route,
+        state::{
+            init::{make_app_state, make_realm_app_state},
+            types::webhook_dispatcher::WebhookDispatcher,
+        },
+    },
     config::types::Config,
 };
 
-pub async fn server_start(config: Config) -> Result<()> {
-    let app_state = make_app_state(&config).await?;
-    let service = make_server_route(app_state).await;
+pub async fn server_start(config: Config, db: DatabaseConnection) -> Result<()> {
+    let app_state = make_app_state(&config, db).await?;
+    let mut realm_states = Vec::with_capacity(config.realms.len());
+    for realm_config in &config.realms {
+        let realm_state = make_realm_app_state(&config, realm_config, &app_state)
+            .await
+            .with_context(|| format!("fail to make realm {} state", realm_config.name))?;
+        realm_states.push((realm_config.hosts.clone(), realm_state));
+    }
+
+    // realm 들이 DB 를 같이 쓰므로 dispatcher 는 하나만 돌림
+    Arc::new(WebhookDispatcher::new(
+        &config,
+        app_state.postgres_state.clone(),
+    )?)
+    .start();
+    // back-channel 로그아웃은 realm 의 issuer 로 서명하므로 realm 마다 돌림
+    app_state.logout_notifier.clone().start();
+    for (_, realm_state) in &realm_states {
+        realm_state.logout_notifier.clone().start();
+    }
+
+    let service = make_server_route(app_state, realm_states, config.metrics.enabled).await;
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.server.port))
         .await
         .context("fail to make binding server address")?;
 
     info!("http server is running");
-    Ok(axum::serve(listener, service)
-        .await
-        .context("fail to start server")?)
+    axum::serve(
+        listener,
+        service.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .context("fail to start server")
 }
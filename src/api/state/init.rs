@@ -1,29 +1,161 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
 use anyhow::Result;
+use sea_orm::DatabaseConnection;
 
 use crate::{
     api::{
-        state::types::{app::AppState, jwt_issuer::JwtIssuer, oauth_client::OAuthProviderClient},
-        types::session::SessionCookieConfig,
+        state::types::{
+            app::AppState, audit_log::AuditLog, jwt_issuer::JwtIssuer,
+            lockout_tracker::LockoutTracker, logout_notifier::LogoutNotifier, metrics::Metrics,
+            oauth_client::OAuthProviderClient,
+        },
+        types::{
+            account_linking::AccountLinkingConfig,
+            admin::AdminCredential,
+            client::ClientRegistry,
+            client_ip::TrustedProxies,
+            cors::CorsPolicy,
+            rate_limit::RateLimiter,
+            realm::{DEFAULT_REALM, Realm},
+            security_headers::SecurityHeaders,
+            session::SessionCookieConfig,
+        },
     },
-    config::types::Config,
-    db::connect::postgres_connect,
+    config::types::{Config, RealmConfig},
     memcached::connect::memcached_connect,
+    state_store::connect::state_store_connect,
 };
 
-pub async fn make_app_state(config: &Config) -> Result<AppState> {
-    let postgres_state = Arc::new(postgres_connect(config).await?);
-    let oauth_provider_state = Arc::new(OAuthProviderClient::new(config)?);
-    let memcached_state = Arc::new(memcached_connect(config)?);
-    let jwt_issuer = Arc::new(JwtIssuer::new(config).await?);
+/// 최상위 설정으로 만든 기본 realm 의 상태
+pub async fn make_app_state(config: &Config, db: DatabaseConnection) -> Result<AppState> {
+    let realm = Realm::new(DEFAULT_REALM);
+    let postgres_state = Arc::new(db);
+    let metrics = Arc::new(Metrics::new(&config.metrics)?);
+    let oauth_provider_state = Arc::new(OAuthProviderClient::new(
+        config,
+        &config.oidc,
+        &realm,
+        None,
+        metrics.clone(),
+    )?);
+    let state_store = state_store_connect(config, postgres_state.clone(), &metrics).await?;
+    let jwt_issuer = Arc::new(JwtIssuer::new(config, &config.jwks).await?);
     let session_config = Arc::new(SessionCookieConfig::from(&config.security.session));
+    let account_linking_config = Arc::new(AccountLinkingConfig::new(
+        &config.security.account_linking,
+        &config.oidc,
+    ));
+    let admin_credential = Arc::new(AdminCredential::from(&config.admin));
+    let clients = Arc::new(ClientRegistry::new(&config.clients));
+    let logout_notifier = Arc::new(LogoutNotifier::new(
+        config,
+        jwt_issuer.clone(),
+        clients.clone(),
+        postgres_state.clone(),
+        &realm,
+    )?);
+    let lockout_tracker = Arc::new(LockoutTracker::new(
+        &config.security.lockout,
+        state_store.clone(),
+        &realm,
+    ));
+    let audit_log = Arc::new(AuditLog::new(postgres_state.clone(), &realm));
+    let trusted_proxies = TrustedProxies::new(&config.server.trusted_proxies);
+    let rate_limiting = &config.security.rate_limiting;
+    let rate_limiter = if rate_limiting.enabled {
+        let shared_counter = match rate_limiting.backend.as_str() {
+            "memcached" => {
+                let pool = memcached_connect(config)?;
+                metrics.track_memcached_pool("rate_limit", pool.clone());
+                Some(pool)
+            }
+            _ => None,
+        };
+        let rate_limiter = Arc::new(RateLimiter::new(
+            rate_limiting,
+            trusted_proxies.clone(),
+            shared_counter,
+        ));
+        rate_limiter.start_cleanup(Duration::from_secs(rate_limiting.cleanup_interval));
+        Some(rate_limiter)
+    } else {
+        None
+    };
+    let cors = &config.security.cors;
+    let cors_policy = if cors.enabled {
+        Some(Arc::new(CorsPolicy::new(cors)?))
+    } else {
+        None
+    };
+
+    let security_headers = Arc::new(SecurityHeaders::new(&config.security.security_headers)?);
 
     Ok(AppState {
         oauth_provider_state,
         postgres_state,
-        memcached_state,
+        state_store,
         jwt_issuer,
         session_config,
+        account_linking_config,
+        admin_credential,
+        realm: Arc::new(realm),
+        clients,
+        logout_notifier,
+        lockout_tracker,
+        audit_log,
+        trusted_proxies: Arc::new(trusted_proxies),
+        rate_limiter,
+        cors_policy,
+        security_headers,
+        metrics,
+    })
+}
+
+/// DB 연결, 상태 저장소와 보안 설정은 기본 realm 과 공유하고 issuer, 키, provider 만 realm 별로 만듦
+pub async fn make_realm_app_state(
+    config: &Config,
+    realm_config: &RealmConfig,
+    default_state: &AppState,
+) -> Result<AppState> {
+    let realm = Realm::new(&realm_config.name);
+    let oauth_provider_state = Arc::new(OAuthProviderClient::new(
+        config,
+        &realm_config.oidc,
+        &realm,
+        realm_config.hosts.first().map(String::as_str),
+        default_state.metrics.clone(),
+    )?);
+    let jwt_issuer = Arc::new(JwtIssuer::new(config, &realm_config.jwks).await?);
+    let account_linking_config = Arc::new(AccountLinkingConfig::new(
+        &config.security.account_linking,
+        &realm_config.oidc,
+    ));
+    let clients = Arc::new(ClientRegistry::new(&realm_config.clients));
+    let logout_notifier = Arc::new(LogoutNotifier::new(
+        config,
+        jwt_issuer.clone(),
+        clients.clone(),
+        default_state.postgres_state.clone(),
+        &realm,
+    )?);
+
+    let lockout_tracker = Arc::new(LockoutTracker::new(
+        &config.security.lockout,
+        default_state.state_store.clone(),
+        &realm,
+    ));
+    let audit_log = Arc::new(AuditLog::new(default_state.postgres_state.clone(), &realm));
+
+    Ok(AppState {
+        oauth_provider_state,
+        jwt_issuer,
+        account_linking_config,
+        realm: Arc::new(realm),
+        clients,
+        logout_notifier,
+        lockout_tracker,
+        audit_log,
+        ..default_state.clone()
     })
 }
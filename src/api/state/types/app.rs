@@ -1,21 +1,44 @@
 use axum::extract::FromRef;
-use deadpool::managed::Pool;
-use deadpool_memcached::Manager;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
-use crate::api::{
-    state::types::{jwt_issuer::JwtIssuer, oauth_client::OAuthProviderClient},
-    types::session::SessionCookieConfig,
+use crate::{
+    api::{
+        state::types::{
+            audit_log::AuditLog, jwt_issuer::JwtIssuer, lockout_tracker::LockoutTracker,
+            logout_notifier::LogoutNotifier, metrics::Metrics, oauth_client::OAuthProviderClient,
+        },
+        types::{
+            account_linking::AccountLinkingConfig, admin::AdminCredential, client::ClientRegistry,
+            client_ip::TrustedProxies, cors::CorsPolicy, rate_limit::RateLimiter, realm::Realm,
+            security_headers::SecurityHeaders, session::SessionCookieConfig,
+        },
+    },
+    state_store::StateStore,
 };
 
 #[derive(Clone)]
 pub struct AppState {
     pub oauth_provider_state: Arc<OAuthProviderClient>,
     pub postgres_state: Arc<DatabaseConnection>,
-    pub memcached_state: Arc<Pool<Manager>>,
+    pub state_store: Arc<dyn StateStore>,
     pub jwt_issuer: Arc<JwtIssuer>,
     pub session_config: Arc<SessionCookieConfig>,
+    pub account_linking_config: Arc<AccountLinkingConfig>,
+    pub admin_credential: Arc<AdminCredential>,
+    pub realm: Arc<Realm>,
+    pub clients: Arc<ClientRegistry>,
+    pub logout_notifier: Arc<LogoutNotifier>,
+    pub lockout_tracker: Arc<LockoutTracker>,
+    pub audit_log: Arc<AuditLog>,
+    pub trusted_proxies: Arc<TrustedProxies>,
+    /// 모든 realm 이 함께 씀. 비활성화하면 없음
+    pub rate_limiter: Option<Arc<RateLimiter>>,
+    /// 모든 realm 이 함께 씀. 비활성화하면 없음
+    pub cors_policy: Option<Arc<CorsPolicy>>,
+    pub security_headers: Arc<SecurityHeaders>,
+    /// 모든 realm 이 함께 씀
+    pub metrics: Arc<Metrics>,
 }
 
 impl FromRef<AppState> for Arc<OAuthProviderClient> {
@@ -30,9 +53,9 @@ impl FromRef<AppState> for Arc<DatabaseConnection> {
     }
 }
 
-impl FromRef<AppState> for Arc<Pool<Manager>> {
+impl FromRef<AppState> for Arc<dyn StateStore> {
     fn from_ref(input: &AppState) -> Self {
-        input.memcached_state.clone()
+        input.state_store.clone()
     }
 }
 
@@ -47,3 +70,57 @@ impl FromRef<AppState> for Arc<SessionCookieConfig> {
         input.session_config.clone()
     }
 }
+
+impl FromRef<AppState> for Arc<AccountLinkingConfig> {
+    fn from_ref(input: &AppState) -> Self {
+        input.account_linking_config.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<AdminCredential> {
+    fn from_ref(input: &AppState) -> Self {
+        input.admin_credential.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<Realm> {
+    fn from_ref(input: &AppState) -> Self {
+        input.realm.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<ClientRegistry> {
+    fn from_ref(input: &AppState) -> Self {
+        input.clients.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<LogoutNotifier> {
+    fn from_ref(input: &AppState) -> Self {
+        input.logout_notifier.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<TrustedProxies> {
+    fn from_ref(input: &AppState) -> Self {
+        input.trusted_proxies.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<LockoutTracker> {
+    fn from_ref(input: &AppState) -> Self {
+        input.lockout_tracker.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<AuditLog> {
+    fn from_ref(input: &AppState) -> Self {
+        input.audit_log.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<Metrics> {
+    fn from_ref(input: &AppState) -> Self {
+        input.metrics.clone()
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use sea_orm::DatabaseConnection;
+use tracing::{info, warn};
+
+use crate::{
+    api::types::{audit::AuditEvent, realm::Realm},
+    db::repo::audit_events::AuditEventsRepo,
+};
+
+/// realm 의 감사 이벤트를 `audit_events` 에 남기는 구조체
+/// 기록에 실패해도 요청은 그대로 처리함
+pub struct AuditLog {
+    db_client: Arc<DatabaseConnection>,
+    realm: String,
+}
+
+impl AuditLog {
+    pub fn new(db_client: Arc<DatabaseConnection>, realm: &Realm) -> Self {
+        Self {
+            db_client,
+            realm: realm.name.clone(),
+        }
+    }
+
+    pub async fn record(&self, event: AuditEvent) {
+        info!(
+            target: "audit",
+            "{} {} actor={:?} client={:?} ip={:?} reason={:?}",
+            event.event_type.as_str(),
+            event.outcome.as_str(),
+            event.actor_id,
+            event.client_id,
+            event.ip_address,
+            event.reason
+        );
+
+        if let Err(err) = AuditEventsRepo::new(self.db_client.as_ref())
+            .create_audit_event(&self.realm, event)
+            .await
+        {
+            warn!("fail to record audit event: {}", err);
+        }
+    }
+}
@@ -8,11 +8,25 @@ use jsonwebtoken::{
     },
 };
 use ring::signature::KeyPair;
-use std::{collections::HashMap, path::PathBuf};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use uuid::Uuid;
 
-use crate::{api::types::jwt_claim::Claims, config::types::Config};
+use crate::{
+    api::types::{
+        admin::ADMIN_SCOPE,
+        jwt_claim::{
+            BACKCHANNEL_LOGOUT_EVENT, Claims, IdTokenClaims, LogoutEvent, LogoutTokenClaims,
+            TokenAuthorization,
+        },
+    },
+    config::types::{Config, JwksConfig},
+};
+
+const LOGOUT_TOKEN_TTL: i64 = 120;
 
 pub struct JwtIssuer {
     header: jsonwebtoken::Header,
@@ -21,8 +35,7 @@ pub struct JwtIssuer {
     aud: String,
     access_token_ttl: i64,
     refresh_token_ttl: i64,
-    key_rotation_interval: u64,
-    algorithm: String,
+    admin_user_ids: HashSet<Uuid>,
 }
 
 pub struct JwtKeyPair {
@@ -32,14 +45,15 @@ pub struct JwtKeyPair {
 }
 
 impl JwtIssuer {
-    pub async fn new(config: &Config) -> Result<Self> {
+    /// realm 마다 issuer 와 서명 키가 다르므로 `jwks` 는 realm 의 설정을 받음
+    pub async fn new(config: &Config, jwks: &JwksConfig) -> Result<Self> {
         let header = jsonwebtoken::Header::new(jsonwebtoken::Algorithm::EdDSA);
-        let iss = config.jwks.iss.clone();
-        let aud = config.jwks.aud.clone();
+        let iss = jwks.iss.clone();
+        let aud = jwks.aud.clone();
 
         let mut key_pairs = HashMap::new();
-        for jwk_config in &config.jwks.keys {
-            let key_path_string = format!("{}/{}.pem", config.jwks.keys_path, jwk_config.kid);
+        for jwk_config in &jwks.keys {
+            let key_path_string = format!("{}/{}.pem", jwks.keys_path, jwk_config.kid);
             let key_pair = read_or_generate_jwks_pkcs8(key_path_string.into()).await?;
             key_pairs.insert(jwk_config.kid, key_pair);
         }
@@ -51,8 +65,7 @@ impl JwtIssuer {
             key_pairs,
             access_token_ttl: config.security.jwt.access_token_ttl as i64,
             refresh_token_ttl: config.security.jwt.refresh_token_ttl as i64,
-            key_rotation_interval: config.security.jwt.key_rotation_interval,
-            algorithm: config.security.jwt.algorithm.clone(),
+            admin_user_ids: config.admin.user_ids.iter().cloned().collect(),
         })
     }
 
@@ -64,7 +77,21 @@ impl JwtIssuer {
         self.access_token_ttl
     }
 
-    pub fn issue_jwt(&self, kid: Uuid, sub: Uuid, ttl: i64) -> Result<String> {
+    pub fn get_refresh_token_ttl(&self) -> i64 {
+        self.refresh_token_ttl
+    }
+
+    pub fn get_aud(&self) -> &str {
+        &self.aud
+    }
+
+    pub fn issue_jwt(
+        &self,
+        kid: Uuid,
+        sub: Uuid,
+        ttl: i64,
+        authorization: TokenAuthorization,
+    ) -> Result<String> {
         let mut header = self.header.clone();
         header.kid = Some(kid.to_string());
 
@@ -80,11 +107,21 @@ impl JwtIssuer {
         let claim = Claims {
             aud: self.aud.clone(),
             iss: self.iss.clone(),
-            sub: sub,
+            sub,
             exp: (now + chrono::Duration::seconds(ttl)).timestamp(),
             jti: Uuid::now_v7(),
             iat: now.timestamp(),
             nbf: now.timestamp(),
+            // 역할은 realm 관리 API 로 누구에게나 줄 수 있으므로 전역 admin scope 는 설정의 사용자만 받음
+            scope: self
+                .admin_user_ids
+                .contains(&sub)
+                .then(|| ADMIN_SCOPE.to_string()),
+            roles: authorization.roles,
+            permissions: authorization.permissions,
+            org_id: authorization.org_id,
+            org_role: authorization.org_role,
+            groups: authorization.groups,
         };
 
         let jwt = jsonwebtoken::encode(&header, &claim, &private_key)
@@ -94,6 +131,125 @@ impl JwtIssuer {
         Ok(jwt)
     }
 
+    /// access token 과 같은 키와 수명으로 서명
+    pub fn issue_id_token(
+        &self,
+        sub: Uuid,
+        client_id: &str,
+        nonce: Option<String>,
+        auth_time: i64,
+        sid: Uuid,
+    ) -> Result<String> {
+        let kid = self.get_kid();
+        let mut header = self.header.clone();
+        header.kid = Some(kid.to_string());
+
+        let private_key = &self
+            .key_pairs
+            .get(&kid)
+            .ok_or_else(|| anyhow!("fail to get jwt key pair"))?
+            .private_key;
+
+        let now = chrono::Utc::now();
+        let claim = IdTokenClaims {
+            iss: self.iss.clone(),
+            sub,
+            aud: client_id.to_string(),
+            exp: (now + chrono::Duration::seconds(self.access_token_ttl)).timestamp(),
+            iat: now.timestamp(),
+            auth_time,
+            nonce,
+            sid,
+        };
+
+        jsonwebtoken::encode(&header, &claim, private_key)
+            .map_err(|e| anyhow!("fail to encode id token: {}", e))
+            .context("fail to issue id token")
+    }
+
+    /// 클라이언트가 바로 처리해야 하므로 수명을 짧게 둠
+    pub fn issue_logout_token(
+        &self,
+        sub: Uuid,
+        client_id: &str,
+        sid: Option<Uuid>,
+    ) -> Result<String> {
+        let kid = self.get_kid();
+        let mut header = self.header.clone();
+        header.kid = Some(kid.to_string());
+        header.typ = Some("logout+jwt".to_string());
+
+        let private_key = &self
+            .key_pairs
+            .get(&kid)
+            .ok_or_else(|| anyhow!("fail to get jwt key pair"))?
+            .private_key;
+
+        let now = chrono::Utc::now();
+        let claim = LogoutTokenClaims {
+            iss: self.iss.clone(),
+            sub,
+            aud: client_id.to_string(),
+            iat: now.timestamp(),
+            exp: (now + chrono::Duration::seconds(LOGOUT_TOKEN_TTL)).timestamp(),
+            jti: Uuid::now_v7(),
+            events: HashMap::from([(BACKCHANNEL_LOGOUT_EVENT.to_string(), LogoutEvent::default())]),
+            sid,
+        };
+
+        jsonwebtoken::encode(&header, &claim, private_key)
+            .map_err(|e| anyhow!("fail to encode logout token: {}", e))
+            .context("fail to issue logout token")
+    }
+
+    pub fn get_iss(&self) -> &str {
+        &self.iss
+    }
+
+    /// 로그아웃 요청의 `id_token_hint`. 만료된 id token 도 받고, 만료된 지 얼마나 됐는지와 `aud` 는 호출한 쪽에서 확인
+    pub fn verify_id_token_hint(&self, token: &str) -> Result<IdTokenClaims> {
+        let header = jsonwebtoken::decode_header(token).context("fail to decode jwt header")?;
+        let kid = header
+            .kid
+            .and_then(|kid| kid.parse::<Uuid>().ok())
+            .ok_or_else(|| anyhow!("jwt kid is missing"))?;
+        let public_key = &self
+            .key_pairs
+            .get(&kid)
+            .ok_or_else(|| anyhow!("unknown jwt kid: {}", kid))?
+            .public_key;
+
+        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::EdDSA);
+        validation.validate_exp = false;
+        validation.validate_aud = false;
+        validation.set_issuer(&[&self.iss]);
+
+        let token_data = jsonwebtoken::decode::<IdTokenClaims>(token, public_key, &validation)
+            .context("fail to verify id token hint")?;
+        Ok(token_data.claims)
+    }
+
+    pub fn verify_jwt(&self, token: &str) -> Result<Claims> {
+        let header = jsonwebtoken::decode_header(token).context("fail to decode jwt header")?;
+        let kid = header
+            .kid
+            .and_then(|kid| kid.parse::<Uuid>().ok())
+            .ok_or_else(|| anyhow!("jwt kid is missing"))?;
+        let public_key = &self
+            .key_pairs
+            .get(&kid)
+            .ok_or_else(|| anyhow!("unknown jwt kid: {}", kid))?
+            .public_key;
+
+        let mut validation = jsonwebtoken::Validation::new(jsonwebtoken::Algorithm::EdDSA);
+        validation.set_audience(&[&self.aud]);
+        validation.set_issuer(&[&self.iss]);
+
+        let token_data = jsonwebtoken::decode::<Claims>(token, public_key, &validation)
+            .context("fail to verify jwt")?;
+        Ok(token_data.claims)
+    }
+
     pub fn jwks(&self) -> Result<JwkSet> {
         let mut keys = Vec::new();
         for (kid, key_pair) in self.key_pairs.iter() {
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use tracing::warn;
+
+use crate::{
+    api::types::{
+        lockout::{LockoutSubject, block_after_failures, retry_after},
+        realm::Realm,
+    },
+    config::types::LockoutSecurityConfig,
+    state_store::{
+        StateStore,
+        repo::{
+            add_lockout_index_entry, delete_lockout, get_lockout, get_lockout_delay,
+            get_lockout_failures, get_lockout_index, increment_lockout_failures, save_lockout,
+            save_lockout_delay,
+        },
+        types::LockoutRecord,
+    },
+    utils::error::AllForOneError,
+};
+
+/// 토큰 엔드포인트, 클라이언트 인증, 로그인 콜백의 인증 실패를 상태 저장소에 세는 구조체
+/// 실패 횟수는 저장소의 원자적 카운터로 세서 동시에 실패해도 빠지지 않음.
+/// 저장소를 쓸 수 없으면 로그만 남기고 막지 않음
+pub struct LockoutTracker {
+    config: LockoutSecurityConfig,
+    state_store: Arc<dyn StateStore>,
+    realm: String,
+}
+
+impl LockoutTracker {
+    pub fn new(
+        config: &LockoutSecurityConfig,
+        state_store: Arc<dyn StateStore>,
+        realm: &Realm,
+    ) -> Self {
+        Self {
+            config: config.clone(),
+            state_store,
+            realm: realm.name.clone(),
+        }
+    }
+
+    /// 막힌 대상이 있으면 남은 시간만큼 429
+    pub async fn check(&self, subjects: &[&LockoutSubject]) -> Result<(), AllForOneError> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        for subject in subjects {
+            let key = subject.key();
+            let retry_after = match self.blocked_for(&key, now).await {
+                Ok(retry_after) => retry_after,
+                Err(err) => {
+                    warn!("fail to check lockout of {}: {:#}", key, err);
+                    continue;
+                }
+            };
+            if let Some(retry_after) = retry_after {
+                warn!(target: "security", "blocked token request by {}", key);
+                return Err(AllForOneError::TooManyRequests(retry_after));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub async fn record_failure(&self, subjects: &[&LockoutSubject]) {
+        if !self.config.enabled {
+            return;
+        }
+
+        for subject in subjects {
+            let key = subject.key();
+            if let Err(err) = self.add_failure(&key).await {
+                warn!(
+                    "fail to record authentication failure of {}: {:#}",
+                    key, err
+                );
+            }
+        }
+    }
+
+    /// 성공하면 그동안의 실패를 잊음
+    pub async fn record_success(&self, subjects: &[&LockoutSubject]) {
+        if !self.config.enabled {
+            return;
+        }
+
+        for subject in subjects {
+            let key = subject.key();
+            if let Err(err) = delete_lockout(self.state_store.as_ref(), &self.store_key(&key)).await
+            {
+                warn!("fail to reset lockout of {}: {:#}", key, err);
+            }
+        }
+    }
+
+    /// 지금 잠겨 있는 대상. 잠금은 `lockout_duration` 안에 풀리므로 지금과 바로 전 구간의 목록만 봄
+    pub async fn list(&self) -> Result<Vec<(String, LockoutRecord)>> {
+        let now = chrono::Utc::now().timestamp();
+        let bucket = self.index_bucket(now);
+        let mut lockouts: Vec<(String, LockoutRecord)> = Vec::new();
+        for bucket in [bucket - 1, bucket] {
+            for key in get_lockout_index(self.state_store.as_ref(), &self.realm, bucket).await? {
+                if lockouts.iter().any(|(subject, _)| *subject == key) {
+                    continue;
+                }
+                if let Some(record) =
+                    get_lockout(self.state_store.as_ref(), &self.store_key(&key)).await?
+                    && record.blocked_until > now
+                {
+                    lockouts.push((key, record));
+                }
+            }
+        }
+        Ok(lockouts)
+    }
+
+    /// 잠금이 없으면 지금까지의 실패 횟수와 지연을 돌려줌
+    pub async fn get(&self, key: &str) -> Result<Option<LockoutRecord>> {
+        let store = self.state_store.as_ref();
+        let store_key = self.store_key(key);
+        if let Some(record) = get_lockout(store, &store_key).await? {
+            return Ok(Some(record));
+        }
+
+        let failures = get_lockout_failures(store, &store_key).await?;
+        let delay = get_lockout_delay(store, &store_key).await?;
+        if failures.is_none() && delay.is_none() {
+            return Ok(None);
+        }
+        let mut record = delay.unwrap_or_default();
+        if let Some(failures) = failures {
+            record.failures = failures as u32;
+        }
+        Ok(Some(record))
+    }
+
+    /// 목록의 칸은 잠금이 풀리면 무시되므로 따로 지우지 않음
+    pub async fn clear(&self, key: &str) -> Result<()> {
+        delete_lockout(self.state_store.as_ref(), &self.store_key(key)).await
+    }
+
+    async fn add_failure(&self, key: &str) -> Result<()> {
+        let now = chrono::Utc::now().timestamp();
+        let store = self.state_store.as_ref();
+        let store_key = self.store_key(key);
+        let failures =
+            increment_lockout_failures(store, &store_key, self.config.failure_window).await?;
+        let record = block_after_failures(failures as u32, &self.config, now);
+
+        if record.locked {
+            warn!(
+                target: "security",
+                "lock {} for {} seconds after {} failures",
+                key,
+                self.config.lockout_duration,
+                record.failures
+            );
+            save_lockout(store, &store_key, &record, self.config.lockout_duration).await?;
+            add_lockout_index_entry(
+                store,
+                &self.realm,
+                self.index_bucket(now),
+                key,
+                self.config.lockout_duration * 2,
+            )
+            .await?;
+        } else if let Some(delay) = retry_after(&record, now) {
+            save_lockout_delay(store, &store_key, &record, delay).await?;
+        }
+        Ok(())
+    }
+
+    /// 잠금과 지연 중 더 오래 막는 쪽
+    async fn blocked_for(&self, key: &str, now: i64) -> Result<Option<u64>> {
+        let store = self.state_store.as_ref();
+        let store_key = self.store_key(key);
+        let lockout = get_lockout(store, &store_key).await?;
+        let delay = get_lockout_delay(store, &store_key).await?;
+        Ok(lockout
+            .iter()
+            .chain(delay.iter())
+            .filter_map(|record| retry_after(record, now))
+            .max())
+    }
+
+    /// 잠긴 대상 목록을 `lockout_duration` 길이의 구간으로 나눈 번호
+    fn index_bucket(&self, now: i64) -> i64 {
+        now / self.config.lockout_duration.max(1) as i64
+    }
+
+    /// realm 이 저장소를 같이 쓰므로 realm 이름을 붙임
+    fn store_key(&self, key: &str) -> String {
+        format!("{}:{}", self.realm, key)
+    }
+}
@@ -0,0 +1,222 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use reqwest::redirect::Policy;
+use sea_orm::{DatabaseConnection, TransactionTrait, prelude::DateTimeWithTimeZone};
+use tokio::{sync::Notify, task::JoinSet};
+use tracing::{info, warn};
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        state::types::jwt_issuer::JwtIssuer,
+        types::{
+            client::ClientRegistry,
+            logout::{
+                BACKCHANNEL_LOGOUT_ATTEMPTS, backchannel_logout_retry_delay,
+                frontchannel_logout_url,
+            },
+            realm::Realm,
+        },
+    },
+    config::types::Config,
+    db::repo::logouts::LogoutsRepo,
+    entity::backchannel_logouts,
+};
+
+/// back-channel 로그아웃 요청 timeout(초)
+const BACKCHANNEL_LOGOUT_TIMEOUT: u64 = 5;
+/// 큐를 다시 보는 주기. 새 로그아웃이 들어오면 바로 깨어남
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+/// 한 번에 보낼 로그아웃 수
+const BATCH_SIZE: u64 = 100;
+/// 로그아웃 없이 끝난 세션의 기록을 지우는 주기
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// 로그아웃을 그 세션에서 토큰을 받은 클라이언트에 알리는 구조체
+/// back-channel 로그아웃은 DB 큐에 남겨서 재시작해도 다시 보냄
+pub struct LogoutNotifier {
+    http_client: reqwest::Client,
+    jwt_issuer: Arc<JwtIssuer>,
+    clients: Arc<ClientRegistry>,
+    db_client: Arc<DatabaseConnection>,
+    realm: String,
+    /// 세션 기록을 남겨 두는 시간. 세션에서 받은 refresh token 보다 오래 남김
+    session_client_ttl: u64,
+    wake: Notify,
+}
+
+impl LogoutNotifier {
+    pub fn new(
+        config: &Config,
+        jwt_issuer: Arc<JwtIssuer>,
+        clients: Arc<ClientRegistry>,
+        db_client: Arc<DatabaseConnection>,
+        realm: &Realm,
+    ) -> Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .user_agent(config.server.user_agent.clone())
+            .timeout(Duration::from_secs(BACKCHANNEL_LOGOUT_TIMEOUT))
+            .build()
+            .context("fail to make logout http client")?;
+
+        Ok(Self {
+            http_client,
+            jwt_issuer,
+            clients,
+            db_client,
+            realm: realm.name.clone(),
+            session_client_ttl: config.security.jwt.refresh_token_ttl,
+            wake: Notify::new(),
+        })
+    }
+
+    /// 끝난 세션에서 토큰을 받은 클라이언트에 back-channel 로그아웃을 큐에 넣고
+    /// 브라우저에서 iframe 으로 열 front-channel 로그아웃 주소를 돌려줌
+    /// `sid` 가 없으면 사용자의 모든 세션
+    pub async fn notify_logout(&self, user_id: Uuid, sid: Option<Uuid>) -> Result<Vec<String>> {
+        let txn = self.db_client.begin().await?;
+        let repo = LogoutsRepo::new(&txn);
+        let client_ids = repo
+            .take_session_clients(&self.realm, user_id, sid)
+            .await
+            .context("fail to get session clients")?;
+        let clients: Vec<_> = client_ids
+            .iter()
+            .filter_map(|client_id| self.clients.get(client_id))
+            .collect();
+
+        let backchannel_client_ids: Vec<&str> = clients
+            .iter()
+            .filter(|client| client.backchannel_logout_uri.is_some())
+            .map(|client| client.client_id.as_str())
+            .collect();
+        repo.enqueue_backchannel_logouts(&self.realm, user_id, sid, &backchannel_client_ids)
+            .await
+            .context("fail to enqueue back-channel logouts")?;
+        txn.commit().await?;
+        if !backchannel_client_ids.is_empty() {
+            self.wake.notify_one();
+        }
+
+        Ok(clients
+            .iter()
+            .filter_map(|client| client.frontchannel_logout_uri.as_deref())
+            .map(|logout_uri| frontchannel_logout_url(logout_uri, self.jwt_issuer.get_iss(), sid))
+            .collect())
+    }
+
+    /// 큐에서 보낼 때가 된 로그아웃을 보내는 백그라운드 작업
+    pub fn start(self: Arc<Self>) {
+        tokio::spawn(async move {
+            let mut last_cleanup: Option<Instant> = None;
+            loop {
+                if let Err(err) = self.deliver_due().await {
+                    warn!("fail to send back-channel logouts: {:#}", err);
+                }
+                if last_cleanup.is_none_or(|at| at.elapsed() >= CLEANUP_INTERVAL) {
+                    last_cleanup = Some(Instant::now());
+                    if let Err(err) = self.delete_expired_session_clients().await {
+                        warn!("fail to delete expired session clients: {:#}", err);
+                    }
+                }
+                let _ = tokio::time::timeout(POLL_INTERVAL, self.wake.notified()).await;
+            }
+        });
+    }
+
+    /// 느린 클라이언트가 다른 클라이언트의 로그아웃을 막지 않도록 동시에 보냄
+    async fn deliver_due(self: &Arc<Self>) -> Result<()> {
+        let repo = LogoutsRepo::new(self.db_client.as_ref());
+        let now = chrono::Utc::now();
+        let mut tasks = JoinSet::new();
+        for logout in repo
+            .list_due_backchannel_logouts(&self.realm, now.into(), BATCH_SIZE)
+            .await?
+        {
+            // 실패하면 다시 보낼 시각을 미리 정해 두므로 보내는 중에 멈춰도 다시 보냄
+            let delay = BACKCHANNEL_LOGOUT_TIMEOUT
+                + backchannel_logout_retry_delay((logout.attempts + 1) as u32);
+            let next_attempt_at = now + Duration::from_secs(delay);
+            if !repo
+                .claim_backchannel_logout(&logout, next_attempt_at.into())
+                .await?
+            {
+                continue;
+            }
+            let notifier = self.clone();
+            tasks.spawn(async move { notifier.deliver(logout).await });
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            if let Err(err) = result.context("back-channel logout task is failed")? {
+                warn!("fail to finish back-channel logout: {:#}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn deliver(&self, logout: backchannel_logouts::Model) -> Result<()> {
+        let repo = LogoutsRepo::new(self.db_client.as_ref());
+        let attempts = (logout.attempts + 1) as u32;
+        let Some(logout_uri) = self
+            .clients
+            .get(&logout.client_id)
+            .and_then(|client| client.backchannel_logout_uri.clone())
+        else {
+            warn!(
+                "back-channel logout uri of {} is not configured",
+                logout.client_id
+            );
+            return Ok(repo.delete_backchannel_logout(logout.id).await?);
+        };
+        let logout_token = self
+            .jwt_issuer
+            .issue_logout_token(logout.user_id, &logout.client_id, logout.session_id)
+            .with_context(|| format!("fail to issue logout token for {}", logout.client_id))?;
+
+        let result = self
+            .http_client
+            .post(&logout_uri)
+            .form(&[("logout_token", logout_token)])
+            .send()
+            .await;
+        let error = match result {
+            Ok(response) if response.status().is_success() => {
+                info!("back-channel logout is delivered to {}", logout.client_id);
+                return Ok(repo.delete_backchannel_logout(logout.id).await?);
+            }
+            Ok(response) => format!("client responded with {}", response.status()),
+            Err(err) => err.to_string(),
+        };
+
+        if attempts >= BACKCHANNEL_LOGOUT_ATTEMPTS {
+            warn!(
+                "back-channel logout to {} failed: {}, give up after {} attempts",
+                logout.client_id, error, attempts
+            );
+            repo.delete_backchannel_logout(logout.id).await?;
+        } else {
+            warn!(
+                "back-channel logout to {} failed: {} (attempt {})",
+                logout.client_id, error, attempts
+            );
+        }
+        Ok(())
+    }
+
+    async fn delete_expired_session_clients(&self) -> Result<()> {
+        let before = chrono::Utc::now() - Duration::from_secs(self.session_client_ttl);
+        let deleted = LogoutsRepo::new(self.db_client.as_ref())
+            .delete_expired_session_clients(DateTimeWithTimeZone::from(before))
+            .await?;
+        if deleted > 0 {
+            info!("delete {} expired session clients", deleted);
+        }
+        Ok(())
+    }
+}
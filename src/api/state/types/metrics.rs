@@ -0,0 +1,249 @@
+use std::{
+    sync::{Mutex, PoisonError},
+    time::Duration,
+};
+
+use anyhow::{Context, Result};
+use deadpool::managed::Pool;
+use deadpool_memcached::Manager;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use sea_orm::DatabaseConnection;
+use tracing::warn;
+
+use crate::{
+    api::response::types::token::Token, config::types::MetricsConfig, utils::token::hash_token,
+};
+
+/// provider 호출은 수 초까지 걸릴 수 있어 기본 bucket 보다 넓게 잡음
+const PROVIDER_LATENCY_BUCKETS: [f64; 10] = [0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 20.0, 30.0];
+
+/// `/metrics` 로 내보내는 Prometheus 지표. 모든 realm 이 함께 씀
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    logins: IntCounterVec,
+    tokens_issued: IntCounterVec,
+    provider_request_duration: HistogramVec,
+    db_pool_connections: IntGaugeVec,
+    memcached_pool_connections: IntGaugeVec,
+    /// scrape 할 때 상태를 읽을 memcached pool 과 그 용도
+    memcached_pools: Mutex<Vec<(String, Pool<Manager>)>>,
+    token_hash: Option<String>,
+    public: bool,
+}
+
+impl Metrics {
+    pub fn new(config: &MetricsConfig) -> Result<Self> {
+        if config.enabled && config.token.is_none() && !config.public {
+            warn!(
+                "metrics token is not configured, /metrics answers 401 unless metrics.public is set"
+            );
+        }
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests by route and status"),
+            &["realm", "method", "route", "status"],
+        )?;
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "HTTP request latency by route",
+            ),
+            &["realm", "method", "route"],
+        )?;
+        let logins = IntCounterVec::new(
+            Opts::new("logins_total", "Provider logins by outcome"),
+            &["realm", "provider", "outcome"],
+        )?;
+        let tokens_issued = IntCounterVec::new(
+            Opts::new("tokens_issued_total", "Issued tokens by type and grant"),
+            &["realm", "token_type", "grant_type"],
+        )?;
+        let provider_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "provider_request_duration_seconds",
+                "Upstream identity provider call latency",
+            )
+            .buckets(PROVIDER_LATENCY_BUCKETS.to_vec()),
+            &["provider", "endpoint", "outcome"],
+        )?;
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new(
+                "db_pool_connections",
+                "Database pool connections by state (idle, in_use, max)",
+            ),
+            &["state"],
+        )?;
+        let memcached_pool_connections = IntGaugeVec::new(
+            Opts::new(
+                "memcached_pool_connections",
+                "Memcached pool connections by state (available, in_use, waiting, max)",
+            ),
+            &["pool", "state"],
+        )?;
+
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_request_duration.clone()))?;
+        registry.register(Box::new(logins.clone()))?;
+        registry.register(Box::new(tokens_issued.clone()))?;
+        registry.register(Box::new(provider_request_duration.clone()))?;
+        registry.register(Box::new(db_pool_connections.clone()))?;
+        registry.register(Box::new(memcached_pool_connections.clone()))?;
+
+        Ok(Self {
+            registry,
+            http_requests,
+            http_request_duration,
+            logins,
+            tokens_issued,
+            provider_request_duration,
+            db_pool_connections,
+            memcached_pool_connections,
+            memcached_pools: Mutex::new(Vec::new()),
+            token_hash: config.token.as_deref().map(hash_token),
+            public: config.public,
+        })
+    }
+
+    /// token 을 설정하지 않았으면 `public` 일 때만 볼 수 있음. 비교는 `AdminCredential` 처럼 해시끼리
+    pub fn allows(&self, token: Option<&str>) -> bool {
+        match &self.token_hash {
+            Some(token_hash) => token.is_some_and(|token| *token_hash == hash_token(token)),
+            None => self.public,
+        }
+    }
+
+    /// `route` 는 경로 변수를 채우지 않은 라우트 패턴
+    pub fn record_http_request(
+        &self,
+        realm: &str,
+        method: &str,
+        route: &str,
+        status: u16,
+        elapsed: Duration,
+    ) {
+        let method = http_method_label(method);
+        self.http_requests
+            .with_label_values(&[realm, method, route, &status.to_string()])
+            .inc();
+        self.http_request_duration
+            .with_label_values(&[realm, method, route])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn record_login(&self, realm: &str, provider: &str, outcome: &str) {
+        self.logins
+            .with_label_values(&[realm, provider, outcome])
+            .inc();
+    }
+
+    pub fn record_tokens(&self, realm: &str, grant_type: &str, token: &Token) {
+        let mut token_types = vec!["access_token", "refresh_token"];
+        if token.id_token.is_some() {
+            token_types.push("id_token");
+        }
+        for token_type in token_types {
+            self.tokens_issued
+                .with_label_values(&[realm, token_type, grant_type])
+                .inc();
+        }
+    }
+
+    /// `endpoint` 는 token, user 처럼 provider 의 어느 API 인지
+    pub fn record_provider_request(
+        &self,
+        provider: &str,
+        endpoint: &str,
+        success: bool,
+        elapsed: Duration,
+    ) {
+        let outcome = if success { "success" } else { "failure" };
+        self.provider_request_duration
+            .with_label_values(&[provider, endpoint, outcome])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn track_memcached_pool(&self, name: &str, pool: Pool<Manager>) {
+        self.memcached_pools
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((name.to_string(), pool));
+    }
+
+    /// pool 상태를 지금 값으로 갱신하고 text format 으로 내보냄
+    pub fn render(&self, db: &DatabaseConnection) -> Result<String> {
+        self.update_pool_gauges(db);
+
+        let mut buffer = Vec::new();
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buffer)
+            .context("fail to encode metrics")?;
+        String::from_utf8(buffer).context("metrics are not utf-8")
+    }
+
+    fn update_pool_gauges(&self, db: &DatabaseConnection) {
+        let db_pool = match db {
+            DatabaseConnection::SqlxPostgresPoolConnection(_) => {
+                let pool = db.get_postgres_connection_pool();
+                Some((
+                    pool.size(),
+                    pool.num_idle() as u32,
+                    pool.options().get_max_connections(),
+                ))
+            }
+            DatabaseConnection::SqlxSqlitePoolConnection(_) => {
+                let pool = db.get_sqlite_connection_pool();
+                Some((
+                    pool.size(),
+                    pool.num_idle() as u32,
+                    pool.options().get_max_connections(),
+                ))
+            }
+            _ => None,
+        };
+        if let Some((size, idle, max)) = db_pool {
+            self.db_pool_connections
+                .with_label_values(&["idle"])
+                .set(idle as i64);
+            self.db_pool_connections
+                .with_label_values(&["in_use"])
+                .set(size.saturating_sub(idle) as i64);
+            self.db_pool_connections
+                .with_label_values(&["max"])
+                .set(max as i64);
+        }
+
+        let pools = self
+            .memcached_pools
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        for (name, pool) in pools.iter() {
+            let status = pool.status();
+            let states = [
+                ("available", status.available),
+                ("in_use", status.size.saturating_sub(status.available)),
+                ("waiting", status.waiting),
+                ("max", status.max_size),
+            ];
+            for (state, value) in states {
+                self.memcached_pool_connections
+                    .with_label_values(&[name.as_str(), state])
+                    .set(value as i64);
+            }
+        }
+    }
+}
+
+/// 임의의 method 로 label 이 끝없이 늘어나지 않도록 표준 method 외에는 `other` 로 묶음
+fn http_method_label(method: &str) -> &str {
+    match method {
+        "GET" | "HEAD" | "POST" | "PUT" | "DELETE" | "CONNECT" | "OPTIONS" | "TRACE" | "PATCH" => {
+            method
+        }
+        _ => "other",
+    }
+}
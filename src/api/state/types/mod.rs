@@ -1,3 +1,8 @@
 pub mod app;
+pub mod audit_log;
 pub mod jwt_issuer;
+pub mod lockout_tracker;
+pub mod logout_notifier;
+pub mod metrics;
 pub mod oauth_client;
+pub mod webhook_dispatcher;
@@ -1,12 +1,14 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Instant};
 
 use crate::{
-    config::types::Config,
+    api::{state::types::metrics::Metrics, types::realm::Realm},
+    config::types::{Config, OIDCProviderConfig},
     provider::{
         github::GithubAuthenticator,
         types::{
             config::{AuthRedirectInfo, Authentication, OAuthClientConfig},
             idp::OAuthProvider,
+            profile::UserProfile,
         },
     },
 };
@@ -16,28 +18,46 @@ use url::Url;
 #[derive(Clone)]
 pub struct OAuthProviderClient {
     pub github: Arc<GithubAuthenticator>,
+    metrics: Arc<Metrics>,
 }
 
 impl OAuthProviderClient {
-    pub fn new(config: &Config) -> Result<Self> {
-        let redirect_url_str = "/api/v1/oauth/github/callback";
+    /// host 가 지정된 realm 은 그 host 로, 아니면 `/realms/{name}` 경로로 callback 을 받음
+    pub fn new(
+        config: &Config,
+        oidc: &OIDCProviderConfig,
+        realm: &Realm,
+        host: Option<&str>,
+        metrics: Arc<Metrics>,
+    ) -> Result<Self> {
         let mut base_url = Url::parse(&config.server.domain).context("fail to parse domain url")?;
         base_url.set_port(Some(config.server.port)).unwrap();
 
+        let redirect_url_str = match host {
+            Some(host) => {
+                base_url
+                    .set_host(Some(host))
+                    .with_context(|| format!("fail to set realm host: {}", host))?;
+                "/api/v1/oauth/github/callback".to_string()
+            }
+            None => format!("{}/api/v1/oauth/github/callback", realm.path_prefix()),
+        };
+
         let mut github_redirect_url = base_url.clone();
-        github_redirect_url.set_path(redirect_url_str);
+        github_redirect_url.set_path(&redirect_url_str);
 
         let github_config = OAuthClientConfig {
-            client_id: config.oidc.github.client_id.clone(),
-            client_secret: config.oidc.github.client_secret.clone(),
-            auth_url: config.oidc.github.auth_url.clone(),
-            token_url: config.oidc.github.token_url.clone(),
-            resource_url: config.oidc.github.resource_url.clone(),
+            client_id: oidc.github.client_id.clone(),
+            client_secret: oidc.github.client_secret.clone(),
+            auth_url: oidc.github.auth_url.clone(),
+            token_url: oidc.github.token_url.clone(),
+            resource_url: oidc.github.resource_url.clone(),
             redirect_url: github_redirect_url,
         };
 
         Ok(OAuthProviderClient {
             github: Arc::new(GithubAuthenticator::new(github_config)?),
+            metrics,
         })
     }
 
@@ -53,19 +73,39 @@ impl OAuthProviderClient {
         authorization_code: String,
         pkce_verifier: String,
     ) -> Result<String> {
-        match idp {
+        let started_at = Instant::now();
+        let result = match idp {
             OAuthProvider::Github => {
                 self.github
                     .clone()
                     .callback(authorization_code, pkce_verifier)
                     .await
             }
-        }
+        };
+        self.metrics.record_provider_request(
+            idp.as_str(),
+            "token",
+            result.is_ok(),
+            started_at.elapsed(),
+        );
+        result
     }
 
-    pub async fn get_user_info(&self, idp: OAuthProvider, access_token: String) -> Result<String> {
-        match idp {
-            OAuthProvider::Github => self.github.get_user_info(access_token).await,
-        }
+    pub async fn get_user_profile(
+        &self,
+        idp: OAuthProvider,
+        access_token: String,
+    ) -> Result<UserProfile> {
+        let started_at = Instant::now();
+        let result = match idp {
+            OAuthProvider::Github => self.github.get_user_profile(access_token).await,
+        };
+        self.metrics.record_provider_request(
+            idp.as_str(),
+            "user",
+            result.is_ok(),
+            started_at.elapsed(),
+        );
+        result
     }
 }
@@ -0,0 +1,218 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result};
+use reqwest::{header::CONTENT_TYPE, redirect::Policy};
+use sea_orm::{DatabaseConnection, TransactionTrait, prelude::DateTimeWithTimeZone};
+use tokio::task::JoinSet;
+use tracing::{info, warn};
+
+use crate::{
+    api::types::webhook::{
+        WEBHOOK_EVENT_HEADER, WEBHOOK_ID_HEADER, WEBHOOK_SIGNATURE_HEADER, WebhookEndpoint,
+        retry_delay, webhook_signature,
+    },
+    config::types::{Config, WebhooksConfig},
+    db::repo::webhooks::WebhooksRepo,
+    entity::webhook_deliveries,
+};
+
+/// 한 번에 처리할 이벤트/전송 수
+const BATCH_SIZE: u64 = 100;
+/// 요청 timeout 에 더해 전송 중인 작업을 잡아 두는 시간(초)
+const LEASE_MARGIN: u64 = 30;
+/// 보관 기간이 지난 기록을 지우는 주기
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// `webhook_events` 를 endpoint 별 전송으로 나누고 때가 된 전송을 보내는 백그라운드 작업
+/// 상태는 모두 DB 에 있으므로 여러 replica 가 함께 돌아도 같은 전송을 두 번 가져가지 않음
+pub struct WebhookDispatcher {
+    config: WebhooksConfig,
+    endpoints: Vec<WebhookEndpoint>,
+    http_client: reqwest::Client,
+    db_client: Arc<DatabaseConnection>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(config: &Config, db_client: Arc<DatabaseConnection>) -> Result<Self> {
+        let http_client = reqwest::Client::builder()
+            .redirect(Policy::none())
+            .user_agent(config.server.user_agent.clone())
+            .timeout(Duration::from_secs(config.webhooks.timeout))
+            .build()
+            .context("fail to make webhook http client")?;
+
+        Ok(Self {
+            config: config.webhooks.clone(),
+            endpoints: config
+                .webhooks
+                .endpoints
+                .iter()
+                .map(WebhookEndpoint::from)
+                .collect(),
+            http_client,
+            db_client,
+        })
+    }
+
+    /// `poll_interval` 마다 실행
+    pub fn start(self: Arc<Self>) {
+        info!(
+            "webhook dispatcher is running with {} endpoints",
+            self.endpoints.len()
+        );
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(Duration::from_secs(self.config.poll_interval));
+            let mut last_cleanup: Option<Instant> = None;
+            loop {
+                interval.tick().await;
+                if let Err(err) = self.run_once().await {
+                    warn!("fail to dispatch webhooks: {:#}", err);
+                }
+                if last_cleanup.is_none_or(|at| at.elapsed() >= CLEANUP_INTERVAL) {
+                    last_cleanup = Some(Instant::now());
+                    if let Err(err) = self.delete_expired().await {
+                        warn!("fail to delete expired webhook deliveries: {:#}", err);
+                    }
+                }
+            }
+        });
+    }
+
+    pub async fn run_once(self: &Arc<Self>) -> Result<()> {
+        self.dispatch_events().await?;
+        self.deliver_due().await
+    }
+
+    /// 받을 endpoint 가 없는 이벤트도 나눈 것으로 표시해서 다시 보지 않음
+    async fn dispatch_events(&self) -> Result<()> {
+        let events = WebhooksRepo::new(self.db_client.as_ref())
+            .list_undispatched_events(BATCH_SIZE)
+            .await?;
+        for event in events {
+            let endpoints: Vec<&str> = self
+                .endpoints
+                .iter()
+                .filter(|endpoint| endpoint.accepts(&event.event_type, &event.realm))
+                .map(|endpoint| endpoint.name.as_str())
+                .collect();
+
+            let txn = self.db_client.begin().await?;
+            WebhooksRepo::new(&txn)
+                .dispatch_event(&event, &endpoints)
+                .await?;
+            txn.commit().await?;
+        }
+        Ok(())
+    }
+
+    /// 느린 endpoint 가 다른 전송을 막지 않도록 가져간 전송을 동시에 보냄
+    async fn deliver_due(self: &Arc<Self>) -> Result<()> {
+        let repo = WebhooksRepo::new(self.db_client.as_ref());
+        let now = chrono::Utc::now();
+        let lease_until = now + Duration::from_secs(self.config.timeout + LEASE_MARGIN);
+        let mut tasks = JoinSet::new();
+        for delivery in repo.list_due_deliveries(now.into(), BATCH_SIZE).await? {
+            if !repo.claim_delivery(&delivery, lease_until.into()).await? {
+                continue;
+            }
+            let dispatcher = self.clone();
+            tasks.spawn(async move { dispatcher.deliver(delivery).await });
+        }
+
+        while let Some(result) = tasks.join_next().await {
+            if let Err(err) = result.context("webhook delivery task is failed")? {
+                warn!("fail to finish webhook delivery: {:#}", err);
+            }
+        }
+        Ok(())
+    }
+
+    async fn deliver(&self, delivery: webhook_deliveries::Model) -> Result<()> {
+        let repo = WebhooksRepo::new(self.db_client.as_ref());
+        let attempts = (delivery.attempts + 1) as u32;
+        let Some(endpoint) = self
+            .endpoints
+            .iter()
+            .find(|endpoint| endpoint.name == delivery.endpoint)
+        else {
+            repo.mark_failed(
+                delivery.id,
+                None,
+                "endpoint is not configured".to_string(),
+                None,
+            )
+            .await?;
+            return Ok(());
+        };
+        let Some(event) = repo.get_event(delivery.event_id).await? else {
+            repo.mark_failed(delivery.id, None, "event is not found".to_string(), None)
+                .await?;
+            return Ok(());
+        };
+
+        let signature = webhook_signature(
+            &endpoint.secret,
+            chrono::Utc::now().timestamp(),
+            &event.payload,
+        );
+        let result = self
+            .http_client
+            .post(&endpoint.url)
+            .header(CONTENT_TYPE, "application/json")
+            .header(WEBHOOK_ID_HEADER, event.id.to_string())
+            .header(WEBHOOK_EVENT_HEADER, &event.event_type)
+            .header(WEBHOOK_SIGNATURE_HEADER, signature)
+            .body(event.payload)
+            .send()
+            .await;
+
+        let (status_code, error) = match result {
+            Ok(response) if response.status().is_success() => {
+                info!(
+                    "webhook {} is delivered to {}",
+                    event.event_type, endpoint.name
+                );
+                repo.mark_delivered(delivery.id, response.status().as_u16() as i32)
+                    .await?;
+                return Ok(());
+            }
+            Ok(response) => (
+                Some(response.status().as_u16() as i32),
+                format!("endpoint responded with {}", response.status()),
+            ),
+            Err(err) => (None, err.to_string()),
+        };
+
+        let next_attempt_at = retry_delay(attempts, &self.config).map(|delay| {
+            DateTimeWithTimeZone::from(chrono::Utc::now() + Duration::from_secs(delay))
+        });
+        match next_attempt_at {
+            Some(_) => warn!(
+                "webhook {} to {} failed: {} (attempt {})",
+                event.event_type, endpoint.name, error, attempts
+            ),
+            None => warn!(
+                "webhook {} to {} failed: {}, give up after {} attempts",
+                event.event_type, endpoint.name, error, attempts
+            ),
+        }
+        repo.mark_failed(delivery.id, status_code, error, next_attempt_at)
+            .await?;
+        Ok(())
+    }
+
+    async fn delete_expired(&self) -> Result<()> {
+        let before = chrono::Utc::now() - Duration::from_secs(self.config.retention);
+        let deleted = WebhooksRepo::new(self.db_client.as_ref())
+            .delete_expired(before.into())
+            .await?;
+        if deleted > 0 {
+            info!("delete {} expired webhook records", deleted);
+        }
+        Ok(())
+    }
+}
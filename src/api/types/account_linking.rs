@@ -0,0 +1,44 @@
+use crate::{
+    config::types::{AccountLinkingSecurityConfig, OIDCProviderConfig},
+    provider::types::idp::OAuthProvider,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AccountLinkingPolicy {
+    Disabled,
+    Auto,
+    Prompt,
+}
+
+/// 검증된 email 기반 계정 자동 연결 설정을 미리 변환해서 저장하는 구조체
+#[derive(Clone)]
+pub struct AccountLinkingConfig {
+    pub policy: AccountLinkingPolicy,
+    pub email_verified_idps: Vec<OAuthProvider>,
+}
+
+impl AccountLinkingConfig {
+    /// 정책은 공통이고, 신뢰할 provider 는 realm 의 provider 설정을 따름
+    pub fn new(linking: &AccountLinkingSecurityConfig, oidc: &OIDCProviderConfig) -> Self {
+        let policy = match linking.policy.as_str() {
+            "auto" => AccountLinkingPolicy::Auto,
+            "prompt" => AccountLinkingPolicy::Prompt,
+            _ => AccountLinkingPolicy::Disabled, // 기본값
+        };
+
+        let mut email_verified_idps = Vec::new();
+        if oidc.github.email_verified {
+            email_verified_idps.push(OAuthProvider::Github);
+        }
+
+        Self {
+            policy,
+            email_verified_idps,
+        }
+    }
+
+    /// 정책이 켜져 있고 provider 의 email 검증을 신뢰할 때만 email 로 계정을 연결
+    pub fn links_by_email(&self, idp: &OAuthProvider) -> bool {
+        self.policy != AccountLinkingPolicy::Disabled && self.email_verified_idps.contains(idp)
+    }
+}
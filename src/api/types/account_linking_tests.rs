@@ -0,0 +1,40 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        api::types::account_linking::{AccountLinkingConfig, AccountLinkingPolicy},
+        provider::types::idp::OAuthProvider,
+    };
+
+    #[test]
+    fn test_links_by_email_requires_enabled_policy() {
+        let linking_config = AccountLinkingConfig {
+            policy: AccountLinkingPolicy::Disabled,
+            email_verified_idps: vec![OAuthProvider::Github],
+        };
+        assert!(!linking_config.links_by_email(&OAuthProvider::Github));
+    }
+
+    #[test]
+    fn test_links_by_email_requires_trusted_provider() {
+        let linking_config = AccountLinkingConfig {
+            policy: AccountLinkingPolicy::Auto,
+            email_verified_idps: vec![],
+        };
+        assert!(!linking_config.links_by_email(&OAuthProvider::Github));
+    }
+
+    #[test]
+    fn test_links_by_email_with_trusted_provider() {
+        let auto_config = AccountLinkingConfig {
+            policy: AccountLinkingPolicy::Auto,
+            email_verified_idps: vec![OAuthProvider::Github],
+        };
+        assert!(auto_config.links_by_email(&OAuthProvider::Github));
+
+        let prompt_config = AccountLinkingConfig {
+            policy: AccountLinkingPolicy::Prompt,
+            email_verified_idps: vec![OAuthProvider::Github],
+        };
+        assert!(prompt_config.links_by_email(&OAuthProvider::Github));
+    }
+}
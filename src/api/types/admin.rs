@@ -0,0 +1,26 @@
+use crate::{config::types::AdminConfig, utils::token::hash_token};
+
+pub const ADMIN_SCOPE: &str = "admin";
+
+/// 고정 admin credential 을 해시로만 들고 있는 구조체
+#[derive(Clone)]
+pub struct AdminCredential {
+    pub token_hash: Option<String>,
+}
+
+impl From<&AdminConfig> for AdminCredential {
+    fn from(config: &AdminConfig) -> Self {
+        Self {
+            token_hash: config.token.as_deref().map(hash_token),
+        }
+    }
+}
+
+impl AdminCredential {
+    /// 원본끼리 비교하지 않고 해시를 비교해 비교 시간이 credential 에 따라 달라지지 않게 함
+    pub fn matches(&self, token: &str) -> bool {
+        self.token_hash
+            .as_deref()
+            .is_some_and(|token_hash| token_hash == hash_token(token))
+    }
+}
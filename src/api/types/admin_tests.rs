@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use crate::{api::types::admin::AdminCredential, config::types::AdminConfig};
+
+    #[test]
+    fn test_admin_credential_matches_configured_token() {
+        let config = AdminConfig {
+            token: Some("0123456789abcdef0123456789abcdef".to_string()),
+            user_ids: vec![],
+        };
+        let credential = AdminCredential::from(&config);
+
+        assert!(credential.matches("0123456789abcdef0123456789abcdef"));
+        assert!(!credential.matches("0123456789abcdef0123456789abcdeX"));
+    }
+
+    #[test]
+    fn test_admin_credential_without_token_never_matches() {
+        let credential = AdminCredential::from(&AdminConfig::default());
+        assert!(credential.token_hash.is_none());
+        assert!(!credential.matches(""));
+    }
+}
@@ -0,0 +1,96 @@
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::api::extractor::device_info::DeviceInfo;
+
+/// 감사 로그에 남기는 이벤트 종류
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditEventType {
+    /// provider 콜백으로 로그인. CSRF/state 불일치 같은 콜백 실패도 포함
+    Login,
+    /// 토큰 엔드포인트의 code 교환과 refresh token 회전
+    TokenIssue,
+    /// 첫 로그인으로 사용자가 만들어짐
+    UserCreate,
+    /// SSO 세션 종료
+    Logout,
+}
+
+impl AuditEventType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Login => "login",
+            Self::TokenIssue => "token_issue",
+            Self::UserCreate => "user_create",
+            Self::Logout => "logout",
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failure,
+}
+
+impl AuditOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failure => "failure",
+        }
+    }
+}
+
+/// 한 번의 감사 이벤트. 요청한 기기 정보는 항상 함께 남김
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    pub event_type: AuditEventType,
+    pub outcome: AuditOutcome,
+    pub actor_id: Option<Uuid>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub reason: Option<String>,
+}
+
+impl AuditEvent {
+    pub fn success(event_type: AuditEventType, device: &DeviceInfo) -> Self {
+        Self::new(event_type, AuditOutcome::Success, device)
+    }
+
+    pub fn failure(event_type: AuditEventType, device: &DeviceInfo, reason: &str) -> Self {
+        Self {
+            reason: Some(reason.to_string()),
+            ..Self::new(event_type, AuditOutcome::Failure, device)
+        }
+    }
+
+    pub fn actor(self, actor_id: Uuid) -> Self {
+        Self {
+            actor_id: Some(actor_id),
+            ..self
+        }
+    }
+
+    pub fn client(self, client_id: Option<&str>) -> Self {
+        Self {
+            client_id: client_id.map(str::to_string),
+            ..self
+        }
+    }
+
+    fn new(event_type: AuditEventType, outcome: AuditOutcome, device: &DeviceInfo) -> Self {
+        Self {
+            event_type,
+            outcome,
+            actor_id: None,
+            client_id: None,
+            ip_address: device.ip_address.clone(),
+            user_agent: device.user_agent.clone(),
+            reason: None,
+        }
+    }
+}
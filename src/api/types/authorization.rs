@@ -0,0 +1,48 @@
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use url::Url;
+
+/// authorization code 수명. 클라이언트가 바로 교환해야 함
+pub const AUTHORIZATION_CODE_TTL: u64 = 60;
+
+pub const PKCE_METHOD_S256: &str = "S256";
+
+/// authorize 요청의 `prompt`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Prompt {
+    /// SSO 세션이 있으면 쓰고, 없으면 provider 로 로그인
+    Default,
+    /// SSO 세션이 없으면 로그인 화면 없이 `login_required` 로 실패
+    None,
+    /// provider 에 재인증을 강제할 방법이 없어서 `login_required` 로 실패
+    Login,
+}
+
+impl Prompt {
+    /// 지원하지 않는 값은 무시하고, `none` 과 다른 값을 함께 쓰면 `None` 을 돌려줌
+    pub fn parse(prompt: Option<&str>) -> Option<Self> {
+        let values: Vec<&str> = prompt.unwrap_or_default().split_whitespace().collect();
+        match (values.contains(&"none"), values.contains(&"login")) {
+            (true, _) if values.len() > 1 => None,
+            (true, _) => Some(Self::None),
+            (false, true) => Some(Self::Login),
+            (false, false) => Some(Self::Default),
+        }
+    }
+}
+
+/// S256 방식만 지원
+pub fn verify_pkce(code_verifier: &str, code_challenge: &str) -> bool {
+    let digest = ring::digest::digest(&ring::digest::SHA256, code_verifier.as_bytes());
+    BASE64_URL_SAFE_NO_PAD.encode(digest.as_ref()) == code_challenge
+}
+
+/// 클라이언트의 redirect_uri 에 응답 파라미터를 붙임
+pub fn authorization_redirect_url(redirect_uri: &str, params: &[(&str, &str)]) -> String {
+    match Url::parse(redirect_uri) {
+        Ok(mut url) => {
+            url.query_pairs_mut().extend_pairs(params);
+            url.to_string()
+        }
+        Err(_) => redirect_uri.to_string(),
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod tests {
+    use crate::api::types::authorization::{Prompt, authorization_redirect_url, verify_pkce};
+
+    #[test]
+    fn test_prompt_parse() {
+        assert_eq!(Prompt::parse(None), Some(Prompt::Default));
+        assert_eq!(Prompt::parse(Some("")), Some(Prompt::Default));
+        assert_eq!(Prompt::parse(Some("none")), Some(Prompt::None));
+        assert_eq!(Prompt::parse(Some("login")), Some(Prompt::Login));
+        assert_eq!(Prompt::parse(Some("login consent")), Some(Prompt::Login));
+        assert_eq!(Prompt::parse(Some("consent")), Some(Prompt::Default));
+        assert_eq!(Prompt::parse(Some("none login")), None);
+    }
+
+    #[test]
+    fn test_verify_pkce_s256() {
+        // RFC 7636 Appendix B
+        let verifier = "dBjftJeZ4CVP-mB92K27uhbUJU1p1r_wW1gFWFOEjXk";
+        let challenge = "E9Melhoa2OwvFrEMTJguCHaoeK1t8URWbuGJSstw-cM";
+
+        assert!(verify_pkce(verifier, challenge));
+        assert!(!verify_pkce("other-verifier", challenge));
+    }
+
+    #[test]
+    fn test_authorization_redirect_url() {
+        let url = authorization_redirect_url(
+            "https://app.example.com/callback?tenant=a",
+            &[("code", "abc"), ("state", "x y")],
+        );
+        assert_eq!(
+            url,
+            "https://app.example.com/callback?tenant=a&code=abc&state=x+y"
+        );
+    }
+}
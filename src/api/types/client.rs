@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+
+use crate::{config::types::ClientConfig, utils::token::hash_token};
+
+/// realm 에 등록된 클라이언트
+#[derive(Clone, Debug)]
+pub struct Client {
+    pub client_id: String,
+    pub redirect_uris: Vec<String>,
+    pub post_logout_redirect_uris: Vec<String>,
+    pub backchannel_logout_uri: Option<String>,
+    pub frontchannel_logout_uri: Option<String>,
+    /// secret 은 해시로만 들고 있음. 없으면 public client
+    pub secret_hash: Option<String>,
+}
+
+impl Client {
+    /// redirect_uri 는 등록된 값과 정확히 일치해야 함
+    pub fn allows_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.redirect_uris.iter().any(|uri| uri == redirect_uri)
+    }
+
+    /// admin credential 과 같이 해시끼리 비교
+    pub fn authenticate(&self, client_secret: &str) -> bool {
+        self.secret_hash
+            .as_deref()
+            .is_some_and(|secret_hash| secret_hash == hash_token(client_secret))
+    }
+
+    pub fn allows_post_logout_redirect_uri(&self, redirect_uri: &str) -> bool {
+        self.post_logout_redirect_uris
+            .iter()
+            .any(|uri| uri == redirect_uri)
+    }
+}
+
+/// client_id 로 클라이언트를 찾는 구조체
+#[derive(Clone, Default)]
+pub struct ClientRegistry {
+    clients: HashMap<String, Client>,
+}
+
+impl ClientRegistry {
+    pub fn new(clients: &[ClientConfig]) -> Self {
+        Self {
+            clients: clients
+                .iter()
+                .map(|client| {
+                    (
+                        client.client_id.clone(),
+                        Client {
+                            client_id: client.client_id.clone(),
+                            redirect_uris: client.redirect_uris.clone(),
+                            post_logout_redirect_uris: client.post_logout_redirect_uris.clone(),
+                            backchannel_logout_uri: client.backchannel_logout_uri.clone(),
+                            frontchannel_logout_uri: client.frontchannel_logout_uri.clone(),
+                            secret_hash: client.client_secret.as_deref().map(hash_token),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn get(&self, client_id: &str) -> Option<&Client> {
+        self.clients.get(client_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Client> {
+        self.clients.values()
+    }
+}
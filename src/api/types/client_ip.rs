@@ -0,0 +1,44 @@
+use std::{collections::HashSet, net::IpAddr};
+
+use axum::http::HeaderMap;
+
+/// `X-Forwarded-For` 를 붙여주는 신뢰하는 프록시
+#[derive(Clone, Debug, Default)]
+pub struct TrustedProxies {
+    proxies: HashSet<IpAddr>,
+}
+
+impl TrustedProxies {
+    pub fn new(proxies: &[IpAddr]) -> Self {
+        Self {
+            proxies: proxies.iter().cloned().collect(),
+        }
+    }
+
+    /// 연결한 주소가 신뢰하는 프록시면 `X-Forwarded-For` 를 오른쪽부터 따라가며
+    /// 신뢰하지 않는 첫 주소를 클라이언트로 봄. 클라이언트가 넣은 왼쪽 값은 무시됨
+    pub fn client_ip(&self, headers: &HeaderMap, peer: Option<IpAddr>) -> Option<IpAddr> {
+        let mut client_ip = peer?;
+        if !self.proxies.contains(&client_ip) {
+            return Some(client_ip);
+        }
+
+        let forwarded_for = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .flat_map(|value| value.split(','))
+            .collect::<Vec<_>>();
+        for forwarded in forwarded_for.into_iter().rev() {
+            let Ok(forwarded_ip) = forwarded.trim().parse::<IpAddr>() else {
+                break;
+            };
+            client_ip = forwarded_ip;
+            if !self.proxies.contains(&client_ip) {
+                break;
+            }
+        }
+
+        Some(client_ip)
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use axum::http::{HeaderMap, HeaderValue};
+
+    use crate::api::types::client_ip::TrustedProxies;
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn forwarded_for(value: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", HeaderValue::from_str(value).unwrap());
+        headers
+    }
+
+    #[test]
+    fn test_client_ip_ignores_headers_from_untrusted_peer() {
+        let trusted_proxies = TrustedProxies::new(&[ip("10.0.0.1")]);
+        let headers = forwarded_for("203.0.113.7");
+
+        assert_eq!(
+            trusted_proxies.client_ip(&headers, Some(ip("198.51.100.2"))),
+            Some(ip("198.51.100.2"))
+        );
+        assert_eq!(trusted_proxies.client_ip(&headers, None), None);
+    }
+
+    #[test]
+    fn test_client_ip_follows_trusted_proxy_chain() {
+        let trusted_proxies = TrustedProxies::new(&[ip("10.0.0.1"), ip("10.0.0.2")]);
+
+        // 클라이언트가 넣은 가장 왼쪽 값은 믿지 않음
+        let headers = forwarded_for("1.1.1.1, 203.0.113.7, 10.0.0.2");
+        assert_eq!(
+            trusted_proxies.client_ip(&headers, Some(ip("10.0.0.1"))),
+            Some(ip("203.0.113.7"))
+        );
+
+        let headers = forwarded_for("not-an-ip, 10.0.0.2");
+        assert_eq!(
+            trusted_proxies.client_ip(&headers, Some(ip("10.0.0.1"))),
+            Some(ip("10.0.0.2"))
+        );
+
+        assert_eq!(
+            trusted_proxies.client_ip(&HeaderMap::new(), Some(ip("10.0.0.1"))),
+            Some(ip("10.0.0.1"))
+        );
+    }
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use crate::{api::types::client::ClientRegistry, config::types::ClientConfig};
+
+    #[test]
+    fn test_client_registry_redirect_uri_exact_match() {
+        let registry = ClientRegistry::new(&[ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec!["https://app.example.com/".to_string()],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }]);
+
+        let client = registry.get("web").unwrap();
+        assert!(client.allows_redirect_uri("https://app.example.com/callback"));
+        assert!(!client.allows_redirect_uri("https://app.example.com/callback/"));
+        assert!(!client.allows_redirect_uri("https://evil.example.com/callback"));
+        assert!(client.allows_post_logout_redirect_uri("https://app.example.com/"));
+        assert!(!client.allows_post_logout_redirect_uri("https://app.example.com/callback"));
+        assert!(registry.get("unknown").is_none());
+    }
+
+    #[test]
+    fn test_client_authenticate_with_secret() {
+        let registry = ClientRegistry::new(&[
+            ClientConfig {
+                client_id: "api".to_string(),
+                redirect_uris: vec!["https://api.example.com/callback".to_string()],
+                post_logout_redirect_uris: vec![],
+                backchannel_logout_uri: None,
+                frontchannel_logout_uri: None,
+                client_secret: Some("0123456789abcdef0123456789abcdef".to_string()),
+            },
+            ClientConfig {
+                client_id: "web".to_string(),
+                redirect_uris: vec!["https://app.example.com/callback".to_string()],
+                post_logout_redirect_uris: vec![],
+                backchannel_logout_uri: None,
+                frontchannel_logout_uri: None,
+                client_secret: None,
+            },
+        ]);
+
+        let api = registry.get("api").unwrap();
+        assert!(api.authenticate("0123456789abcdef0123456789abcdef"));
+        assert!(!api.authenticate("wrong"));
+        // public client 는 어떤 secret 으로도 인증되지 않음
+        assert!(!registry.get("web").unwrap().authenticate(""));
+    }
+}
@@ -1 +1,3 @@
 pub const COOKIE_AUTH_REQUEST_ID: &str = "session_id";
+/// 로그인한 브라우저를 기억하는 SSO 세션
+pub const COOKIE_SSO_SESSION: &str = "sso_session";
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use axum::http::{HeaderName, Method};
+
+use crate::{api::types::realm::strip_realm_prefix, config::types::CorsConfig};
+
+/// 설정과 상관없이 CORS 를 허용하지 않는 경로. admin credential 이 브라우저에서 쓰이면 안 됨
+pub const ALWAYS_EXCLUDED_PATHS: [&str; 1] = ["/api/admin"];
+
+#[derive(Debug)]
+enum OriginPattern {
+    Any,
+    Exact(String),
+    /// `https://*.example.com` 은 scheme `https://` 와 suffix `.example.com`
+    Subdomain {
+        scheme: String,
+        suffix: String,
+    },
+}
+
+impl OriginPattern {
+    fn parse(origin: &str) -> Self {
+        let origin = origin.to_lowercase();
+        if origin == "*" {
+            return Self::Any;
+        }
+        match origin.split_once("://*.") {
+            Some((scheme, host)) => Self::Subdomain {
+                scheme: format!("{scheme}://"),
+                suffix: format!(".{host}"),
+            },
+            None => Self::Exact(origin),
+        }
+    }
+
+    fn matches(&self, origin: &str) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Exact(exact) => exact == origin,
+            Self::Subdomain { scheme, suffix } => origin
+                .strip_prefix(scheme.as_str())
+                .and_then(|host| host.strip_suffix(suffix.as_str()))
+                .is_some_and(|subdomain| {
+                    !subdomain.is_empty()
+                        && subdomain
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '.')
+                }),
+        }
+    }
+}
+
+/// 설정된 CORS 정책. origin 은 경로별 제외를 거친 뒤에만 허용
+#[derive(Debug)]
+pub struct CorsPolicy {
+    origins: Vec<OriginPattern>,
+    pub allowed_methods: Vec<Method>,
+    pub allowed_headers: Vec<HeaderName>,
+    pub max_age: Duration,
+    pub allow_credentials: bool,
+    excluded_paths: Vec<String>,
+}
+
+impl CorsPolicy {
+    pub fn new(config: &CorsConfig) -> Result<Self> {
+        let allowed_methods = config
+            .allowed_methods
+            .iter()
+            .map(|method| {
+                Method::from_bytes(method.to_uppercase().as_bytes())
+                    .with_context(|| format!("invalid CORS method: {method}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let allowed_headers = config
+            .allowed_headers
+            .iter()
+            .map(|header| {
+                HeaderName::from_bytes(header.as_bytes())
+                    .with_context(|| format!("invalid CORS header: {header}"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Self {
+            origins: config
+                .allowed_origins
+                .iter()
+                .map(|origin| OriginPattern::parse(origin))
+                .collect(),
+            allowed_methods,
+            allowed_headers,
+            max_age: Duration::from_secs(config.max_age),
+            allow_credentials: config.allow_credentials,
+            excluded_paths: ALWAYS_EXCLUDED_PATHS
+                .iter()
+                .map(|path| path.to_string())
+                .chain(config.excluded_paths.iter().cloned())
+                .collect(),
+        })
+    }
+
+    /// `path` 로 온 요청에 `origin` 을 허용하는지. realm 경로는 떼고 비교
+    pub fn allows(&self, origin: &str, path: &str) -> bool {
+        let path = strip_realm_prefix(path);
+        if self
+            .excluded_paths
+            .iter()
+            .any(|excluded| path.starts_with(excluded.as_str()))
+        {
+            return false;
+        }
+
+        let origin = origin.to_lowercase();
+        self.origins.iter().any(|pattern| pattern.matches(&origin))
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::Body,
+        http::{Method, Request, StatusCode},
+        routing::post,
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        api::{middleware::cors::cors_layer, types::cors::CorsPolicy},
+        config::types::CorsConfig,
+    };
+
+    fn cors_policy(allowed_origins: &[&str], excluded_paths: &[&str]) -> CorsPolicy {
+        CorsPolicy::new(&CorsConfig {
+            enabled: true,
+            allowed_origins: allowed_origins.iter().map(|s| s.to_string()).collect(),
+            allowed_methods: vec!["get".to_string(), "POST".to_string()],
+            allowed_headers: vec!["Content-Type".to_string(), "Authorization".to_string()],
+            max_age: 600,
+            allow_credentials: true,
+            excluded_paths: excluded_paths.iter().map(|s| s.to_string()).collect(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cors_policy_matches_exact_and_wildcard_origins() {
+        let policy = cors_policy(&["https://app.example.com", "https://*.example.org"], &[]);
+
+        assert!(policy.allows("https://app.example.com", "/api/v1/jwks"));
+        assert!(policy.allows("HTTPS://App.Example.com", "/api/v1/jwks"));
+        assert!(!policy.allows("http://app.example.com", "/api/v1/jwks"));
+        assert!(!policy.allows("https://app.example.com:8443", "/api/v1/jwks"));
+
+        assert!(policy.allows("https://shop.example.org", "/api/v1/jwks"));
+        assert!(policy.allows("https://a.b.example.org", "/api/v1/jwks"));
+        // wildcard 는 서브도메인만 뜻하고 상위 도메인이나 비슷한 이름은 아님
+        assert!(!policy.allows("https://example.org", "/api/v1/jwks"));
+        assert!(!policy.allows("https://evilexample.org", "/api/v1/jwks"));
+        assert!(!policy.allows("https://evil.com/.example.org", "/api/v1/jwks"));
+    }
+
+    #[test]
+    fn test_cors_policy_excludes_paths_in_every_realm() {
+        let policy = cors_policy(&["*"], &["/api/v1/oauth/authorize"]);
+
+        assert!(policy.allows("https://any.example.com", "/api/v1/oauth/token"));
+        assert!(!policy.allows("https://any.example.com", "/api/v1/oauth/authorize"));
+        assert!(!policy.allows(
+            "https://any.example.com",
+            "/realms/shop/api/v1/oauth/authorize"
+        ));
+        // admin API 는 설정에 없어도 항상 제외
+        assert!(!policy.allows("https://any.example.com", "/api/admin/users"));
+        assert!(!policy.allows("https://any.example.com", "/realms/shop/api/admin/users"));
+    }
+
+    #[test]
+    fn test_cors_policy_normalizes_methods() {
+        let policy = cors_policy(&["https://app.example.com"], &[]);
+        assert_eq!(policy.allowed_methods[0].as_str(), "GET");
+        assert_eq!(policy.max_age.as_secs(), 600);
+    }
+
+    async fn preflight(router: Router, origin: &str, path: &str) -> axum::response::Response {
+        router
+            .oneshot(
+                Request::builder()
+                    .method(Method::OPTIONS)
+                    .uri(path)
+                    .header("origin", origin)
+                    .header("access-control-request-method", "POST")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_cors_layer_answers_preflight_with_credentials() {
+        let policy = Arc::new(cors_policy(&["https://*.example.com"], &[]));
+        let router = Router::new()
+            .route("/api/v1/oauth/token", post(|| async { "token" }))
+            .route("/api/admin/users", post(|| async { "users" }))
+            .layer(cors_layer(policy));
+
+        let response = preflight(
+            router.clone(),
+            "https://app.example.com",
+            "/api/v1/oauth/token",
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let headers = response.headers();
+        assert_eq!(
+            headers["access-control-allow-origin"],
+            "https://app.example.com"
+        );
+        assert_eq!(headers["access-control-allow-credentials"], "true");
+        assert_eq!(headers["access-control-max-age"], "600");
+
+        let response = preflight(router, "https://app.example.com", "/api/admin/users").await;
+        assert!(
+            response
+                .headers()
+                .get("access-control-allow-origin")
+                .is_none()
+        );
+    }
+}
@@ -18,4 +18,4 @@ impl From<&JwtSecurityConfig> for JwtConfig {
             algorithm: config.algorithm.clone(),
         }
     }
-}
\ No newline at end of file
+}
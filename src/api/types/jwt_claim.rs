@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use sonic_rs::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,4 +12,71 @@ pub struct Claims {
     pub jti: Uuid,
     pub iat: i64,
     pub nbf: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub permissions: Vec<String>,
+    /// 토큰이 속한 조직. 하위 서비스는 이 값으로 테넌트를 격리
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_id: Option<Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub org_role: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+}
+
+/// 클라이언트에 로그인 결과를 알려주는 id token. `aud` 는 client_id
+#[derive(Deserialize, Serialize, Debug)]
+pub struct IdTokenClaims {
+    pub iss: String,
+    pub sub: Uuid,
+    pub aud: String,
+    pub exp: i64,
+    pub iat: i64,
+    /// provider 로 마지막으로 로그인한 시각. SSO 세션으로 재인증해도 바뀌지 않음
+    pub auth_time: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    /// SSO 세션 id
+    pub sid: Uuid,
+}
+
+pub const BACKCHANNEL_LOGOUT_EVENT: &str = "http://schemas.openid.net/event/backchannel-logout";
+
+/// back-channel 로그아웃으로 보내는 logout token. `sid` 가 없으면 사용자의 모든 세션을 끝냄
+#[derive(Deserialize, Serialize, Debug)]
+pub struct LogoutTokenClaims {
+    pub iss: String,
+    pub sub: Uuid,
+    pub aud: String,
+    pub iat: i64,
+    pub exp: i64,
+    pub jti: Uuid,
+    pub events: HashMap<String, LogoutEvent>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<Uuid>,
+}
+
+/// 빈 객체(`{}`)로 직렬화되는 이벤트 값
+#[derive(Deserialize, Serialize, Debug, Default)]
+pub struct LogoutEvent {}
+
+/// access token 에 실을 사용자 권한 정보
+#[derive(Default, Debug)]
+pub struct TokenAuthorization {
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+    pub org_id: Option<Uuid>,
+    pub org_role: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl Claims {
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scope
+            .as_deref()
+            .is_some_and(|scopes| scopes.split_whitespace().any(|s| s == scope))
+    }
 }
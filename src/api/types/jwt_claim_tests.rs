@@ -3,7 +3,11 @@ mod tests {
     use chrono::Utc;
     use uuid::Uuid;
 
-    use crate::api::types::jwt_claim::Claims;
+    use std::collections::HashMap;
+
+    use crate::api::types::jwt_claim::{
+        BACKCHANNEL_LOGOUT_EVENT, Claims, LogoutEvent, LogoutTokenClaims,
+    };
 
     #[test]
     fn test_claims_creation() {
@@ -19,6 +23,12 @@ mod tests {
             jti: jwt_id,
             iat: now,
             nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
         };
 
         assert_eq!(claims.aud, "test-audience");
@@ -44,6 +54,12 @@ mod tests {
             jti: jwt_id,
             iat: now,
             nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
         };
 
         let serialized = sonic_rs::to_string(&claims).unwrap();
@@ -83,6 +99,9 @@ mod tests {
         assert_eq!(claims.jti, jwt_id);
         assert_eq!(claims.iat, now);
         assert_eq!(claims.nbf, now);
+        assert_eq!(claims.scope, None);
+        assert!(claims.roles.is_empty());
+        assert!(claims.permissions.is_empty());
     }
 
     #[test]
@@ -99,10 +118,146 @@ mod tests {
             jti: jwt_id,
             iat: now,
             nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
         };
 
         let debug_str = format!("{:?}", claims);
         assert!(debug_str.contains("test-audience"));
         assert!(debug_str.contains("test-issuer"));
     }
+
+    #[test]
+    fn test_claims_has_scope() {
+        let now = Utc::now().timestamp();
+        let mut claims = Claims {
+            aud: "test-audience".to_string(),
+            iss: "test-issuer".to_string(),
+            sub: Uuid::now_v7(),
+            exp: now + 3600,
+            jti: Uuid::now_v7(),
+            iat: now,
+            nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
+        };
+        assert!(!claims.has_scope("admin"));
+
+        claims.scope = Some("openid admin".to_string());
+        assert!(claims.has_scope("admin"));
+        assert!(claims.has_scope("openid"));
+        assert!(!claims.has_scope("adm"));
+    }
+
+    #[test]
+    fn test_claims_scope_skipped_when_none() {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            aud: "test-audience".to_string(),
+            iss: "test-issuer".to_string(),
+            sub: Uuid::now_v7(),
+            exp: now + 3600,
+            jti: Uuid::now_v7(),
+            iat: now,
+            nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
+        };
+
+        let serialized = sonic_rs::to_string(&claims).unwrap();
+        assert!(!serialized.contains("scope"));
+        assert!(!serialized.contains("roles"));
+        assert!(!serialized.contains("permissions"));
+        assert!(!serialized.contains("org_id"));
+        assert!(!serialized.contains("groups"));
+    }
+
+    #[test]
+    fn test_claims_roles_and_permissions() {
+        let now = Utc::now().timestamp();
+        let claims = Claims {
+            aud: "test-audience".to_string(),
+            iss: "test-issuer".to_string(),
+            sub: Uuid::now_v7(),
+            exp: now + 3600,
+            jti: Uuid::now_v7(),
+            iat: now,
+            nbf: now,
+            scope: None,
+            roles: vec!["editor".to_string()],
+            permissions: vec!["posts:write".to_string()],
+            org_id: None,
+            org_role: None,
+            groups: vec![],
+        };
+
+        let serialized = sonic_rs::to_string(&claims).unwrap();
+        let deserialized: Claims = sonic_rs::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.roles, vec!["editor".to_string()]);
+        assert_eq!(deserialized.permissions, vec!["posts:write".to_string()]);
+    }
+
+    #[test]
+    fn test_claims_organization() {
+        let now = Utc::now().timestamp();
+        let org_id = Uuid::now_v7();
+        let claims = Claims {
+            aud: "test-audience".to_string(),
+            iss: "test-issuer".to_string(),
+            sub: Uuid::now_v7(),
+            exp: now + 3600,
+            jti: Uuid::now_v7(),
+            iat: now,
+            nbf: now,
+            scope: None,
+            roles: vec![],
+            permissions: vec![],
+            org_id: Some(org_id),
+            org_role: Some("admin".to_string()),
+            groups: vec!["backend".to_string(), "oncall".to_string()],
+        };
+
+        let serialized = sonic_rs::to_string(&claims).unwrap();
+        let deserialized: Claims = sonic_rs::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.org_id, Some(org_id));
+        assert_eq!(deserialized.org_role, Some("admin".to_string()));
+        assert_eq!(
+            deserialized.groups,
+            vec!["backend".to_string(), "oncall".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_logout_token_claims_serialization() {
+        let now = Utc::now().timestamp();
+        let claims = LogoutTokenClaims {
+            iss: "test-issuer".to_string(),
+            sub: Uuid::now_v7(),
+            aud: "web".to_string(),
+            iat: now,
+            exp: now + 120,
+            jti: Uuid::now_v7(),
+            events: HashMap::from([(BACKCHANNEL_LOGOUT_EVENT.to_string(), LogoutEvent::default())]),
+            sid: None,
+        };
+
+        let json = sonic_rs::to_string(&claims).unwrap();
+        assert!(
+            json.contains(r#""events":{"http://schemas.openid.net/event/backchannel-logout":{}}"#)
+        );
+        assert!(!json.contains("sid"));
+        assert!(!json.contains("nonce"));
+    }
 }
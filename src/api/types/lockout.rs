@@ -0,0 +1,81 @@
+use uuid::Uuid;
+
+use crate::{
+    api::types::client::ClientRegistry, config::types::LockoutSecurityConfig,
+    state_store::types::LockoutRecord,
+};
+
+/// 인증 실패를 따로 세는 대상
+#[derive(Debug, Clone)]
+pub enum LockoutSubject {
+    /// code 나 refresh token 으로 알아낸 사용자
+    Account(Uuid),
+    /// client_id 와 요청 IP 의 조합. 한 곳의 공격 때문에 클라이언트 전체가 잠기지 않도록 IP 별로 나눔
+    Client {
+        client_id: Option<String>,
+        ip_address: Option<String>,
+    },
+}
+
+impl LockoutSubject {
+    /// 등록되지 않은 client_id 는 마음대로 바꿔 가며 잠금을 피할 수 있어서 IP 만으로 셈
+    pub fn client(
+        clients: &ClientRegistry,
+        client_id: Option<&str>,
+        ip_address: Option<String>,
+    ) -> Self {
+        Self::Client {
+            client_id: client_id
+                .filter(|client_id| clients.get(client_id).is_some())
+                .map(str::to_string),
+            ip_address,
+        }
+    }
+
+    /// 관리 API 에서 대상을 가리키는 값. `account:{user_id}` 또는 `client:{client_id}@{ip}`
+    pub fn key(&self) -> String {
+        match self {
+            Self::Account(user_id) => format!("account:{}", user_id),
+            Self::Client {
+                client_id,
+                ip_address,
+            } => format!(
+                "client:{}@{}",
+                client_id.as_deref().unwrap_or("-"),
+                ip_address.as_deref().unwrap_or("-")
+            ),
+        }
+    }
+}
+
+/// 실패 window 안에서 `failures` 번째 실패 뒤의 기록.
+/// `free_attempts` 뒤로는 1, 2, 4 ... 초씩 막고 `max_failures` 에 닿으면 잠금
+pub fn block_after_failures(
+    failures: u32,
+    config: &LockoutSecurityConfig,
+    now: i64,
+) -> LockoutRecord {
+    let mut record = LockoutRecord {
+        failures,
+        ..Default::default()
+    };
+
+    if failures >= config.max_failures {
+        record.locked = true;
+        record.blocked_until = now + config.lockout_duration as i64;
+    } else if failures > config.free_attempts {
+        let exponent = (failures - config.free_attempts - 1).min(63);
+        let delay = 1u64
+            .checked_shl(exponent)
+            .unwrap_or(u64::MAX)
+            .min(config.max_delay);
+        record.blocked_until = now + delay as i64;
+    }
+
+    record
+}
+
+/// 막혀 있으면 다시 시도할 수 있을 때까지 남은 초
+pub fn retry_after(record: &LockoutRecord, now: i64) -> Option<u64> {
+    (record.blocked_until > now).then(|| (record.blocked_until - now) as u64)
+}
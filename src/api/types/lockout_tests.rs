@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        api::{
+            state::types::lockout_tracker::LockoutTracker,
+            types::{
+                client::ClientRegistry,
+                lockout::{LockoutSubject, block_after_failures, retry_after},
+                realm::Realm,
+            },
+        },
+        config::types::{ClientConfig, LockoutSecurityConfig},
+        state_store::memory_store::MemoryStateStore,
+        utils::error::AllForOneError,
+    };
+
+    fn lockout_config() -> LockoutSecurityConfig {
+        LockoutSecurityConfig {
+            enabled: true,
+            free_attempts: 2,
+            max_failures: 6,
+            max_delay: 4,
+            lockout_duration: 600,
+            failure_window: 300,
+        }
+    }
+
+    #[test]
+    fn test_block_after_failures_delays_progressively_then_locks() {
+        let config = lockout_config();
+        let now = 1_700_000_000;
+
+        let records: Vec<_> = (1..=6)
+            .map(|failures| block_after_failures(failures, &config, now))
+            .collect();
+        let delays: Vec<_> = records
+            .iter()
+            .map(|record| retry_after(record, now).unwrap_or(0))
+            .collect();
+        // 2 번은 그냥 통과하고 1, 2, 4 초, 상한 4 초 뒤 6 번째에 잠김
+        assert_eq!(delays, vec![0, 0, 1, 2, 4, 600]);
+        assert!(records[5].locked);
+        assert!(!records[4].locked);
+    }
+
+    #[tokio::test]
+    async fn test_lockout_tracker_counts_concurrent_failures() {
+        let mut config = lockout_config();
+        config.max_failures = 100;
+        let tracker = Arc::new(LockoutTracker::new(
+            &config,
+            Arc::new(MemoryStateStore::new()),
+            &Realm::new("default"),
+        ));
+        let account = LockoutSubject::Account(Uuid::now_v7());
+
+        let mut tasks = tokio::task::JoinSet::new();
+        for _ in 0..20 {
+            let tracker = tracker.clone();
+            let account = account.clone();
+            tasks.spawn(async move { tracker.record_failure(&[&account]).await });
+        }
+        tasks.join_all().await;
+        let record = tracker.get(&account.key()).await.unwrap().unwrap();
+        assert_eq!(record.failures, 20);
+
+        tracker.record_success(&[&account]).await;
+        assert!(tracker.get(&account.key()).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lockout_tracker_forgets_after_failure_window() {
+        let mut config = lockout_config();
+        config.failure_window = 0;
+        let tracker = LockoutTracker::new(
+            &config,
+            Arc::new(MemoryStateStore::new()),
+            &Realm::new("default"),
+        );
+        let account = LockoutSubject::Account(Uuid::now_v7());
+
+        for _ in 0..3 {
+            tracker.record_failure(&[&account]).await;
+        }
+        assert!(tracker.check(&[&account]).await.is_ok());
+    }
+
+    #[test]
+    fn test_lockout_subject_key() {
+        let user_id = Uuid::now_v7();
+        assert_eq!(
+            LockoutSubject::Account(user_id).key(),
+            format!("account:{}", user_id)
+        );
+        let client = LockoutSubject::Client {
+            client_id: Some("web".to_string()),
+            ip_address: None,
+        };
+        assert_eq!(client.key(), "client:web@-");
+    }
+
+    #[test]
+    fn test_lockout_subject_ignores_unregistered_client_id() {
+        let clients = ClientRegistry::new(&[ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec![],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }]);
+        let ip_address = Some("203.0.113.7".to_string());
+
+        let registered = LockoutSubject::client(&clients, Some("web"), ip_address.clone());
+        assert_eq!(registered.key(), "client:web@203.0.113.7");
+        let unknown = LockoutSubject::client(&clients, Some("made-up"), ip_address.clone());
+        assert_eq!(unknown.key(), "client:-@203.0.113.7");
+        let missing = LockoutSubject::client(&clients, None, ip_address);
+        assert_eq!(missing.key(), unknown.key());
+    }
+
+    #[tokio::test]
+    async fn test_lockout_tracker_locks_lists_and_clears() {
+        let mut config = lockout_config();
+        config.free_attempts = 3;
+        config.max_failures = 4;
+        let tracker = LockoutTracker::new(
+            &config,
+            Arc::new(MemoryStateStore::new()),
+            &Realm::new("default"),
+        );
+        let account = LockoutSubject::Account(Uuid::now_v7());
+        let client = LockoutSubject::Client {
+            client_id: Some("web".to_string()),
+            ip_address: Some("203.0.113.7".to_string()),
+        };
+
+        for _ in 0..3 {
+            tracker.record_failure(&[&account]).await;
+        }
+        assert!(tracker.check(&[&client, &account]).await.is_ok());
+        assert!(tracker.list().await.unwrap().is_empty());
+
+        tracker.record_failure(&[&account]).await;
+        assert!(matches!(
+            tracker.check(&[&client, &account]).await,
+            Err(AllForOneError::TooManyRequests(600))
+        ));
+        assert!(tracker.check(&[&client]).await.is_ok());
+        let lockouts = tracker.list().await.unwrap();
+        assert_eq!(lockouts.len(), 1);
+        assert_eq!(lockouts[0].0, account.key());
+
+        tracker.clear(&account.key()).await.unwrap();
+        assert!(tracker.check(&[&account]).await.is_ok());
+        assert!(tracker.list().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,104 @@
+use url::Url;
+use uuid::Uuid;
+
+use crate::utils::token::hash_token;
+
+/// back-channel 로그아웃 전송 횟수
+pub const BACKCHANNEL_LOGOUT_ATTEMPTS: u32 = 5;
+
+/// `attempts` 번째 전송이 실패했을 때 다음 전송까지 기다릴 초. 30, 60, 120 ... 초
+pub fn backchannel_logout_retry_delay(attempts: u32) -> u64 {
+    30u64.saturating_mul(1u64 << attempts.saturating_sub(1).min(16))
+}
+
+/// front-channel 로그아웃 주소에 `iss`, `sid` 를 붙임
+pub fn frontchannel_logout_url(logout_uri: &str, iss: &str, sid: Option<Uuid>) -> String {
+    match Url::parse(logout_uri) {
+        Ok(mut url) => {
+            url.query_pairs_mut().append_pair("iss", iss);
+            if let Some(sid) = sid {
+                url.query_pairs_mut().append_pair("sid", &sid.to_string());
+            }
+            url.to_string()
+        }
+        Err(_) => logout_uri.to_string(),
+    }
+}
+
+/// 클라이언트의 로그아웃 주소를 iframe 으로 열고, 돌아갈 주소가 있으면 잠시 뒤 이동하는 페이지
+pub fn frontchannel_logout_page(logout_urls: &[String], redirect_url: Option<&str>) -> String {
+    let iframes: String = logout_urls
+        .iter()
+        .map(|url| {
+            format!(
+                r#"<iframe src="{}" style="display:none"></iframe>"#,
+                escape_html(url)
+            )
+        })
+        .collect();
+    let refresh = redirect_url
+        .map(|url| {
+            format!(
+                r#"<meta http-equiv="refresh" content="2;url={}">"#,
+                escape_html(url)
+            )
+        })
+        .unwrap_or_default();
+
+    format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8">{}<title>Signed out</title></head><body><p>Signed out.</p>{}</body></html>"#,
+        refresh, iframes
+    )
+}
+
+/// 전역 CSP 가 iframe 을 막으므로 로그아웃 페이지는 클라이언트 origin 만 frame 으로 허용
+pub fn frontchannel_logout_csp(logout_urls: &[String]) -> String {
+    let mut origins: Vec<String> = logout_urls
+        .iter()
+        .filter_map(|url| Url::parse(url).ok())
+        .map(|url| url.origin().ascii_serialization())
+        .collect();
+    origins.sort();
+    origins.dedup();
+
+    format!("default-src 'none'; frame-src {}", origins.join(" "))
+}
+
+/// 확인 페이지가 되돌려 보내는 값. SSO 쿠키에서 만들어서 다른 사이트가 미리 알 수 없음
+pub fn logout_confirmation(sso_token: &str) -> String {
+    hash_token(&format!("logout:{}", sso_token))
+}
+
+/// id_token_hint 없이 온 로그아웃 요청을 사용자가 직접 확인하는 페이지.
+/// 같은 주소로 받은 값과 확인 값을 다시 POST 함
+pub fn logout_confirmation_page(confirmation: &str, params: &[(&str, &str)]) -> String {
+    let inputs: String = params
+        .iter()
+        .chain([("confirm", confirmation)].iter())
+        .map(|(name, value)| {
+            format!(
+                r#"<input type="hidden" name="{}" value="{}">"#,
+                escape_html(name),
+                escape_html(value)
+            )
+        })
+        .collect();
+
+    format!(
+        r#"<!DOCTYPE html><html><head><meta charset="utf-8"><title>Sign out</title></head><body><form method="post"><p>Do you want to sign out?</p>{}<button type="submit">Sign out</button></form></body></html>"#,
+        inputs
+    )
+}
+
+/// 확인 페이지는 스크립트와 frame 없이 폼만 보냄
+pub fn logout_confirmation_csp() -> &'static str {
+    "default-src 'none'; frame-ancestors 'none'"
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
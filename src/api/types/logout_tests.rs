@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::api::types::logout::{
+        backchannel_logout_retry_delay, frontchannel_logout_csp, frontchannel_logout_page,
+        frontchannel_logout_url, logout_confirmation, logout_confirmation_page,
+    };
+
+    #[test]
+    fn test_frontchannel_logout_url_appends_iss_and_sid() {
+        let sid = Uuid::now_v7();
+        let url = frontchannel_logout_url(
+            "https://app.example.com/logout?from=sso",
+            "https://auth.example.com",
+            Some(sid),
+        );
+        assert_eq!(
+            url,
+            format!(
+                "https://app.example.com/logout?from=sso&iss=https%3A%2F%2Fauth.example.com&sid={}",
+                sid
+            )
+        );
+
+        let url = frontchannel_logout_url(
+            "https://app.example.com/logout",
+            "https://auth.example.com",
+            None,
+        );
+        assert!(!url.contains("sid="));
+    }
+
+    #[test]
+    fn test_frontchannel_logout_page_escapes_urls() {
+        let page = frontchannel_logout_page(
+            &["https://app.example.com/logout?a=1&b=\"2\"".to_string()],
+            Some("https://app.example.com/"),
+        );
+        assert!(page.contains(
+            r#"<iframe src="https://app.example.com/logout?a=1&amp;b=&quot;2&quot;" style="display:none"></iframe>"#
+        ));
+        assert!(page.contains(r#"content="2;url=https://app.example.com/""#));
+
+        let page = frontchannel_logout_page(&[], None);
+        assert!(!page.contains("http-equiv"));
+    }
+
+    #[test]
+    fn test_frontchannel_logout_csp_allows_client_origins() {
+        let csp = frontchannel_logout_csp(&[
+            "https://b.example.com/logout?iss=x".to_string(),
+            "https://a.example.com:8443/logout".to_string(),
+            "https://b.example.com/other".to_string(),
+        ]);
+        assert_eq!(
+            csp,
+            "default-src 'none'; frame-src https://a.example.com:8443 https://b.example.com"
+        );
+    }
+
+    #[test]
+    fn test_logout_confirmation_page_posts_back_params() {
+        let confirmation = logout_confirmation("sso-token");
+        assert_ne!(confirmation, logout_confirmation("other-token"));
+
+        let page = logout_confirmation_page(
+            &confirmation,
+            &[("client_id", "web"), ("state", "\"><script>")],
+        );
+        assert!(page.contains(r#"<form method="post">"#));
+        assert!(page.contains(r#"<input type="hidden" name="client_id" value="web">"#));
+        assert!(page.contains(r#"value="&quot;&gt;&lt;script&gt;""#));
+        assert!(page.contains(&format!(
+            r#"<input type="hidden" name="confirm" value="{}">"#,
+            confirmation
+        )));
+    }
+
+    #[test]
+    fn test_backchannel_logout_retry_delay_doubles() {
+        let delays: Vec<u64> = (1..=4).map(backchannel_logout_retry_delay).collect();
+        assert_eq!(delays, [30, 60, 120, 240]);
+        assert!(backchannel_logout_retry_delay(u32::MAX) > 0);
+    }
+}
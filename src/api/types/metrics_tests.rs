@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod tests {
+    use std::{sync::Arc, time::Duration};
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::Path,
+        http::{Request, StatusCode},
+        middleware,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        api::{
+            middleware::metrics::track_http_metrics, response::types::token::Token,
+            state::types::metrics::Metrics, types::realm::Realm,
+        },
+        config::types::MetricsConfig,
+        db::connect::memory_connect,
+    };
+
+    fn token(id_token: Option<&str>) -> Token {
+        Token {
+            access_token: "access".to_string(),
+            token_type: "Bearer".to_string(),
+            expires_in: 3600,
+            refresh_token: "refresh".to_string(),
+            id_token: id_token.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metrics_render_counters_and_pool_gauges() {
+        let db = memory_connect().await;
+        let metrics = Metrics::new(&MetricsConfig::default()).unwrap();
+
+        metrics.record_login("default", "github", "success");
+        metrics.record_login("default", "github", "failure");
+        metrics.record_login("default", "github", "failure");
+        metrics.record_tokens("default", "authorization_code", &token(Some("id")));
+        metrics.record_tokens("default", "refresh_token", &token(None));
+        metrics.record_provider_request("github", "token", true, Duration::from_millis(120));
+
+        let body = metrics.render(&db).unwrap();
+        assert!(
+            body.contains(r#"logins_total{outcome="failure",provider="github",realm="default"} 2"#)
+        );
+        assert!(body.contains(
+            r#"tokens_issued_total{grant_type="authorization_code",realm="default",token_type="id_token"} 1"#
+        ));
+        assert!(!body.contains(
+            r#"tokens_issued_total{grant_type="refresh_token",realm="default",token_type="id_token"}"#
+        ));
+        assert!(body.contains(
+            r#"provider_request_duration_seconds_count{endpoint="token",outcome="success",provider="github"} 1"#
+        ));
+        assert!(body.contains(r#"db_pool_connections{state="max"}"#));
+    }
+
+    #[tokio::test]
+    async fn test_http_metrics_use_route_pattern_without_realm_prefix() {
+        let db = memory_connect().await;
+        let metrics = Arc::new(Metrics::new(&MetricsConfig::default()).unwrap());
+        let realm_router = Router::new()
+            .nest(
+                "/api",
+                Router::new().route("/users/{id}", get(|Path(id): Path<String>| async { id })),
+            )
+            .route_layer(middleware::from_fn_with_state(
+                (metrics.clone(), Arc::new(Realm::new("shop"))),
+                track_http_metrics,
+            ));
+        let router = Router::new().nest("/realms/shop", realm_router);
+
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+        for uri in ["/realms/shop/api/users/1", "/realms/shop/api/users/2"] {
+            let response = router.clone().oneshot(request(uri)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+        }
+        let response = router
+            .clone()
+            .oneshot(request("/realms/shop/api/unknown"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let body = metrics.render(&db).unwrap();
+        assert!(body.contains(
+            r#"http_requests_total{method="GET",realm="shop",route="/api/users/{id}",status="200"} 2"#
+        ));
+        assert!(!body.contains("/api/unknown"));
+        assert!(!body.contains("/realms/shop"));
+    }
+
+    #[tokio::test]
+    async fn test_http_metrics_group_unknown_methods() {
+        let db = memory_connect().await;
+        let metrics = Metrics::new(&MetricsConfig::default()).unwrap();
+
+        for method in ["PURGE", "X-RANDOM-1", "X-RANDOM-2"] {
+            metrics.record_http_request("default", method, "/api/heartbeat", 405, Duration::ZERO);
+        }
+        metrics.record_http_request("default", "PATCH", "/api/heartbeat", 405, Duration::ZERO);
+
+        let body = metrics.render(&db).unwrap();
+        assert!(body.contains(
+            r#"http_requests_total{method="other",realm="default",route="/api/heartbeat",status="405"} 3"#
+        ));
+        assert!(body.contains(r#"method="PATCH""#));
+        assert!(!body.contains("PURGE"));
+    }
+
+    #[test]
+    fn test_metrics_require_token_unless_public() {
+        let closed = Metrics::new(&MetricsConfig::default()).unwrap();
+        assert!(!closed.allows(None));
+
+        let open = Metrics::new(&MetricsConfig {
+            public: true,
+            ..MetricsConfig::default()
+        })
+        .unwrap();
+        assert!(open.allows(None));
+
+        let protected = Metrics::new(&MetricsConfig {
+            enabled: true,
+            token: Some("0123456789abcdef0123456789abcdef".to_string()),
+            public: false,
+        })
+        .unwrap();
+        assert!(!protected.allows(None));
+        assert!(!protected.allows(Some("wrong")));
+        assert!(protected.allows(Some("0123456789abcdef0123456789abcdef")));
+    }
+}
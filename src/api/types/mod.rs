@@ -1,9 +1,65 @@
+pub mod account_linking;
+pub mod admin;
+pub mod audit;
+pub mod authorization;
+pub mod client;
+pub mod client_ip;
 pub mod cookie;
+pub mod cors;
 pub mod jwt_claim;
+pub mod lockout;
+pub mod logout;
+pub mod organization;
+pub mod rate_limit;
+pub mod realm;
+pub mod security_headers;
 pub mod session;
+pub mod webhook;
+
+#[cfg(test)]
+mod account_linking_tests;
+
+#[cfg(test)]
+mod admin_tests;
+
+#[cfg(test)]
+mod authorization_tests;
+
+#[cfg(test)]
+mod client_ip_tests;
+
+#[cfg(test)]
+mod client_tests;
+
+#[cfg(test)]
+mod cors_tests;
 
 #[cfg(test)]
 mod jwt_claim_tests;
 
+#[cfg(test)]
+mod lockout_tests;
+
+#[cfg(test)]
+mod logout_tests;
+
+#[cfg(test)]
+mod metrics_tests;
+
+#[cfg(test)]
+mod organization_tests;
+
+#[cfg(test)]
+mod rate_limit_tests;
+
+#[cfg(test)]
+mod realm_tests;
+
+#[cfg(test)]
+mod security_headers_tests;
+
 #[cfg(test)]
 mod session_tests;
+
+#[cfg(test)]
+mod webhook_tests;
@@ -0,0 +1,39 @@
+use sonic_rs::{Deserialize, Serialize};
+
+/// 조직 안에서의 역할. 선언 순서대로 권한이 커짐
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum OrganizationRole {
+    Member,
+    Admin,
+    Owner,
+}
+
+impl OrganizationRole {
+    pub fn as_str(&self) -> &str {
+        match self {
+            OrganizationRole::Member => "member",
+            OrganizationRole::Admin => "admin",
+            OrganizationRole::Owner => "owner",
+        }
+    }
+
+    /// DB 에 저장된 문자열을 해석. 알 수 없는 값은 가장 낮은 권한으로 취급
+    pub fn parse(role: &str) -> Self {
+        match role {
+            "owner" => OrganizationRole::Owner,
+            "admin" => OrganizationRole::Admin,
+            _ => OrganizationRole::Member,
+        }
+    }
+}
+
+/// 조직 slug 는 영문 소문자, 숫자, `-` 로 3~63 자. `-` 로 시작하거나 끝날 수 없음
+pub fn is_valid_slug(slug: &str) -> bool {
+    (3..=63).contains(&slug.len())
+        && !slug.starts_with('-')
+        && !slug.ends_with('-')
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use crate::api::types::organization::{OrganizationRole, is_valid_slug};
+
+    #[test]
+    fn test_organization_role_round_trip() {
+        for role in [
+            OrganizationRole::Member,
+            OrganizationRole::Admin,
+            OrganizationRole::Owner,
+        ] {
+            assert_eq!(OrganizationRole::parse(role.as_str()), role);
+        }
+    }
+
+    #[test]
+    fn test_organization_role_unknown_is_member() {
+        assert_eq!(OrganizationRole::parse("root"), OrganizationRole::Member);
+    }
+
+    #[test]
+    fn test_organization_role_ordering() {
+        assert!(OrganizationRole::Owner > OrganizationRole::Admin);
+        assert!(OrganizationRole::Admin > OrganizationRole::Member);
+    }
+
+    #[test]
+    fn test_organization_role_deserialization() {
+        let role: OrganizationRole = sonic_rs::from_str(r#""admin""#).unwrap();
+        assert_eq!(role, OrganizationRole::Admin);
+        assert!(sonic_rs::from_str::<OrganizationRole>(r#""root""#).is_err());
+    }
+
+    #[test]
+    fn test_is_valid_slug() {
+        assert!(is_valid_slug("acme"));
+        assert!(is_valid_slug("acme-corp-2"));
+        assert!(!is_valid_slug("ac"));
+        assert!(!is_valid_slug("Acme"));
+        assert!(!is_valid_slug("-acme"));
+        assert!(!is_valid_slug("acme-"));
+        assert!(!is_valid_slug("acme corp"));
+        assert!(!is_valid_slug(&"a".repeat(64)));
+    }
+}
@@ -0,0 +1,257 @@
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use deadpool::managed::Pool;
+use deadpool_memcached::Manager;
+use tracing::warn;
+
+use crate::{
+    api::types::{client_ip::TrustedProxies, realm::strip_realm_prefix},
+    config::types::RateLimitingConfig,
+    memcached::counter::increment_window_counter,
+};
+
+/// 어느 route group 에도 맞지 않는 경로를 세는 그룹 이름. 설정의 그룹 이름으로 쓸 수 없음
+pub const DEFAULT_ROUTE_GROUP: &str = "default";
+/// memcached 가 응답하지 않을 때 요청이 같이 멈추지 않도록 기다리는 한도
+const SHARED_COUNTER_TIMEOUT: Duration = Duration::from_millis(500);
+/// 공유 카운터가 실패하면 이 시간 동안은 memcached 를 기다리지 않고 로컬로만 셈
+const SHARED_COUNTER_BACKOFF: Duration = Duration::from_secs(30);
+
+/// 분당 요청 수로 채워지고 `burst_size` 만큼 쌓이는 token bucket 한도
+#[derive(Clone, Copy, Debug)]
+pub struct RateLimit {
+    pub capacity: f64,
+    pub refill_per_sec: f64,
+    pub burst_size: u64,
+    pub requests_per_minute: u64,
+}
+
+impl RateLimit {
+    pub fn new(requests_per_minute: u32, burst_size: u32) -> Self {
+        Self {
+            capacity: burst_size as f64,
+            refill_per_sec: requests_per_minute as f64 / 60.0,
+            burst_size: burst_size as u64,
+            requests_per_minute: requests_per_minute as u64,
+        }
+    }
+
+    /// 공유 카운터의 window 길이(초). bucket 이 비었다가 다시 가득 차는 시간이라서
+    /// window 마다 `burst_size` 번을 받으면 평균은 분당 요청 수와 같고,
+    /// window 경계를 걸친 짧은 구간에서도 token bucket 처럼 최대 `burst_size` 의 2 배까지만 받음
+    pub fn shared_window_secs(&self) -> u64 {
+        (60 * self.burst_size)
+            .div_ceil(self.requests_per_minute.max(1))
+            .max(1)
+    }
+
+    /// window 하나에서 받을 요청 수. window 를 1 초로 올려 잡았으면 그만큼 더 받음
+    pub fn shared_window_limit(&self) -> u64 {
+        (self.requests_per_minute * self.shared_window_secs() / 60).max(self.burst_size)
+    }
+}
+
+#[derive(Debug)]
+struct RouteGroup {
+    name: String,
+    path_prefix: String,
+    limit: RateLimit,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated_at: Instant,
+}
+
+/// 클라이언트 IP 와 경로 그룹별 token bucket
+/// memcached 카운터가 있으면 replica 끼리 `burst_size` 크기의 window 를 같이 세고, 쓸 수 없을 때만 token bucket 으로 셈
+pub struct RateLimiter {
+    default_limit: RateLimit,
+    route_groups: Vec<RouteGroup>,
+    trusted_proxies: TrustedProxies,
+    buckets: Mutex<HashMap<(usize, IpAddr), Bucket>>,
+    shared_counter: Option<Pool<Manager>>,
+    /// 공유 카운터가 실패한 뒤 다시 써 볼 시각
+    shared_counter_retry_at: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(
+        config: &RateLimitingConfig,
+        trusted_proxies: TrustedProxies,
+        shared_counter: Option<Pool<Manager>>,
+    ) -> Self {
+        Self {
+            default_limit: RateLimit::new(config.requests_per_minute, config.burst_size),
+            route_groups: config
+                .route_groups
+                .iter()
+                .map(|group| RouteGroup {
+                    name: group.name.clone(),
+                    path_prefix: group.path_prefix.clone(),
+                    limit: RateLimit::new(group.requests_per_minute, group.burst_size),
+                })
+                .collect(),
+            trusted_proxies,
+            buckets: Mutex::new(HashMap::new()),
+            shared_counter,
+            shared_counter_retry_at: Mutex::new(None),
+        }
+    }
+
+    pub fn trusted_proxies(&self) -> &TrustedProxies {
+        &self.trusted_proxies
+    }
+
+    /// 한도 안이면 통과, 넘었으면 다시 시도할 수 있을 때까지 기다릴 시간을 돌려줌
+    pub async fn check(&self, client_ip: IpAddr, path: &str) -> Result<(), Duration> {
+        let (group, limit) = self.route_group(path);
+
+        if let Some(pool) = &self.shared_counter
+            && !self.is_shared_counter_suspended()
+        {
+            match self.check_shared(pool, group, limit, client_ip).await {
+                Ok(result) => return result,
+                Err(err) => {
+                    warn!(
+                        "shared rate limit counter is unavailable, fall back to local limit for {:?}: {:#}",
+                        SHARED_COUNTER_BACKOFF, err
+                    );
+                    *self.shared_counter_retry_at.lock().unwrap() =
+                        Some(Instant::now() + SHARED_COUNTER_BACKOFF);
+                }
+            }
+        }
+
+        self.check_local(group, limit, client_ip)
+    }
+
+    /// 공유 카운터가 실패해서 잠시 로컬로만 세는 중
+    pub fn is_shared_counter_suspended(&self) -> bool {
+        self.shared_counter_retry_at
+            .lock()
+            .unwrap()
+            .is_some_and(|retry_at| Instant::now() < retry_at)
+    }
+
+    /// 현재 window 의 공유 카운터를 올리고 window 의 한도를 넘으면 window 가 끝날 때까지 막음
+    async fn check_shared(
+        &self,
+        pool: &Pool<Manager>,
+        group: usize,
+        limit: RateLimit,
+        client_ip: IpAddr,
+    ) -> anyhow::Result<Result<(), Duration>> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+        let window_secs = limit.shared_window_secs();
+        let window = now / window_secs;
+        let key = format!(
+            "rate_limit:{}:{}:{}",
+            self.group_name(group),
+            client_ip,
+            window
+        );
+
+        let count = tokio::time::timeout(
+            SHARED_COUNTER_TIMEOUT,
+            increment_window_counter(pool, &key, window_secs),
+        )
+        .await??;
+
+        if count <= limit.shared_window_limit() {
+            Ok(Ok(()))
+        } else {
+            let window_end = (window + 1) * window_secs;
+            Ok(Err(Duration::from_secs(window_end - now)))
+        }
+    }
+
+    /// 토큰이 남았으면 하나 쓰고, 없으면 다음 토큰이 찰 때까지 기다릴 시간을 돌려줌
+    fn check_local(
+        &self,
+        group: usize,
+        limit: RateLimit,
+        client_ip: IpAddr,
+    ) -> Result<(), Duration> {
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().unwrap();
+        let bucket = buckets.entry((group, client_ip)).or_insert(Bucket {
+            tokens: limit.capacity,
+            updated_at: now,
+        });
+        bucket.tokens = refilled_tokens(bucket, limit, now);
+        bucket.updated_at = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64(
+                (1.0 - bucket.tokens) / limit.refill_per_sec,
+            ))
+        }
+    }
+
+    /// 가득 찬 bucket 은 새로 만든 것과 같으므로 지움
+    pub fn cleanup(&self) {
+        let now = Instant::now();
+        let mut buckets = self.buckets.lock().unwrap();
+        buckets.retain(|(group, _), bucket| {
+            let limit = self.group_limit(*group);
+            refilled_tokens(bucket, limit, now) < limit.capacity
+        });
+    }
+
+    /// `cleanup_interval` 마다 정리
+    pub fn start_cleanup(self: &Arc<Self>, cleanup_interval: Duration) {
+        let rate_limiter = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(cleanup_interval);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                rate_limiter.cleanup();
+            }
+        });
+    }
+
+    /// 가장 긴 prefix 가 맞는 그룹. 없으면 기본 한도
+    /// `/realms/{name}/api/...` 도 `/api/...` 와 같은 그룹으로 셈
+    fn route_group(&self, path: &str) -> (usize, RateLimit) {
+        let path = strip_realm_prefix(path);
+        self.route_groups
+            .iter()
+            .enumerate()
+            .filter(|(_, group)| path.starts_with(&group.path_prefix))
+            .max_by_key(|(_, group)| group.path_prefix.len())
+            .map(|(index, group)| (index, group.limit))
+            .unwrap_or((self.route_groups.len(), self.default_limit))
+    }
+
+    /// replica 마다 설정 순서가 달라도 같은 키를 쓰도록 그룹 이름으로 셈
+    fn group_name(&self, group: usize) -> &str {
+        self.route_groups
+            .get(group)
+            .map(|group| group.name.as_str())
+            .unwrap_or(DEFAULT_ROUTE_GROUP)
+    }
+
+    fn group_limit(&self, group: usize) -> RateLimit {
+        self.route_groups
+            .get(group)
+            .map(|group| group.limit)
+            .unwrap_or(self.default_limit)
+    }
+}
+
+fn refilled_tokens(bucket: &Bucket, limit: RateLimit, now: Instant) -> f64 {
+    let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+    (bucket.tokens + elapsed * limit.refill_per_sec).min(limit.capacity)
+}
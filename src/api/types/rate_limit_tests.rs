@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod tests {
+    use std::net::IpAddr;
+
+    use deadpool::managed::Pool;
+    use deadpool_memcached::Manager;
+
+    use crate::{
+        api::types::{
+            client_ip::TrustedProxies,
+            rate_limit::{RateLimit, RateLimiter},
+        },
+        config::types::{RateLimitRouteGroupConfig, RateLimitingConfig},
+    };
+
+    fn rate_limiter(route_groups: Vec<RateLimitRouteGroupConfig>) -> RateLimiter {
+        rate_limiter_with_shared_counter(route_groups, None)
+    }
+
+    fn rate_limiter_with_shared_counter(
+        route_groups: Vec<RateLimitRouteGroupConfig>,
+        shared_counter: Option<Pool<Manager>>,
+    ) -> RateLimiter {
+        RateLimiter::new(
+            &RateLimitingConfig {
+                enabled: true,
+                requests_per_minute: 60,
+                burst_size: 2,
+                cleanup_interval: 300,
+                backend: "local".to_string(),
+                route_groups,
+            },
+            TrustedProxies::default(),
+            shared_counter,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_allows_burst_then_limits() {
+        let rate_limiter = rate_limiter(vec![]);
+        let client_ip: IpAddr = "203.0.113.7".parse().unwrap();
+
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/heartbeat")
+                .await
+                .is_ok()
+        );
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/heartbeat")
+                .await
+                .is_ok()
+        );
+        let retry_after = rate_limiter
+            .check(client_ip, "/api/heartbeat")
+            .await
+            .unwrap_err();
+        assert!(retry_after.as_secs_f64() > 0.0 && retry_after.as_secs_f64() <= 1.0);
+
+        // 다른 클라이언트는 따로 셈
+        let other_ip: IpAddr = "203.0.113.8".parse().unwrap();
+        assert!(rate_limiter.check(other_ip, "/api/heartbeat").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_counts_route_groups_separately() {
+        let rate_limiter = rate_limiter(vec![RateLimitRouteGroupConfig {
+            name: "token".to_string(),
+            path_prefix: "/api/v1/oauth/token".to_string(),
+            requests_per_minute: 1,
+            burst_size: 1,
+        }]);
+        let client_ip: IpAddr = "203.0.113.7".parse().unwrap();
+
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/v1/oauth/token")
+                .await
+                .is_ok()
+        );
+        let retry_after = rate_limiter
+            .check(client_ip, "/realms/shop/api/v1/oauth/token")
+            .await
+            .unwrap_err();
+        assert!(retry_after.as_secs() >= 59);
+
+        assert!(rate_limiter.check(client_ip, "/api/v1/me").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_falls_back_to_local_when_memcached_is_unavailable() {
+        // 아무도 듣지 않는 포트라서 공유 카운터는 항상 실패함
+        let pool = Pool::builder(Manager::new("127.0.0.1:1")).build().unwrap();
+        let rate_limiter = rate_limiter_with_shared_counter(vec![], Some(pool));
+        let client_ip: IpAddr = "203.0.113.7".parse().unwrap();
+
+        assert!(!rate_limiter.is_shared_counter_suspended());
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/heartbeat")
+                .await
+                .is_ok()
+        );
+        // 실패한 뒤로는 한동안 memcached 를 기다리지 않음
+        assert!(rate_limiter.is_shared_counter_suspended());
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/heartbeat")
+                .await
+                .is_ok()
+        );
+        assert!(
+            rate_limiter
+                .check(client_ip, "/api/heartbeat")
+                .await
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_shared_window_refills_burst() {
+        assert_eq!(RateLimit::new(60, 10).shared_window_secs(), 10);
+        assert_eq!(RateLimit::new(20, 5).shared_window_secs(), 15);
+        assert_eq!(RateLimit::new(600, 1).shared_window_secs(), 1);
+        assert_eq!(RateLimit::new(7, 2).shared_window_secs(), 18);
+
+        assert_eq!(RateLimit::new(60, 10).shared_window_limit(), 10);
+        assert_eq!(RateLimit::new(600, 1).shared_window_limit(), 10);
+    }
+}
@@ -0,0 +1,32 @@
+/// 최상위 설정이 그대로 쓰이는 realm 이름
+pub const DEFAULT_REALM: &str = "default";
+
+/// 요청이 라우팅된 realm. 사용자, identity, 조직은 realm 별로 분리됨
+#[derive(Clone, Debug)]
+pub struct Realm {
+    pub name: String,
+}
+
+impl Realm {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+        }
+    }
+
+    /// 기본 realm 은 `/api`, 나머지는 `/realms/{name}/api` 아래에 라우팅
+    pub fn path_prefix(&self) -> String {
+        if self.name == DEFAULT_REALM {
+            String::new()
+        } else {
+            format!("/realms/{}", self.name)
+        }
+    }
+}
+
+/// `/realms/{name}/api/...` 를 realm 경로를 뗀 `/api/...` 로
+pub fn strip_realm_prefix(path: &str) -> &str {
+    path.strip_prefix("/realms/")
+        .and_then(|rest| rest.find('/').map(|index| &rest[index..]))
+        .unwrap_or(path)
+}
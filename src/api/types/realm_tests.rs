@@ -0,0 +1,14 @@
+#[cfg(test)]
+mod tests {
+    use crate::api::types::realm::{DEFAULT_REALM, Realm};
+
+    #[test]
+    fn test_default_realm_has_no_path_prefix() {
+        assert_eq!(Realm::new(DEFAULT_REALM).path_prefix(), "");
+    }
+
+    #[test]
+    fn test_realm_path_prefix() {
+        assert_eq!(Realm::new("shop").path_prefix(), "/realms/shop");
+    }
+}
@@ -0,0 +1,43 @@
+use anyhow::{Context, Result};
+use axum::http::{
+    HeaderName, HeaderValue,
+    header::{
+        CONTENT_SECURITY_POLICY, STRICT_TRANSPORT_SECURITY, X_CONTENT_TYPE_OPTIONS, X_FRAME_OPTIONS,
+    },
+};
+
+use crate::config::types::SecurityHeadersConfig;
+
+/// 모든 응답에 붙일 보안 헤더
+#[derive(Debug)]
+pub struct SecurityHeaders {
+    pub headers: Vec<(HeaderName, HeaderValue)>,
+}
+
+impl SecurityHeaders {
+    pub fn new(config: &SecurityHeadersConfig) -> Result<Self> {
+        let mut headers = vec![(
+            X_FRAME_OPTIONS,
+            HeaderValue::from_str(&config.x_frame_options)
+                .context("invalid X-Frame-Options value")?,
+        )];
+        if config.hsts_enabled {
+            headers.push((
+                STRICT_TRANSPORT_SECURITY,
+                HeaderValue::from_str(&format!("max-age={}", config.hsts_max_age))
+                    .context("invalid HSTS max age")?,
+            ));
+        }
+        if config.csp_enabled {
+            headers.push((
+                CONTENT_SECURITY_POLICY,
+                HeaderValue::from_str(&config.csp_policy).context("invalid CSP policy")?,
+            ));
+        }
+        if config.x_content_type_options {
+            headers.push((X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff")));
+        }
+
+        Ok(Self { headers })
+    }
+}
@@ -0,0 +1,80 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use axum::{
+        Router,
+        body::Body,
+        http::{Request, header::CONTENT_SECURITY_POLICY},
+        middleware,
+        routing::get,
+    };
+    use tower::ServiceExt;
+
+    use crate::{
+        api::{
+            middleware::security_headers::{no_store, security_headers},
+            types::security_headers::SecurityHeaders,
+        },
+        config::types::SecurityHeadersConfig,
+    };
+
+    fn security_headers_config() -> SecurityHeadersConfig {
+        SecurityHeadersConfig {
+            hsts_enabled: true,
+            hsts_max_age: 31536000,
+            csp_enabled: true,
+            csp_policy: "default-src 'self'".to_string(),
+            x_frame_options: "DENY".to_string(),
+            x_content_type_options: true,
+        }
+    }
+
+    #[test]
+    fn test_security_headers_skip_disabled_headers() {
+        let mut config = security_headers_config();
+        config.hsts_enabled = false;
+        config.csp_enabled = false;
+        config.x_content_type_options = false;
+
+        let headers = SecurityHeaders::new(&config).unwrap().headers;
+        assert_eq!(headers.len(), 1);
+        assert_eq!(headers[0].0, "x-frame-options");
+    }
+
+    #[tokio::test]
+    async fn test_security_headers_keep_handler_headers() {
+        let headers = Arc::new(SecurityHeaders::new(&security_headers_config()).unwrap());
+        let router = Router::new()
+            .route("/", get(|| async { "ok" }))
+            .route(
+                "/logout",
+                get(|| async { ([(CONTENT_SECURITY_POLICY, "frame-src https://a.com")], "ok") }),
+            )
+            .route(
+                "/token",
+                get(|| async { "token" }).route_layer(middleware::from_fn(no_store)),
+            )
+            .layer(middleware::from_fn_with_state(headers, security_headers));
+
+        let request = |uri: &str| Request::builder().uri(uri).body(Body::empty()).unwrap();
+
+        let response = router.clone().oneshot(request("/")).await.unwrap();
+        let headers = response.headers();
+        assert_eq!(headers["strict-transport-security"], "max-age=31536000");
+        assert_eq!(headers["content-security-policy"], "default-src 'self'");
+        assert_eq!(headers["x-frame-options"], "DENY");
+        assert_eq!(headers["x-content-type-options"], "nosniff");
+        assert!(headers.get("cache-control").is_none());
+
+        let response = router.clone().oneshot(request("/logout")).await.unwrap();
+        assert_eq!(
+            response.headers()["content-security-policy"],
+            "frame-src https://a.com"
+        );
+
+        let response = router.oneshot(request("/token")).await.unwrap();
+        assert_eq!(response.headers()["cache-control"], "no-store");
+        assert_eq!(response.headers()["pragma"], "no-cache");
+    }
+}
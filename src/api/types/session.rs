@@ -1,5 +1,8 @@
+use crate::{
+    api::types::cookie::{COOKIE_AUTH_REQUEST_ID, COOKIE_SSO_SESSION},
+    config::types::SessionSecurityConfig,
+};
 use axum_extra::extract::cookie::{Cookie, SameSite};
-use crate::{api::types::cookie::COOKIE_AUTH_REQUEST_ID, config::types::SessionSecurityConfig};
 
 /// 세션 쿠키 설정을 미리 변환해서 저장하는 구조체
 #[derive(Clone)]
@@ -9,6 +12,7 @@ pub struct SessionCookieConfig {
     pub secure_cookies: bool,
     pub same_site: SameSite,
     pub http_only: bool,
+    pub sso_ttl: u64,
 }
 
 impl From<&SessionSecurityConfig> for SessionCookieConfig {
@@ -26,6 +30,7 @@ impl From<&SessionSecurityConfig> for SessionCookieConfig {
             secure_cookies: config.secure_cookies,
             same_site,
             http_only: config.http_only,
+            sso_ttl: config.sso_ttl,
         }
     }
 }
@@ -50,8 +55,32 @@ impl SessionCookieConfig {
             .same_site(self.same_site)
             .path("/")
             .build();
-        
+
         cookie.make_removal();
         cookie
     }
-}
\ No newline at end of file
+
+    /// SSO 세션 쿠키 생성. 다른 클라이언트에서 넘어온 authorize 요청에도 실려야 함
+    pub fn create_sso_cookie(&self, token: &str) -> Cookie<'static> {
+        Cookie::build((COOKIE_SSO_SESSION, token.to_string()))
+            .http_only(true)
+            .secure(self.secure_cookies)
+            .same_site(self.same_site)
+            .max_age(cookie::time::Duration::seconds(self.sso_ttl as i64))
+            .path("/")
+            .build()
+    }
+
+    /// SSO 세션 쿠키 삭제
+    pub fn create_sso_removal_cookie(&self) -> Cookie<'static> {
+        let mut cookie = Cookie::build((COOKIE_SSO_SESSION, ""))
+            .http_only(true)
+            .secure(self.secure_cookies)
+            .same_site(self.same_site)
+            .path("/")
+            .build();
+
+        cookie.make_removal();
+        cookie
+    }
+}
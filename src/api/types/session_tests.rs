@@ -11,6 +11,7 @@ mod tests {
             secure_cookies: true,
             same_site: "Lax".to_string(),
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let session_config = SessionCookieConfig::from(&security_config);
@@ -30,6 +31,7 @@ mod tests {
             secure_cookies: true,
             same_site: "Strict".to_string(),
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let session_config = SessionCookieConfig::from(&security_config);
@@ -44,6 +46,7 @@ mod tests {
             secure_cookies: true,
             same_site: "None".to_string(),
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let session_config = SessionCookieConfig::from(&security_config);
@@ -58,6 +61,7 @@ mod tests {
             secure_cookies: true,
             same_site: "Invalid".to_string(),
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let session_config = SessionCookieConfig::from(&security_config);
@@ -72,6 +76,7 @@ mod tests {
             secure_cookies: true,
             same_site: SameSite::Lax,
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let session_id = "test-session-id";
@@ -93,6 +98,7 @@ mod tests {
             secure_cookies: true,
             same_site: SameSite::Lax,
             http_only: true,
+            sso_ttl: 28800,
         };
 
         let cookie = session_config.create_session_removal_cookie();
@@ -0,0 +1,176 @@
+use ring::hmac;
+use serde::Deserialize;
+use sonic_rs::Serialize;
+use uuid::Uuid;
+
+use crate::config::types::{WebhookEndpointConfig, WebhooksConfig};
+
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "x-webhook-signature";
+pub const WEBHOOK_EVENT_HEADER: &str = "x-webhook-event";
+pub const WEBHOOK_ID_HEADER: &str = "x-webhook-id";
+
+/// webhook 으로 알리는 사용자 lifecycle 이벤트
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WebhookEventType {
+    /// 첫 로그인으로 사용자가 만들어짐
+    UserRegistered,
+    /// 기존 사용자에 provider 계정이 연결됨
+    IdentityLinked,
+    UserDeactivated,
+    UserDeleted,
+}
+
+impl WebhookEventType {
+    pub const ALL: [Self; 4] = [
+        Self::UserRegistered,
+        Self::IdentityLinked,
+        Self::UserDeactivated,
+        Self::UserDeleted,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UserRegistered => "user.registered",
+            Self::IdentityLinked => "identity.linked",
+            Self::UserDeactivated => "user.deactivated",
+            Self::UserDeleted => "user.deleted",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|event_type| event_type.as_str() == value)
+    }
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// 아직 보내지 못했고 재시도를 기다림
+    Pending,
+    Delivered,
+    /// `max_attempts` 만큼 실패해서 더 보내지 않음
+    Failed,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// 이벤트의 대상 사용자. 이벤트에 따라 없는 값은 보내지 않음
+#[derive(Serialize, Debug, Clone)]
+pub struct WebhookUserData {
+    pub user_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idp: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+impl WebhookUserData {
+    pub fn new(user_id: Uuid) -> Self {
+        Self {
+            user_id,
+            idp: None,
+            email: None,
+            username: None,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    id: Uuid,
+    #[serde(rename = "type")]
+    event_type: &'a str,
+    realm: &'a str,
+    created_at: i64,
+    data: &'a WebhookUserData,
+}
+
+/// endpoint 로 보낼 JSON 본문. `id` 는 재시도해도 같으므로 받는 쪽에서 중복을 거를 수 있음
+pub fn webhook_payload(
+    event_id: Uuid,
+    event_type: WebhookEventType,
+    realm: &str,
+    created_at: i64,
+    data: &WebhookUserData,
+) -> Result<String, sonic_rs::Error> {
+    sonic_rs::to_string(&WebhookPayload {
+        id: event_id,
+        event_type: event_type.as_str(),
+        realm,
+        created_at,
+        data,
+    })
+}
+
+/// `t={timestamp},v1={hex(HMAC-SHA256(secret, "{timestamp}.{payload}"))}`
+/// timestamp 를 함께 서명해서 받는 쪽이 오래된 요청의 재전송을 거를 수 있음
+pub fn webhook_signature(secret: &str, timestamp: i64, payload: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+    let tag = hmac::sign(&key, format!("{}.{}", timestamp, payload).as_bytes());
+    let hex: String = tag
+        .as_ref()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    format!("t={},v1={}", timestamp, hex)
+}
+
+/// `attempts` 번 실패한 뒤 다음 시도까지 기다릴 초. 더 보내지 않으면 None
+pub fn retry_delay(attempts: u32, config: &WebhooksConfig) -> Option<u64> {
+    if attempts >= config.max_attempts {
+        return None;
+    }
+    let exponent = attempts.saturating_sub(1).min(63);
+    let delay = 1u64
+        .checked_shl(exponent)
+        .and_then(|factor| config.initial_backoff.checked_mul(factor))
+        .unwrap_or(u64::MAX);
+    Some(delay.min(config.max_backoff))
+}
+
+/// 설정한 endpoint. 받을 이벤트와 realm 을 거름
+#[derive(Debug, Clone)]
+pub struct WebhookEndpoint {
+    pub name: String,
+    pub url: String,
+    pub secret: String,
+    events: Vec<WebhookEventType>,
+    realms: Vec<String>,
+}
+
+impl From<&WebhookEndpointConfig> for WebhookEndpoint {
+    fn from(config: &WebhookEndpointConfig) -> Self {
+        Self {
+            name: config.name.clone(),
+            url: config.url.clone(),
+            secret: config.secret.clone(),
+            events: config
+                .events
+                .iter()
+                .filter_map(|event| WebhookEventType::parse(event))
+                .collect(),
+            realms: config.realms.clone(),
+        }
+    }
+}
+
+impl WebhookEndpoint {
+    pub fn accepts(&self, event_type: &str, realm: &str) -> bool {
+        let accepts_event =
+            self.events.is_empty() || self.events.iter().any(|event| event.as_str() == event_type);
+        let accepts_realm = self.realms.is_empty() || self.realms.iter().any(|r| r == realm);
+        accepts_event && accepts_realm
+    }
+}
@@ -0,0 +1,112 @@
+#[cfg(test)]
+mod tests {
+    use sonic_rs::JsonValueTrait;
+    use uuid::Uuid;
+
+    use crate::{
+        api::types::webhook::{
+            WebhookEndpoint, WebhookEventType, WebhookUserData, retry_delay, webhook_payload,
+            webhook_signature,
+        },
+        config::types::{WebhookEndpointConfig, WebhooksConfig},
+    };
+
+    #[test]
+    fn test_webhook_event_type_round_trips() {
+        for event_type in WebhookEventType::ALL {
+            assert_eq!(
+                WebhookEventType::parse(event_type.as_str()),
+                Some(event_type)
+            );
+        }
+        assert_eq!(WebhookEventType::parse("user.updated"), None);
+    }
+
+    #[test]
+    fn test_webhook_payload_skips_missing_fields() {
+        let event_id = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        let payload = webhook_payload(
+            event_id,
+            WebhookEventType::UserDeactivated,
+            "default",
+            1700000000,
+            &WebhookUserData::new(user_id),
+        )
+        .unwrap();
+
+        let value: sonic_rs::Value = sonic_rs::from_str(&payload).unwrap();
+        assert_eq!(value["id"].as_str(), Some(event_id.to_string().as_str()));
+        assert_eq!(value["type"].as_str(), Some("user.deactivated"));
+        assert_eq!(value["realm"].as_str(), Some("default"));
+        assert_eq!(value["created_at"].as_i64(), Some(1700000000));
+        assert_eq!(
+            value["data"]["user_id"].as_str(),
+            Some(user_id.to_string().as_str())
+        );
+        assert!(value["data"].get("email").is_none());
+    }
+
+    #[test]
+    fn test_webhook_signature_is_hmac_sha256_of_timestamp_and_payload() {
+        let signature = webhook_signature("Jefe", 0, "what do ya want for nothing?");
+        let expected = ring::hmac::sign(
+            &ring::hmac::Key::new(ring::hmac::HMAC_SHA256, b"Jefe"),
+            b"0.what do ya want for nothing?",
+        );
+        let hex: String = expected
+            .as_ref()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect();
+        assert_eq!(signature, format!("t=0,v1={}", hex));
+
+        assert_ne!(
+            webhook_signature("Jefe", 1, "what do ya want for nothing?"),
+            signature
+        );
+        assert_ne!(
+            webhook_signature("other", 0, "what do ya want for nothing?"),
+            signature
+        );
+    }
+
+    #[test]
+    fn test_retry_delay_doubles_until_max_backoff_and_gives_up() {
+        let config = WebhooksConfig {
+            max_attempts: 5,
+            initial_backoff: 30,
+            max_backoff: 100,
+            ..WebhooksConfig::default()
+        };
+
+        assert_eq!(retry_delay(1, &config), Some(30));
+        assert_eq!(retry_delay(2, &config), Some(60));
+        assert_eq!(retry_delay(3, &config), Some(100));
+        assert_eq!(retry_delay(4, &config), Some(100));
+        assert_eq!(retry_delay(5, &config), None);
+    }
+
+    #[test]
+    fn test_webhook_endpoint_filters_events_and_realms() {
+        let all = WebhookEndpoint::from(&WebhookEndpointConfig {
+            name: "all".to_string(),
+            url: "https://crm.example.com/hooks".to_string(),
+            secret: "secret".to_string(),
+            events: vec![],
+            realms: vec![],
+        });
+        assert!(all.accepts("user.deleted", "shop"));
+
+        let filtered = WebhookEndpoint::from(&WebhookEndpointConfig {
+            name: "filtered".to_string(),
+            url: "https://crm.example.com/hooks".to_string(),
+            secret: "secret".to_string(),
+            events: vec!["user.registered".to_string(), "user.deleted".to_string()],
+            realms: vec!["default".to_string()],
+        });
+        assert!(filtered.accepts("user.registered", "default"));
+        assert!(!filtered.accepts("identity.linked", "default"));
+        assert!(!filtered.accepts("user.registered", "shop"));
+    }
+}
@@ -0,0 +1,204 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::{Query, State, rejection::QueryRejection},
+    response::{IntoResponse, Redirect, Response},
+    routing::get,
+};
+use axum_extra::extract::CookieJar;
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+
+use crate::{
+    api::{
+        state::types::{app::AppState, oauth_client::OAuthProviderClient},
+        types::{
+            authorization::{
+                AUTHORIZATION_CODE_TTL, PKCE_METHOD_S256, Prompt, authorization_redirect_url,
+            },
+            client::ClientRegistry,
+            cookie::COOKIE_SSO_SESSION,
+            realm::Realm,
+            session::SessionCookieConfig,
+        },
+        v1::{oauth::redirect_to_idp, token::ensure_user_can_sign_in},
+    },
+    db::repo::users::UsersRepo,
+    provider::types::idp::OAuthProvider,
+    state_store::{
+        StateStore,
+        repo::{cache_authorization_code, get_sso_session},
+        types::{AuthorizationCode, AuthorizationRequest, SsoSession},
+    },
+    utils::{error::AllForOneError, token::generate_opaque_token},
+};
+
+#[derive(Deserialize, Debug)]
+struct AuthorizeQuery {
+    pub response_type: String,
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: Option<String>,
+    pub code_challenge_method: Option<String>,
+    pub prompt: Option<String>,
+    /// SSO 세션의 마지막 로그인 후 이 시간(초)이 지났으면 `login_required`
+    pub max_age: Option<i64>,
+    /// 로그인할 provider. 없으면 github
+    pub idp: Option<OAuthProvider>,
+}
+
+/// SSO 세션이 유효하면 provider 를 거치지 않고 바로 code 를 발급
+#[allow(clippy::too_many_arguments)]
+async fn authorize(
+    query: Result<Query<AuthorizeQuery>, QueryRejection>,
+    State(oauth_client): State<Arc<OAuthProviderClient>>,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(session_config): State<Arc<SessionCookieConfig>>,
+    State(clients): State<Arc<ClientRegistry>>,
+    State(realm): State<Arc<Realm>>,
+    jar: CookieJar,
+) -> Result<Response, AllForOneError> {
+    let Query(query) = query?;
+
+    // 확인되지 않은 redirect_uri 로는 오류도 돌려보내지 않음
+    let client = clients
+        .get(&query.client_id)
+        .ok_or_else(|| AllForOneError::BadRequest("client is not registered".to_string()))?;
+    if !client.allows_redirect_uri(&query.redirect_uri) {
+        return Err(AllForOneError::BadRequest(
+            "redirect_uri is not registered for this client".to_string(),
+        ));
+    }
+
+    let error_redirect = |error: &str| {
+        let mut params = vec![("error", error)];
+        if let Some(state) = query.state.as_deref() {
+            params.push(("state", state));
+        }
+        Redirect::to(&authorization_redirect_url(&query.redirect_uri, &params)).into_response()
+    };
+
+    if query.response_type != "code" {
+        return Ok(error_redirect("unsupported_response_type"));
+    }
+    let Some(prompt) = Prompt::parse(query.prompt.as_deref()) else {
+        return Ok(error_redirect("invalid_request"));
+    };
+    let code_challenge = match (
+        &query.code_challenge,
+        query.code_challenge_method.as_deref(),
+    ) {
+        (Some(code_challenge), Some(PKCE_METHOD_S256)) => code_challenge.clone(),
+        _ => return Ok(error_redirect("invalid_request")),
+    };
+
+    // GitHub 는 자기 세션으로 조용히 승인하므로 provider 로 보내도 재인증이 되지 않음.
+    // 재인증을 요구하는 요청은 새 auth_time 을 주지 않고 실패시킴
+    if prompt == Prompt::Login {
+        return Ok(error_redirect("login_required"));
+    }
+    let sso_session =
+        active_sso_session(state_store.as_ref(), db_client.as_ref(), &realm, &jar).await?;
+    if let (Some(sso_session), Some(max_age)) = (&sso_session, query.max_age)
+        && chrono::Utc::now().timestamp() - sso_session.auth_time > max_age
+    {
+        return Ok(error_redirect("login_required"));
+    }
+
+    let request = AuthorizationRequest {
+        client_id: query.client_id.clone(),
+        redirect_uri: query.redirect_uri.clone(),
+        scope: query.scope.clone(),
+        state: query.state.clone(),
+        nonce: query.nonce.clone(),
+        code_challenge,
+    };
+
+    match (sso_session, prompt) {
+        (Some(sso_session), _) => {
+            redirect_with_authorization_code(state_store.as_ref(), &realm, request, &sso_session)
+                .await
+        }
+        (None, Prompt::None) => Ok(error_redirect("login_required")),
+        (None, _) => {
+            redirect_to_idp(
+                query.idp.unwrap_or(OAuthProvider::Github),
+                None,
+                Some(request),
+                oauth_client,
+                state_store,
+                session_config,
+                jar,
+            )
+            .await
+        }
+    }
+}
+
+/// 쿠키의 SSO 세션이 이 realm 의 로그인 가능한 사용자면 돌려줌
+async fn active_sso_session(
+    state_store: &dyn StateStore,
+    db_client: &DatabaseConnection,
+    realm: &Realm,
+    jar: &CookieJar,
+) -> Result<Option<SsoSession>, AllForOneError> {
+    let Some(cookie) = jar.get(COOKIE_SSO_SESSION) else {
+        return Ok(None);
+    };
+    let Some(sso_session) = get_sso_session(state_store, cookie.value()).await? else {
+        return Ok(None);
+    };
+
+    let user = UsersRepo::new(db_client)
+        .get_user_by_id(sso_session.user_id)
+        .await?;
+    match user {
+        Some(user) if user.realm == realm.name && ensure_user_can_sign_in(&user).is_ok() => {
+            Ok(Some(sso_session))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// 한 번만 쓸 수 있는 code 를 발급하고 클라이언트로 돌려보냄
+pub(super) async fn redirect_with_authorization_code(
+    state_store: &dyn StateStore,
+    realm: &Realm,
+    request: AuthorizationRequest,
+    sso_session: &SsoSession,
+) -> Result<Response, AllForOneError> {
+    let code = generate_opaque_token()?;
+    let mut params = vec![("code", code.as_str())];
+    if let Some(state) = request.state.as_deref() {
+        params.push(("state", state));
+    }
+    let redirect_url = authorization_redirect_url(&request.redirect_uri, &params);
+
+    cache_authorization_code(
+        state_store,
+        &code,
+        &AuthorizationCode {
+            realm: realm.name.clone(),
+            request,
+            user_id: sso_session.user_id,
+            idp: sso_session.idp.clone(),
+            sid: sso_session.sid,
+            auth_time: sso_session.auth_time,
+        },
+        AUTHORIZATION_CODE_TTL,
+    )
+    .await?;
+
+    Ok(Redirect::to(&redirect_url).into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/authorize", get(authorize))
+        .with_state(app_state)
+}
@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, put},
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::auth_user::AuthUser,
+        response::types::organization::{Group, GroupMember},
+        state::types::app::AppState,
+        types::organization::OrganizationRole,
+        v1::organizations::require_membership,
+    },
+    db::repo::{groups::GroupsRepo, organizations::OrganizationsRepo},
+    entity::groups,
+    utils::error::AllForOneError,
+};
+
+async fn list_groups(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Member,
+    )
+    .await?;
+
+    let groups = GroupsRepo::new(db_client)
+        .list_groups(organization_id)
+        .await?
+        .into_iter()
+        .map(Group::from)
+        .collect::<Vec<_>>();
+    Ok(Json(groups).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct CreateGroupBody {
+    pub name: String,
+    pub description: Option<String>,
+}
+
+/// 조직 안에서 그룹 이름은 유일
+async fn create_group(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    body: Result<Json<CreateGroupBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let Json(body) = body?;
+    let name = body.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AllForOneError::BadRequest(
+            "group name cannot be empty".to_string(),
+        ));
+    }
+
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+
+    let groups_repo = GroupsRepo::new(db_client);
+    if groups_repo
+        .get_group_by_name(organization_id, &name)
+        .await?
+        .is_some()
+    {
+        return Err(AllForOneError::Conflict(format!(
+            "group {} already exists",
+            name
+        )));
+    }
+
+    let group = groups_repo
+        .create_group(organization_id, name, body.description)
+        .await?;
+    Ok((StatusCode::CREATED, Json(Group::from(group))).into_response())
+}
+
+async fn delete_group(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, group_id)) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+    let group = find_group(db_client, organization_id, group_id).await?;
+
+    GroupsRepo::new(db_client).delete_group(group.id).await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn list_group_members(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, group_id)) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Member,
+    )
+    .await?;
+    let group = find_group(db_client, organization_id, group_id).await?;
+
+    let members = GroupsRepo::new(db_client)
+        .list_group_members(group.id)
+        .await?
+        .into_iter()
+        .map(GroupMember::from)
+        .collect::<Vec<_>>();
+    Ok(Json(members).into_response())
+}
+
+/// 조직 멤버만 그룹에 넣을 수 있음
+async fn add_group_member(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, group_id, user_id)) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+    let group = find_group(db_client, organization_id, group_id).await?;
+
+    OrganizationsRepo::new(db_client)
+        .get_member(organization_id, user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("member is not found".to_string()))?;
+
+    GroupsRepo::new(db_client)
+        .add_group_member(group.id, user_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn remove_group_member(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, group_id, user_id)) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+    let group = find_group(db_client, organization_id, group_id).await?;
+
+    let removed = GroupsRepo::new(db_client)
+        .remove_group_member(group.id, user_id)
+        .await?;
+    if removed == 0 {
+        return Err(AllForOneError::NotFound(
+            "user is not a member of the group".to_string(),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn find_group<C: ConnectionTrait>(
+    conn: &C,
+    organization_id: Uuid,
+    group_id: Uuid,
+) -> Result<groups::Model, AllForOneError> {
+    GroupsRepo::new(conn)
+        .get_group(organization_id, group_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("group is not found".to_string()))
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/{org_id}/groups", get(list_groups).post(create_group))
+        .route("/{org_id}/groups/{group_id}", delete(delete_group))
+        .route(
+            "/{org_id}/groups/{group_id}/members",
+            get(list_group_members),
+        )
+        .route(
+            "/{org_id}/groups/{group_id}/members/{user_id}",
+            put(add_group_member).delete(remove_group_member),
+        )
+        .with_state(app_state)
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use axum::{
+    Form, Router,
+    extract::{
+        Query, State,
+        rejection::{FormRejection, QueryRejection},
+    },
+    http::{StatusCode, header::CONTENT_SECURITY_POLICY},
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::get,
+};
+use axum_extra::extract::CookieJar;
+use sea_orm::DatabaseConnection;
+use serde::Deserialize;
+use tracing::info;
+
+use crate::{
+    api::{
+        extractor::device_info::DeviceInfo,
+        state::types::{
+            app::AppState, audit_log::AuditLog, jwt_issuer::JwtIssuer,
+            logout_notifier::LogoutNotifier,
+        },
+        types::{
+            audit::{AuditEvent, AuditEventType},
+            authorization::authorization_redirect_url,
+            client::ClientRegistry,
+            cookie::COOKIE_SSO_SESSION,
+            logout::{
+                frontchannel_logout_csp, frontchannel_logout_page, logout_confirmation,
+                logout_confirmation_csp, logout_confirmation_page,
+            },
+            session::SessionCookieConfig,
+        },
+    },
+    db::repo::refresh_tokens::RefreshTokensRepo,
+    state_store::{
+        StateStore,
+        repo::{delete_sso_session, get_sso_session},
+    },
+    utils::error::AllForOneError,
+};
+
+#[derive(Deserialize, Debug)]
+struct EndSessionRequest {
+    pub id_token_hint: Option<String>,
+    pub client_id: Option<String>,
+    pub post_logout_redirect_uri: Option<String>,
+    pub state: Option<String>,
+    /// 확인 페이지에서 다시 보낸 값
+    pub confirm: Option<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn end_session_by_query(
+    query: Result<Query<EndSessionRequest>, QueryRejection>,
+    State(jwt_issuer): State<Arc<JwtIssuer>>,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(session_config): State<Arc<SessionCookieConfig>>,
+    State(clients): State<Arc<ClientRegistry>>,
+    State(logout_notifier): State<Arc<LogoutNotifier>>,
+    State(audit_log): State<Arc<AuditLog>>,
+    device: DeviceInfo,
+    jar: CookieJar,
+) -> Result<Response, AllForOneError> {
+    let Query(request) = query?;
+    end_session(
+        request,
+        &jwt_issuer,
+        state_store.as_ref(),
+        &db_client,
+        &session_config,
+        &clients,
+        &logout_notifier,
+        &audit_log,
+        &device,
+        jar,
+    )
+    .await
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn end_session_by_form(
+    State(jwt_issuer): State<Arc<JwtIssuer>>,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(session_config): State<Arc<SessionCookieConfig>>,
+    State(clients): State<Arc<ClientRegistry>>,
+    State(logout_notifier): State<Arc<LogoutNotifier>>,
+    State(audit_log): State<Arc<AuditLog>>,
+    device: DeviceInfo,
+    jar: CookieJar,
+    form: Result<Form<EndSessionRequest>, FormRejection>,
+) -> Result<Response, AllForOneError> {
+    let Form(request) = form?;
+    end_session(
+        request,
+        &jwt_issuer,
+        state_store.as_ref(),
+        &db_client,
+        &session_config,
+        &clients,
+        &logout_notifier,
+        &audit_log,
+        &device,
+        jar,
+    )
+    .await
+}
+
+/// SSO 세션을 끝내고 그 세션에서 발급한 refresh token 을 폐기한 뒤 쿠키를 지움
+/// 클라이언트에는 back-channel 로 알리고, front-channel 주소가 있으면 iframe 페이지를 돌려줌.
+/// 다른 사이트가 사용자를 몰래 로그아웃시키지 못하도록, 유효한 id_token_hint 가 없으면 확인 페이지를 먼저 보여줌
+#[allow(clippy::too_many_arguments)]
+async fn end_session(
+    request: EndSessionRequest,
+    jwt_issuer: &JwtIssuer,
+    state_store: &dyn StateStore,
+    db_client: &DatabaseConnection,
+    session_config: &SessionCookieConfig,
+    clients: &ClientRegistry,
+    logout_notifier: &LogoutNotifier,
+    audit_log: &AuditLog,
+    device: &DeviceInfo,
+    jar: CookieJar,
+) -> Result<Response, AllForOneError> {
+    let hint = request
+        .id_token_hint
+        .as_deref()
+        .map(|token| jwt_issuer.verify_id_token_hint(token))
+        .transpose()
+        .map_err(|_| AllForOneError::Auth("id_token_hint is invalid".to_string()))?;
+
+    let client_id = match (&hint, request.client_id) {
+        (Some(hint), Some(client_id)) if hint.aud != client_id => {
+            return Err(AllForOneError::BadRequest(
+                "client_id does not match id_token_hint".to_string(),
+            ));
+        }
+        (Some(hint), _) => Some(hint.aud.clone()),
+        (None, client_id) => client_id,
+    };
+
+    // 등록된 주소로만 돌려보내므로 로그아웃을 오픈 리다이렉트로 쓸 수 없음
+    let redirect_url = match request.post_logout_redirect_uri.as_deref() {
+        Some(redirect_uri) => {
+            let client = client_id
+                .as_deref()
+                .and_then(|client_id| clients.get(client_id))
+                .ok_or_else(|| {
+                    AllForOneError::BadRequest(
+                        "post_logout_redirect_uri requires a registered client".to_string(),
+                    )
+                })?;
+            if !client.allows_post_logout_redirect_uri(redirect_uri) {
+                return Err(AllForOneError::BadRequest(
+                    "post_logout_redirect_uri is not registered for this client".to_string(),
+                ));
+            }
+            let params: Vec<(&str, &str)> = request
+                .state
+                .as_deref()
+                .map(|state| ("state", state))
+                .into_iter()
+                .collect();
+            Some(authorization_redirect_url(redirect_uri, &params))
+        }
+        None => None,
+    };
+
+    // 만료된 지 SSO 세션 수명보다 오래된 hint 로는 세션을 끝내지 않음
+    let now = chrono::Utc::now().timestamp();
+    let session_hint = hint
+        .as_ref()
+        .filter(|hint| hint.exp + session_config.sso_ttl as i64 > now);
+
+    let sso_token = jar
+        .get(COOKIE_SSO_SESSION)
+        .map(|cookie| cookie.value().to_string());
+    if session_hint.is_none()
+        && let Some(sso_token) = sso_token.as_deref()
+    {
+        let confirmation = logout_confirmation(sso_token);
+        if request.confirm.as_deref() != Some(confirmation.as_str()) {
+            let params: Vec<(&str, &str)> = [
+                ("client_id", client_id.as_deref()),
+                (
+                    "post_logout_redirect_uri",
+                    request.post_logout_redirect_uri.as_deref(),
+                ),
+                ("state", request.state.as_deref()),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| (name, value)))
+            .collect();
+            let page = logout_confirmation_page(&confirmation, &params);
+            return Ok((
+                [(CONTENT_SECURITY_POLICY, logout_confirmation_csp())],
+                Html(page),
+            )
+                .into_response());
+        }
+    }
+
+    let sso_session = match sso_token.as_deref() {
+        Some(sso_token) => get_sso_session(state_store, sso_token).await?,
+        None => None,
+    };
+    if let (Some(hint), Some(sso_session)) = (&hint, &sso_session)
+        && hint.sub != sso_session.user_id
+    {
+        return Err(AllForOneError::BadRequest(
+            "id_token_hint does not belong to the current session".to_string(),
+        ));
+    }
+
+    if let Some(sso_token) = sso_token.as_deref() {
+        delete_sso_session(state_store, sso_token).await?;
+    }
+    // 쿠키가 없어도 서명된 id_token_hint 의 세션은 폐기
+    let ended_session = sso_session
+        .as_ref()
+        .map(|sso_session| (sso_session.user_id, sso_session.sid))
+        .or(session_hint.map(|hint| (hint.sub, hint.sid)));
+    let mut frontchannel_logout_urls = Vec::new();
+    if let Some((user_id, sid)) = ended_session {
+        let revoked = RefreshTokensRepo::new(db_client)
+            .revoke_refresh_tokens_by_session_id(sid)
+            .await?;
+        info!("end sso session {}, revoke {} refresh tokens", sid, revoked);
+        frontchannel_logout_urls = logout_notifier.notify_logout(user_id, Some(sid)).await?;
+        audit_log
+            .record(
+                AuditEvent::success(AuditEventType::Logout, device)
+                    .actor(user_id)
+                    .client(client_id.as_deref()),
+            )
+            .await;
+    }
+
+    let updated_jar = jar
+        .remove(session_config.create_sso_removal_cookie())
+        .remove(session_config.create_session_removal_cookie());
+
+    if !frontchannel_logout_urls.is_empty() {
+        let page = frontchannel_logout_page(&frontchannel_logout_urls, redirect_url.as_deref());
+        let csp = frontchannel_logout_csp(&frontchannel_logout_urls);
+        return Ok((updated_jar, [(CONTENT_SECURITY_POLICY, csp)], Html(page)).into_response());
+    }
+
+    match redirect_url {
+        Some(redirect_url) => Ok((updated_jar, Redirect::to(&redirect_url)).into_response()),
+        None => Ok((updated_jar, StatusCode::NO_CONTENT).into_response()),
+    }
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route(
+            "/logout",
+            get(end_session_by_query).post(end_session_by_form),
+        )
+        .with_state(app_state)
+}
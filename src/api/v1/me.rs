@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{Path, State, rejection::PathRejection},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use sea_orm::{DatabaseConnection, TransactionTrait};
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::auth_user::AuthUser,
+        response::types::{identity::Identity, session::Session},
+        state::types::app::AppState,
+    },
+    db::repo::{
+        refresh_tokens::RefreshTokensRepo, sessions::SessionsRepo,
+        user_identities::UserIdentitiesRepo,
+    },
+    provider::types::idp::OAuthProvider,
+    utils::error::AllForOneError,
+};
+
+async fn list_identities(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let identities = UserIdentitiesRepo::new(db_client.as_ref())
+        .list_identities_by_user_id(auth_user.user_id)
+        .await?
+        .into_iter()
+        .map(Identity::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(identities).into_response())
+}
+
+/// 마지막 남은 identity 는 해제할 수 없음
+async fn unlink_identity(
+    path: Result<Path<OAuthProvider>, PathRejection>,
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(idp) = path?;
+
+    let txn = db_client.begin().await?;
+    let identities_repo = UserIdentitiesRepo::new(&txn);
+    let identity = identities_repo
+        .get_identity_by_user_id_and_idp(auth_user.user_id, idp.clone())
+        .await?
+        .ok_or_else(|| {
+            AllForOneError::NotFound(format!("{} identity is not linked", idp.as_str()))
+        })?;
+
+    let identity_count = identities_repo
+        .count_identities_by_user_id(auth_user.user_id)
+        .await?;
+    if identity_count <= 1 {
+        return Err(AllForOneError::Conflict(
+            "cannot unlink the last identity".to_string(),
+        ));
+    }
+
+    identities_repo.delete_identity(identity.id).await?;
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+/// refresh token 이 남아 있는 기기만
+async fn list_sessions(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let sessions = SessionsRepo::new(db_client.as_ref())
+        .list_active_sessions_by_user_id(auth_user.user_id)
+        .await?
+        .into_iter()
+        .map(Session::from)
+        .collect::<Vec<_>>();
+
+    Ok(Json(sessions).into_response())
+}
+
+/// 기기의 refresh token 계열을 폐기해서 로그아웃시킴. 발급된 access token 은 만료될 때까지 유효
+async fn revoke_session(
+    path: Result<Path<Uuid>, PathRejection>,
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(session_id) = path?;
+
+    let txn = db_client.begin().await?;
+    let sessions_repo = SessionsRepo::new(&txn);
+    let session = sessions_repo
+        .get_session_by_user_id(auth_user.user_id, session_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("session is not found".to_string()))?;
+
+    RefreshTokensRepo::new(&txn)
+        .revoke_refresh_token_family(session.id)
+        .await?;
+    sessions_repo.delete_session(session.id).await?;
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/identities", get(list_identities))
+        .route("/identities/{idp}", delete(unlink_identity))
+        .route("/sessions", get(list_sessions))
+        .route("/sessions/{id}", delete(revoke_session))
+        .with_state(app_state)
+}
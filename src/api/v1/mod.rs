@@ -2,11 +2,29 @@ use axum::Router;
 
 use crate::api::state::types::app::AppState;
 
+mod authorize;
+mod groups;
 mod jwks;
+mod logout;
+mod me;
 mod oauth;
+mod organizations;
+mod token;
 
 pub async fn router(app_state: AppState) -> Router {
     Router::new()
-        .nest("/oauth", oauth::router(app_state.clone()).await)
+        .nest(
+            "/oauth",
+            oauth::router(app_state.clone())
+                .await
+                .merge(authorize::router(app_state.clone()).await)
+                .merge(logout::router(app_state.clone()).await)
+                .merge(token::router(app_state.clone()).await),
+        )
+        .nest("/me", me::router(app_state.clone()).await)
+        .nest(
+            "/organizations",
+            organizations::router(app_state.clone()).await,
+        )
         .nest("/jwks", jwks::router(app_state).await)
 }
@@ -1,47 +1,119 @@
 use std::sync::Arc;
 
 use axum::{
-    Router,
+    Json, Router,
     extract::{
         Path, Query, State,
-        rejection::{PathRejection, QueryRejection},
+        rejection::{JsonRejection, PathRejection, QueryRejection},
     },
+    middleware,
     response::{IntoResponse, Redirect, Response},
-    routing::get,
+    routing::{get, post},
 };
-use axum_extra::extract::CookieJar;
-use deadpool::managed::Pool;
-use deadpool_memcached::Manager;
-use sea_orm::{DatabaseConnection, TransactionTrait};
+use axum_extra::extract::{CookieJar, cookie::Cookie};
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
 use serde::Deserialize;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 use crate::{
     api::{
-        state::types::{app::AppState, jwt_issuer, oauth_client::OAuthProviderClient},
-        types::{cookie::COOKIE_AUTH_REQUEST_ID, session::SessionCookieConfig},
+        extractor::{auth_user::AuthUser, device_info::DeviceInfo},
+        middleware::security_headers::no_store,
+        response::types::link::PendingIdentityLinkResponse,
+        state::types::{
+            app::AppState, audit_log::AuditLog, jwt_issuer, lockout_tracker::LockoutTracker,
+            metrics::Metrics, oauth_client::OAuthProviderClient,
+        },
+        types::{
+            account_linking::{AccountLinkingConfig, AccountLinkingPolicy},
+            audit::{AuditEvent, AuditEventType},
+            cookie::{COOKIE_AUTH_REQUEST_ID, COOKIE_SSO_SESSION},
+            lockout::LockoutSubject,
+            realm::Realm,
+            session::SessionCookieConfig,
+        },
+        v1::{
+            authorize::redirect_with_authorization_code,
+            token::{TokenFamily, ensure_user_can_sign_in, issue_tokens},
+        },
     },
-    db::repo::users::UsersRepo,
-    memcached::{
+    db::repo::{
+        user_identities::{LinkIdentityResult, UserIdentitiesRepo},
+        users::UsersRepo,
+    },
+    entity::users,
+    provider::types::{config::AuthRedirectInfo, idp::OAuthProvider, profile::UserProfile},
+    state_store::{
+        StateStore,
         repo::{
-            cache_auth_redirect_info_by_session_id,
-            get_auth_redirect_info_from_memecached_by_session_id,
+            cache_auth_redirect_info_by_state, cache_pending_identity_link_by_ticket,
+            consume_auth_redirect_info_by_state, create_sso_session,
+            delete_pending_identity_link_by_ticket, delete_sso_session,
+            get_pending_identity_link_by_ticket, get_sso_session,
+        },
+        types::{
+            AuthVerifyToken, AuthorizationRequest, PendingIdentityLink, SsoSession,
+            StateConsumeError,
         },
-        types::AuthVerifyToken,
     },
-    provider::types::{config::AuthRedirectInfo, idp::OAuthProvider},
-    utils::error::AllForOneError,
+    utils::{error::AllForOneError, token::generate_opaque_token},
 };
 
 pub async fn oauth_login(
     path: Result<Path<OAuthProvider>, PathRejection>,
     State(oauth_client): State<Arc<OAuthProviderClient>>,
-    State(memcached_client): State<Arc<Pool<Manager>>>,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(session_config): State<Arc<SessionCookieConfig>>,
+    jar: CookieJar,
+) -> Result<Response, AllForOneError> {
+    let Path(idp) = path?;
+
+    redirect_to_idp(
+        idp,
+        None,
+        None,
+        oauth_client,
+        state_store,
+        session_config,
+        jar,
+    )
+    .await
+}
+
+/// 로그인한 사용자에게 다른 provider identity 를 연결
+async fn oauth_link(
+    path: Result<Path<OAuthProvider>, PathRejection>,
+    auth_user: AuthUser,
+    State(oauth_client): State<Arc<OAuthProviderClient>>,
+    State(state_store): State<Arc<dyn StateStore>>,
     State(session_config): State<Arc<SessionCookieConfig>>,
     jar: CookieJar,
 ) -> Result<Response, AllForOneError> {
     let Path(idp) = path?;
 
+    redirect_to_idp(
+        idp,
+        Some(auth_user.user_id),
+        None,
+        oauth_client,
+        state_store,
+        session_config,
+        jar,
+    )
+    .await
+}
+
+/// `authorization` 이 있으면 로그인을 마친 뒤 그 클라이언트로 code 를 돌려보냄
+pub(super) async fn redirect_to_idp(
+    idp: OAuthProvider,
+    link_user_id: Option<Uuid>,
+    authorization: Option<AuthorizationRequest>,
+    oauth_client: Arc<OAuthProviderClient>,
+    state_store: Arc<dyn StateStore>,
+    session_config: Arc<SessionCookieConfig>,
+    jar: CookieJar,
+) -> Result<Response, AllForOneError> {
     let AuthRedirectInfo {
         auth_url,
         csrf_token,
@@ -50,14 +122,18 @@ pub async fn oauth_login(
     } = oauth_client.auth_request(idp).await;
 
     let session_id = Uuid::now_v7();
+    let state = csrf_token.clone();
     let cache_body = AuthVerifyToken {
         csrf_token,
         pkce_verifier,
         nonce,
+        link_user_id,
+        session_id: Some(session_id),
+        authorization,
     };
-    cache_auth_redirect_info_by_session_id(
-        memcached_client,
-        session_id,
+    cache_auth_redirect_info_by_state(
+        state_store.as_ref(),
+        &state,
         &cache_body,
         session_config.cache_ttl,
     )
@@ -75,57 +151,295 @@ struct OAuthCallbackQuery {
     pub state: String,
 }
 
+#[allow(clippy::too_many_arguments)]
 async fn oauth_callback(
     query: Result<Query<OAuthCallbackQuery>, QueryRejection>,
     path: Result<Path<OAuthProvider>, PathRejection>,
     State(oauth_client): State<Arc<OAuthProviderClient>>,
-    State(memcached_client): State<Arc<Pool<Manager>>>,
+    State(state_store): State<Arc<dyn StateStore>>,
     State(db_client): State<Arc<DatabaseConnection>>,
     State(jwt_issuer): State<Arc<jwt_issuer::JwtIssuer>>,
     State(session_config): State<Arc<SessionCookieConfig>>,
+    State(linking_config): State<Arc<AccountLinkingConfig>>,
+    State(realm): State<Arc<Realm>>,
+    State(audit_log): State<Arc<AuditLog>>,
+    State(metrics): State<Arc<Metrics>>,
+    State(lockout_tracker): State<Arc<LockoutTracker>>,
+    device: DeviceInfo,
     jar: CookieJar,
 ) -> Result<Response, AllForOneError> {
     let Path(idp) = path?;
     let Query(callback_params) = query?;
+    // 콜백 전에는 어느 클라이언트의 로그인인지 모르므로 IP 로만 셈
+    let client = LockoutSubject::Client {
+        client_id: None,
+        ip_address: device.ip_address.clone(),
+    };
+    lockout_tracker.check(&[&client]).await?;
+    let login_failure = |reason: &str| AuditEvent::failure(AuditEventType::Login, &device, reason);
+    // SameSite=Strict, 인앱 브라우저, 다른 기기에서 끝낸 로그인은 쿠키가 없을 수 있음
     let session_id = jar
         .get(COOKIE_AUTH_REQUEST_ID)
-        .and_then(|cookie| cookie.value().parse::<Uuid>().ok())
-        .ok_or_else(|| AllForOneError::Auth("session id is not found".to_string()))?;
+        .map(|cookie| cookie.value().parse::<Uuid>().ok());
+
+    let previous_sso_token = jar
+        .get(COOKIE_SSO_SESSION)
+        .map(|cookie| cookie.value().to_string());
 
     let session_remove = session_config.create_session_removal_cookie();
     let updated_jar = jar.remove(session_remove);
 
-    let verification_token =
-        get_auth_redirect_info_from_memecached_by_session_id(memcached_client, session_id).await?;
+    let verification_token = match consume_auth_redirect_info_by_state(
+        state_store.as_ref(),
+        &callback_params.state,
+        session_config.cache_ttl,
+    )
+    .await
+    {
+        Ok(verification_token) => verification_token,
+        Err(StateConsumeError::NotFound) => {
+            record_login(
+                &audit_log,
+                &metrics,
+                &realm,
+                &idp,
+                login_failure("login state is not found or expired"),
+            )
+            .await;
+            lockout_tracker.record_failure(&[&client]).await;
+            return Err(AllForOneError::Auth(
+                "login state is not found or expired".to_string(),
+            ));
+        }
+        Err(StateConsumeError::Replayed) => {
+            warn!(
+                target: "security",
+                "replayed oauth callback for {} login state",
+                idp.as_str()
+            );
+            record_login(
+                &audit_log,
+                &metrics,
+                &realm,
+                &idp,
+                login_failure("login state is already used"),
+            )
+            .await;
+            lockout_tracker.record_failure(&[&client]).await;
+            return Err(AllForOneError::Replay(
+                "login state is already used".to_string(),
+            ));
+        }
+        Err(StateConsumeError::Store(err)) => return Err(err.into()),
+    };
     if verification_token.csrf_token != callback_params.state {
+        record_login(
+            &audit_log,
+            &metrics,
+            &realm,
+            &idp,
+            login_failure("csrf token is invalid"),
+        )
+        .await;
+        lockout_tracker.record_failure(&[&client]).await;
         return Err(AllForOneError::Auth("csrf token is invalid".to_string()));
     }
+    // 쿠키가 남아 있으면 로그인을 시작한 브라우저와 같은지 추가로 확인
+    if let Some(session_id) = session_id
+        && session_id != verification_token.session_id
+    {
+        warn!(
+            target: "security",
+            "oauth callback session cookie does not match {} login state",
+            idp.as_str()
+        );
+        record_login(
+            &audit_log,
+            &metrics,
+            &realm,
+            &idp,
+            login_failure("login state does not belong to this session"),
+        )
+        .await;
+        lockout_tracker.record_failure(&[&client]).await;
+        return Err(AllForOneError::Auth(
+            "login state does not belong to this session".to_string(),
+        ));
+    }
+    // 쿠키가 없으면 이 브라우저가 로그인을 시작했는지 알 수 없어서, 남이 시작한 로그인의 콜백을
+    // 열게 하는 login CSRF 를 막지 못함. 클라이언트의 state 와 PKCE 로 묶인 authorize 요청만 끝내고
+    // 이 브라우저에는 SSO 세션을 만들지 않음
+    let browser_bound = session_id.is_some();
+    if !browser_bound && verification_token.authorization.is_none() {
+        warn!(
+            target: "security",
+            "oauth callback without session cookie for {} login state",
+            idp.as_str()
+        );
+        record_login(
+            &audit_log,
+            &metrics,
+            &realm,
+            &idp,
+            login_failure("login session cookie is missing"),
+        )
+        .await;
+        lockout_tracker.record_failure(&[&client]).await;
+        return Err(AllForOneError::Auth(
+            "login session cookie is missing".to_string(),
+        ));
+    }
 
-    let access_token = oauth_client
+    let access_token = match oauth_client
         .callback(
             idp.clone(),
             callback_params.code,
             verification_token.pkce_verifier,
         )
-        .await?;
+        .await
+    {
+        Ok(access_token) => access_token,
+        Err(err) => {
+            let failure = login_failure("provider token exchange failed");
+            record_login(&audit_log, &metrics, &realm, &idp, failure).await;
+            lockout_tracker.record_failure(&[&client]).await;
+            return Err(err.into());
+        }
+    };
 
-    let idp_uid = oauth_client
-        .get_user_info(idp.clone(), access_token.clone())
-        .await?;
+    let profile = match oauth_client
+        .get_user_profile(idp.clone(), access_token.clone())
+        .await
+    {
+        Ok(profile) => profile,
+        Err(err) => {
+            let failure = login_failure("provider user profile request failed");
+            record_login(&audit_log, &metrics, &realm, &idp, failure).await;
+            lockout_tracker.record_failure(&[&client]).await;
+            return Err(err.into());
+        }
+    };
 
+    let is_link = verification_token.link_user_id.is_some();
     let txn = db_client.begin().await?;
-    let user_repo = UsersRepo::new(&txn);
-    let user = user_repo
-        .get_or_create_user_if_not_exist(idp, idp_uid)
-        .await?;
+    let login_result = match verification_token.link_user_id {
+        Some(user_id) => {
+            LoginResult::User(link_identity(&txn, user_id, idp.clone(), profile.idp_uid).await?)
+        }
+        None => login_user(&txn, &realm, &linking_config, idp.clone(), profile).await?,
+    };
     txn.commit().await?;
 
-    let key_id = jwt_issuer.get_kid();
-    let access_token_ttl = jwt_issuer.get_access_token_ttl();
-    let jwt = jwt_issuer
-        .issue_jwt(key_id, user.id, access_token_ttl)
-        .map_err(|e| AllForOneError::Auth(format!("fail to issue jwt: {}", e)))?;
-    let response_body = crate::api::response::types::token::Token { access_token: jwt };
+    let user = match login_result {
+        LoginResult::User(user) => user,
+        LoginResult::Created(user) => {
+            audit_log
+                .record(AuditEvent::success(AuditEventType::UserCreate, &device).actor(user.id))
+                .await;
+            user
+        }
+        LoginResult::PendingLink(pending_link) => {
+            let link_ticket = Uuid::now_v7();
+            let response_body = PendingIdentityLinkResponse {
+                link_ticket,
+                idp: pending_link.idp.clone(),
+                expires_in: session_config.cache_ttl,
+            };
+            cache_pending_identity_link_by_ticket(
+                state_store.as_ref(),
+                link_ticket,
+                &pending_link,
+                session_config.cache_ttl,
+            )
+            .await?;
+
+            return Ok((
+                updated_jar,
+                (axum::http::StatusCode::ACCEPTED, axum::Json(response_body)),
+            )
+                .into_response());
+        }
+    };
+
+    if let Err(err) = ensure_user_can_sign_in(&user) {
+        record_login(
+            &audit_log,
+            &metrics,
+            &realm,
+            &idp,
+            login_failure(&err.reason()).actor(user.id),
+        )
+        .await;
+        return Err(err);
+    }
+    record_login(
+        &audit_log,
+        &metrics,
+        &realm,
+        &idp,
+        AuditEvent::success(AuditEventType::Login, &device)
+            .actor(user.id)
+            .client(
+                verification_token
+                    .authorization
+                    .as_ref()
+                    .map(|authorization| authorization.client_id.as_str()),
+            ),
+    )
+    .await;
+
+    // 계정 연결은 이미 로그인한 사용자의 요청이라 SSO 세션을 새로 만들지 않음
+    let (updated_jar, sso_session_id) = if is_link {
+        (updated_jar, None)
+    } else {
+        let (updated_jar, sso_session) = if browser_bound {
+            let (sso_cookie, sso_session) = start_sso_session(
+                state_store.as_ref(),
+                &session_config,
+                &previous_sso_token,
+                user.id,
+                idp.clone(),
+            )
+            .await?;
+            (updated_jar.add(sso_cookie), sso_session)
+        } else {
+            // sid 는 이번에 발급할 토큰 계열을 묶는 데만 씀
+            let sso_session = SsoSession {
+                sid: Uuid::now_v7(),
+                user_id: user.id,
+                idp: idp.clone(),
+                auth_time: chrono::Utc::now().timestamp(),
+            };
+            (updated_jar, sso_session)
+        };
+        if let Some(authorization) = verification_token.authorization {
+            let redirect = redirect_with_authorization_code(
+                state_store.as_ref(),
+                &realm,
+                authorization,
+                &sso_session,
+            )
+            .await?;
+            return Ok((updated_jar, redirect).into_response());
+        }
+        (updated_jar, Some(sso_session.sid))
+    };
+
+    let response_body = issue_tokens(
+        db_client.as_ref(),
+        &jwt_issuer,
+        &realm,
+        user.id,
+        TokenFamily::New {
+            idp,
+            client_id: None,
+            device: &device,
+        },
+        None,
+        sso_session_id,
+    )
+    .await?;
+    metrics.record_tokens(&realm.name, "oauth_callback", &response_body);
 
     Ok((
         updated_jar,
@@ -134,9 +448,178 @@ async fn oauth_callback(
         .into_response())
 }
 
+/// provider 로그인을 마친 브라우저에 새 SSO 세션을 발급. 이전 세션은 폐기
+/// provider 가 자기 세션으로 조용히 승인했을 수 있으므로, 같은 사용자의 이전 세션이 있으면
+/// 그 `auth_time` 을 이어받아 재인증한 것처럼 보이지 않게 함
+async fn start_sso_session(
+    state_store: &dyn StateStore,
+    session_config: &SessionCookieConfig,
+    previous_token: &Option<String>,
+    user_id: Uuid,
+    idp: OAuthProvider,
+) -> Result<(Cookie<'static>, SsoSession), AllForOneError> {
+    let mut auth_time = chrono::Utc::now().timestamp();
+    if let Some(previous_token) = previous_token {
+        if let Some(previous) = get_sso_session(state_store, previous_token).await?
+            && previous.user_id == user_id
+        {
+            auth_time = previous.auth_time;
+        }
+        delete_sso_session(state_store, previous_token).await?;
+    }
+
+    let token = generate_opaque_token()?;
+    let sso_session = SsoSession {
+        sid: Uuid::now_v7(),
+        user_id,
+        idp,
+        auth_time,
+    };
+    create_sso_session(state_store, &token, &sso_session, session_config.sso_ttl).await?;
+
+    Ok((session_config.create_sso_cookie(&token), sso_session))
+}
+
+/// 로그인 결과를 감사 로그와 지표에 함께 남김
+async fn record_login(
+    audit_log: &AuditLog,
+    metrics: &Metrics,
+    realm: &Realm,
+    idp: &OAuthProvider,
+    event: AuditEvent,
+) {
+    metrics.record_login(&realm.name, idp.as_str(), event.outcome.as_str());
+    audit_log.record(event).await;
+}
+
+enum LoginResult {
+    User(users::Model),
+    /// 처음 보는 identity 라서 사용자를 새로 만듦
+    Created(users::Model),
+    PendingLink(PendingIdentityLink),
+}
+
+/// 처음 보는 identity 의 검증된 email 이 기존 계정과 같으면 정책에 따라 연결하거나 확인을 요청
+async fn login_user<C: ConnectionTrait + TransactionTrait>(
+    conn: &C,
+    realm: &Realm,
+    linking_config: &AccountLinkingConfig,
+    idp: OAuthProvider,
+    profile: UserProfile,
+) -> Result<LoginResult, AllForOneError> {
+    let users_repo = UsersRepo::new(conn);
+    let is_new_identity = UserIdentitiesRepo::new(conn)
+        .get_identity_by_idp_and_idp_uid(&realm.name, idp.clone(), profile.idp_uid.clone())
+        .await?
+        .is_none();
+
+    if linking_config.links_by_email(&idp)
+        && is_new_identity
+        && let Some(email) = profile.email.as_deref()
+        && let Some(user) = users_repo.get_user_by_email(&realm.name, email).await?
+    {
+        match linking_config.policy {
+            AccountLinkingPolicy::Auto => {
+                info!(
+                    "link {} identity to existing user by verified email",
+                    idp.as_str()
+                );
+                let user = link_identity(conn, user.id, idp, profile.idp_uid).await?;
+                return Ok(LoginResult::User(user));
+            }
+            AccountLinkingPolicy::Prompt => {
+                return Ok(LoginResult::PendingLink(PendingIdentityLink {
+                    user_id: user.id,
+                    idp,
+                    idp_uid: profile.idp_uid,
+                }));
+            }
+            AccountLinkingPolicy::Disabled => {}
+        }
+    }
+
+    let user = users_repo
+        .upsert_user_by_profile(&realm.name, idp, profile)
+        .await?;
+    if is_new_identity {
+        Ok(LoginResult::Created(user))
+    } else {
+        Ok(LoginResult::User(user))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct ConfirmLinkBody {
+    pub link_ticket: Uuid,
+}
+
+/// 기존 계정으로 로그인한 사용자가 email 기반 계정 연결을 확인
+async fn confirm_link(
+    auth_user: AuthUser,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    body: Result<Json<ConfirmLinkBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Json(body) = body?;
+
+    let pending_link = get_pending_identity_link_by_ticket(state_store.as_ref(), body.link_ticket)
+        .await
+        .map_err(|_| AllForOneError::NotFound("link ticket is not found".to_string()))?;
+    if pending_link.user_id != auth_user.user_id {
+        return Err(AllForOneError::Auth(
+            "link ticket does not belong to this user".to_string(),
+        ));
+    }
+
+    let txn = db_client.begin().await?;
+    link_identity(
+        &txn,
+        pending_link.user_id,
+        pending_link.idp,
+        pending_link.idp_uid,
+    )
+    .await?;
+    txn.commit().await?;
+
+    delete_pending_identity_link_by_ticket(state_store.as_ref(), body.link_ticket).await?;
+
+    Ok(axum::http::StatusCode::NO_CONTENT.into_response())
+}
+
+async fn link_identity<C: ConnectionTrait>(
+    conn: &C,
+    user_id: Uuid,
+    idp: OAuthProvider,
+    idp_uid: String,
+) -> Result<users::Model, AllForOneError> {
+    let user = UsersRepo::new(conn)
+        .get_user_by_id(user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::Auth("user is not found".to_string()))?;
+
+    match UserIdentitiesRepo::new(conn)
+        .link_identity(&user.realm, user.id, idp.clone(), idp_uid)
+        .await?
+    {
+        LinkIdentityResult::Linked | LinkIdentityResult::AlreadyLinked => Ok(user),
+        LinkIdentityResult::LinkedToOtherUser => Err(AllForOneError::Conflict(
+            "identity is already linked to another user".to_string(),
+        )),
+        LinkIdentityResult::ProviderAlreadyLinked => Err(AllForOneError::Conflict(format!(
+            "{} identity is already linked to this user",
+            idp.as_str()
+        ))),
+    }
+}
+
 pub async fn router(app_state: AppState) -> Router {
     axum::Router::new()
         .route("/{idp}/login", get(oauth_login))
-        .route("/{idp}/callback", get(oauth_callback))
+        .route("/{idp}/link", get(oauth_link))
+        .route("/link/confirm", post(confirm_link))
+        .route(
+            "/{idp}/callback",
+            get(oauth_callback).route_layer(middleware::from_fn(no_store)),
+        )
         .with_state(app_state)
 }
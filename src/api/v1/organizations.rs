@@ -0,0 +1,498 @@
+use std::sync::Arc;
+
+use axum::{
+    Json, Router,
+    extract::{
+        Path, State,
+        rejection::{JsonRejection, PathRejection},
+    },
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{delete, get, post, put},
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::auth_user::AuthUser,
+        response::types::organization::{Organization, OrganizationInvitation, OrganizationMember},
+        state::types::app::AppState,
+        types::{
+            organization::{OrganizationRole, is_valid_slug},
+            realm::Realm,
+        },
+        v1::groups,
+    },
+    db::repo::{groups::GroupsRepo, organizations::OrganizationsRepo, users::UsersRepo},
+    entity::organization_members,
+    utils::{
+        error::AllForOneError,
+        token::{generate_opaque_token, hash_token},
+    },
+};
+
+/// 초대 토큰 유효 기간 (7일)
+const INVITATION_TTL: i64 = 7 * 24 * 60 * 60;
+
+/// 요청한 사용자가 조직에서 `required` 이상의 역할인지 확인. 멤버가 아니면 조직이 없는 것처럼 404
+pub async fn require_membership<C: ConnectionTrait>(
+    conn: &C,
+    organization_id: Uuid,
+    user_id: Uuid,
+    required: OrganizationRole,
+) -> Result<organization_members::Model, AllForOneError> {
+    let member = OrganizationsRepo::new(conn)
+        .get_member(organization_id, user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("organization is not found".to_string()))?;
+
+    if OrganizationRole::parse(&member.role) < required {
+        return Err(AllForOneError::Forbidden(format!(
+            "{} role is required",
+            required.as_str()
+        )));
+    }
+    Ok(member)
+}
+
+async fn list_organizations(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let organizations = OrganizationsRepo::new(db_client.as_ref())
+        .list_organizations_by_user_id(auth_user.user_id)
+        .await?
+        .into_iter()
+        .map(Organization::from)
+        .collect::<Vec<_>>();
+    Ok(Json(organizations).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct CreateOrganizationBody {
+    pub name: String,
+    pub slug: String,
+}
+
+/// 만든 사용자가 owner 가 됨
+async fn create_organization(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    body: Result<Json<CreateOrganizationBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Json(body) = body?;
+    let name = body.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AllForOneError::BadRequest(
+            "organization name cannot be empty".to_string(),
+        ));
+    }
+    if !is_valid_slug(&body.slug) {
+        return Err(AllForOneError::BadRequest(format!(
+            "invalid organization slug: {}",
+            body.slug
+        )));
+    }
+
+    let txn = db_client.begin().await?;
+    let organizations_repo = OrganizationsRepo::new(&txn);
+    if organizations_repo
+        .get_organization_by_slug(&realm.name, &body.slug)
+        .await?
+        .is_some()
+    {
+        return Err(AllForOneError::Conflict(format!(
+            "organization {} already exists",
+            body.slug
+        )));
+    }
+
+    let (organization, member) = organizations_repo
+        .create_organization(&realm.name, name, body.slug, auth_user.user_id)
+        .await?;
+    txn.commit().await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(Organization::from((organization, member))),
+    )
+        .into_response())
+}
+
+async fn get_organization(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let db_client = db_client.as_ref();
+    let member = require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Member,
+    )
+    .await?;
+
+    let organization = OrganizationsRepo::new(db_client)
+        .get_organization_by_id(organization_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("organization is not found".to_string()))?;
+    Ok(Json(Organization::from((organization, member))).into_response())
+}
+
+async fn delete_organization(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Owner,
+    )
+    .await?;
+
+    OrganizationsRepo::new(db_client)
+        .delete_organization(organization_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn list_members(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Member,
+    )
+    .await?;
+
+    let members = OrganizationsRepo::new(db_client)
+        .list_members(organization_id)
+        .await?
+        .into_iter()
+        .map(OrganizationMember::from)
+        .collect::<Vec<_>>();
+    Ok(Json(members).into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct UpdateMemberBody {
+    pub role: OrganizationRole,
+}
+
+/// admin 은 member/admin 을 바꿀 수 있고, owner 를 주거나 뺏는 것은 owner 만 가능
+async fn update_member_role(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    body: Result<Json<UpdateMemberBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, user_id)) = path?;
+    let Json(body) = body?;
+
+    let txn = db_client.begin().await?;
+    let organizations_repo = OrganizationsRepo::new(&txn);
+    let target = organizations_repo
+        .get_member(organization_id, user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("member is not found".to_string()))?;
+    let current_role = OrganizationRole::parse(&target.role);
+
+    let required =
+        if current_role == OrganizationRole::Owner || body.role == OrganizationRole::Owner {
+            OrganizationRole::Owner
+        } else {
+            OrganizationRole::Admin
+        };
+    require_membership(&txn, organization_id, auth_user.user_id, required).await?;
+
+    if current_role == OrganizationRole::Owner && body.role != OrganizationRole::Owner {
+        ensure_not_last_owner(&organizations_repo, organization_id).await?;
+    }
+
+    let member = organizations_repo
+        .update_member_role(target, body.role)
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(OrganizationMember::from(member)).into_response())
+}
+
+/// 스스로 나가거나 admin 이 내보냄. owner 를 내보내는 것은 owner 만 가능
+async fn remove_member(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, user_id)) = path?;
+
+    let txn = db_client.begin().await?;
+    let organizations_repo = OrganizationsRepo::new(&txn);
+    let target = organizations_repo
+        .get_member(organization_id, user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::NotFound("member is not found".to_string()))?;
+    let target_role = OrganizationRole::parse(&target.role);
+
+    if user_id != auth_user.user_id {
+        let required = if target_role == OrganizationRole::Owner {
+            OrganizationRole::Owner
+        } else {
+            OrganizationRole::Admin
+        };
+        require_membership(&txn, organization_id, auth_user.user_id, required).await?;
+    }
+    if target_role == OrganizationRole::Owner {
+        ensure_not_last_owner(&organizations_repo, organization_id).await?;
+    }
+
+    organizations_repo
+        .remove_member(organization_id, user_id)
+        .await?;
+    GroupsRepo::new(&txn)
+        .remove_user_from_organization_groups(organization_id, user_id)
+        .await?;
+    txn.commit().await?;
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+async fn ensure_not_last_owner<C: ConnectionTrait>(
+    organizations_repo: &OrganizationsRepo<'_, C>,
+    organization_id: Uuid,
+) -> Result<(), AllForOneError> {
+    let owner_count = organizations_repo
+        .count_members_by_role(organization_id, OrganizationRole::Owner)
+        .await?;
+    if owner_count <= 1 {
+        return Err(AllForOneError::Conflict(
+            "organization must have at least one owner".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Deserialize, Debug)]
+struct CreateInvitationBody {
+    pub email: String,
+    pub role: OrganizationRole,
+}
+
+/// 초대 토큰은 응답으로 한 번만 내려주고 해시만 저장. 전달은 호출한 쪽이 담당
+async fn create_invitation(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    body: Result<Json<CreateInvitationBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let Json(body) = body?;
+    let email = body.email.trim().to_lowercase();
+    if !email.contains('@') {
+        return Err(AllForOneError::BadRequest(format!(
+            "invalid email: {}",
+            body.email
+        )));
+    }
+
+    let db_client = db_client.as_ref();
+    let required = if body.role == OrganizationRole::Owner {
+        OrganizationRole::Owner
+    } else {
+        OrganizationRole::Admin
+    };
+    require_membership(db_client, organization_id, auth_user.user_id, required).await?;
+
+    let organizations_repo = OrganizationsRepo::new(db_client);
+    if let Some(user) = UsersRepo::new(db_client)
+        .get_user_by_email(&realm.name, &email)
+        .await?
+        && organizations_repo
+            .get_member(organization_id, user.id)
+            .await?
+            .is_some()
+    {
+        return Err(AllForOneError::Conflict(
+            "user is already a member of the organization".to_string(),
+        ));
+    }
+
+    let token = generate_opaque_token()?;
+    let invitation = organizations_repo
+        .create_invitation(
+            organization_id,
+            email,
+            body.role,
+            hash_token(&token),
+            auth_user.user_id,
+            INVITATION_TTL,
+        )
+        .await?;
+
+    let response_body = OrganizationInvitation {
+        token: Some(token),
+        ..OrganizationInvitation::from(invitation)
+    };
+    Ok((StatusCode::CREATED, Json(response_body)).into_response())
+}
+
+async fn list_invitations(
+    auth_user: AuthUser,
+    path: Result<Path<Uuid>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path(organization_id) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+
+    let invitations = OrganizationsRepo::new(db_client)
+        .list_pending_invitations(organization_id)
+        .await?
+        .into_iter()
+        .map(OrganizationInvitation::from)
+        .collect::<Vec<_>>();
+    Ok(Json(invitations).into_response())
+}
+
+async fn delete_invitation(
+    auth_user: AuthUser,
+    path: Result<Path<(Uuid, Uuid)>, PathRejection>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+) -> Result<Response, AllForOneError> {
+    let Path((organization_id, invitation_id)) = path?;
+    let db_client = db_client.as_ref();
+    require_membership(
+        db_client,
+        organization_id,
+        auth_user.user_id,
+        OrganizationRole::Admin,
+    )
+    .await?;
+
+    let deleted = OrganizationsRepo::new(db_client)
+        .delete_invitation(organization_id, invitation_id)
+        .await?;
+    if deleted == 0 {
+        return Err(AllForOneError::NotFound(
+            "invitation is not found".to_string(),
+        ));
+    }
+
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[derive(Deserialize, Debug)]
+struct AcceptInvitationBody {
+    pub token: String,
+}
+
+/// 초대받은 email 과 같은 email 의 사용자만 수락할 수 있음. 다른 realm 의 조직 초대는 없는 것으로 취급
+async fn accept_invitation(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+    body: Result<Json<AcceptInvitationBody>, JsonRejection>,
+) -> Result<Response, AllForOneError> {
+    let Json(body) = body?;
+
+    let txn = db_client.begin().await?;
+    let organizations_repo = OrganizationsRepo::new(&txn);
+    let invitation = organizations_repo
+        .get_invitation_by_hash(&hash_token(&body.token))
+        .await?
+        .filter(|invitation| {
+            invitation.accepted_at.is_none() && invitation.expires_at > chrono::Utc::now()
+        })
+        .ok_or_else(|| AllForOneError::NotFound("invitation is not found".to_string()))?;
+    let organization = organizations_repo
+        .get_organization_by_id(invitation.organization_id)
+        .await?
+        .filter(|organization| organization.realm == realm.name)
+        .ok_or_else(|| AllForOneError::NotFound("invitation is not found".to_string()))?;
+
+    let user = UsersRepo::new(&txn)
+        .get_user_by_id(auth_user.user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::Auth("user is not found".to_string()))?;
+    if !user
+        .email
+        .as_deref()
+        .is_some_and(|email| email.eq_ignore_ascii_case(&invitation.email))
+    {
+        return Err(AllForOneError::Forbidden(
+            "invitation was sent to another email".to_string(),
+        ));
+    }
+
+    if organizations_repo
+        .get_member(invitation.organization_id, user.id)
+        .await?
+        .is_some()
+    {
+        return Err(AllForOneError::Conflict(
+            "user is already a member of the organization".to_string(),
+        ));
+    }
+    if organizations_repo.accept_invitation(invitation.id).await? == 0 {
+        return Err(AllForOneError::Conflict(
+            "invitation is already accepted".to_string(),
+        ));
+    }
+
+    let member = organizations_repo
+        .add_member(
+            invitation.organization_id,
+            user.id,
+            OrganizationRole::parse(&invitation.role),
+        )
+        .await?;
+    txn.commit().await?;
+
+    Ok(Json(Organization::from((organization, member))).into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/", get(list_organizations).post(create_organization))
+        .route("/invitations/accept", post(accept_invitation))
+        .route(
+            "/{org_id}",
+            get(get_organization).delete(delete_organization),
+        )
+        .route("/{org_id}/members", get(list_members))
+        .route(
+            "/{org_id}/members/{user_id}",
+            put(update_member_role).delete(remove_member),
+        )
+        .route(
+            "/{org_id}/invitations",
+            get(list_invitations).post(create_invitation),
+        )
+        .route(
+            "/{org_id}/invitations/{invitation_id}",
+            delete(delete_invitation),
+        )
+        .with_state(app_state.clone())
+        .merge(groups::router(app_state).await)
+}
@@ -0,0 +1,683 @@
+use std::sync::Arc;
+
+use axum::{
+    Form, Json, Router,
+    extract::{State, rejection::FormRejection},
+    http::{HeaderMap, StatusCode},
+    middleware,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use sea_orm::{ConnectionTrait, DatabaseConnection, TransactionTrait};
+use serde::Deserialize;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        extractor::{
+            admin_auth::AdminAuth,
+            auth_user::AuthUser,
+            client_auth::{ClientAuth, basic_credentials},
+            device_info::DeviceInfo,
+        },
+        middleware::security_headers::no_store,
+        response::types::{introspection::Introspection, token::Token, userinfo::UserInfo},
+        state::types::{
+            app::AppState, audit_log::AuditLog, jwt_issuer::JwtIssuer,
+            lockout_tracker::LockoutTracker, metrics::Metrics,
+        },
+        types::{
+            audit::{AuditEvent, AuditEventType},
+            authorization::verify_pkce,
+            client::ClientRegistry,
+            jwt_claim::TokenAuthorization,
+            lockout::LockoutSubject,
+            realm::Realm,
+            session::SessionCookieConfig,
+        },
+    },
+    db::repo::{
+        groups::GroupsRepo, logouts::LogoutsRepo, organizations::OrganizationsRepo,
+        refresh_tokens::RefreshTokensRepo, roles::RolesRepo, sessions::SessionsRepo,
+        users::UsersRepo,
+    },
+    entity::users,
+    provider::types::idp::OAuthProvider,
+    state_store::{StateStore, repo::consume_authorization_code, types::StateConsumeError},
+    utils::{
+        error::AllForOneError,
+        token::{generate_opaque_token, hash_token},
+    },
+};
+
+/// 발급할 refresh token 의 계열. `client_id` 는 토큰을 받는 클라이언트로, 회전할 때 같은지 확인
+pub enum TokenFamily<'a> {
+    /// 새로 로그인해서 계열을 시작. 기기 세션을 기록
+    New {
+        idp: OAuthProvider,
+        client_id: Option<&'a str>,
+        device: &'a DeviceInfo,
+    },
+    /// 기존 계열의 토큰을 회전. 기기 세션의 마지막 사용 시각을 갱신
+    Rotate {
+        family_id: Uuid,
+        client_id: Option<&'a str>,
+        device: &'a DeviceInfo,
+    },
+}
+
+/// 사용자의 역할/권한을 담은 access token 과 refresh token 을 함께 발급
+/// `session_id` 는 토큰을 발급한 SSO 세션으로, 로그아웃할 때 함께 폐기
+pub async fn issue_tokens<C: ConnectionTrait>(
+    conn: &C,
+    jwt_issuer: &JwtIssuer,
+    realm: &Realm,
+    user_id: Uuid,
+    family: TokenFamily<'_>,
+    organization_id: Option<Uuid>,
+    session_id: Option<Uuid>,
+) -> Result<Token, AllForOneError> {
+    let authorization =
+        load_authorization(conn, realm, jwt_issuer.get_aud(), user_id, organization_id).await?;
+
+    let key_id = jwt_issuer.get_kid();
+    let access_token_ttl = jwt_issuer.get_access_token_ttl();
+    let access_token = jwt_issuer
+        .issue_jwt(key_id, user_id, access_token_ttl, authorization)
+        .map_err(|e| AllForOneError::Auth(format!("fail to issue jwt: {}", e)))?;
+
+    let sessions_repo = SessionsRepo::new(conn);
+    let (family_id, client_id) = match family {
+        TokenFamily::New {
+            idp,
+            client_id,
+            device,
+        } => {
+            let family_id = Uuid::now_v7();
+            sessions_repo
+                .create_session(
+                    family_id,
+                    user_id,
+                    idp,
+                    device.user_agent.clone(),
+                    device.ip_address.clone(),
+                )
+                .await?;
+            (family_id, client_id)
+        }
+        TokenFamily::Rotate {
+            family_id,
+            client_id,
+            device,
+        } => {
+            sessions_repo
+                .touch_session(
+                    family_id,
+                    device.user_agent.clone(),
+                    device.ip_address.clone(),
+                )
+                .await?;
+            (family_id, client_id)
+        }
+    };
+
+    let refresh_token = generate_opaque_token()?;
+    RefreshTokensRepo::new(conn)
+        .create_refresh_token(
+            user_id,
+            family_id,
+            organization_id,
+            session_id,
+            client_id.map(str::to_string),
+            hash_token(&refresh_token),
+            jwt_issuer.get_refresh_token_ttl(),
+        )
+        .await?;
+
+    Ok(Token {
+        access_token,
+        token_type: "Bearer".to_string(),
+        expires_in: access_token_ttl,
+        refresh_token,
+        id_token: None,
+    })
+}
+
+/// 조직을 선택했으면 그 조직의 멤버여야 하고, 조직 역할과 그룹을 함께 실음
+async fn load_authorization<C: ConnectionTrait>(
+    conn: &C,
+    realm: &Realm,
+    audience: &str,
+    user_id: Uuid,
+    organization_id: Option<Uuid>,
+) -> Result<TokenAuthorization, AllForOneError> {
+    let (roles, permissions) = RolesRepo::new(conn)
+        .get_user_authorization(&realm.name, user_id, audience)
+        .await?;
+    let mut authorization = TokenAuthorization {
+        roles,
+        permissions,
+        ..Default::default()
+    };
+
+    if let Some(organization_id) = organization_id {
+        let member = OrganizationsRepo::new(conn)
+            .get_member(organization_id, user_id)
+            .await?
+            .ok_or_else(|| {
+                AllForOneError::Forbidden("user is not a member of the organization".to_string())
+            })?;
+        authorization.org_id = Some(organization_id);
+        authorization.org_role = Some(member.role);
+        authorization.groups = GroupsRepo::new(conn)
+            .list_group_names_by_user_id(organization_id, user_id)
+            .await?;
+    }
+
+    Ok(authorization)
+}
+
+/// 로그인/토큰 발급을 막아야 하는 사용자면 403
+pub fn ensure_user_can_sign_in(user: &users::Model) -> Result<(), AllForOneError> {
+    match user.sign_in_denial_reason(chrono::Utc::now().into()) {
+        Some(reason) => Err(AllForOneError::Forbidden(reason)),
+        None => Ok(()),
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct TokenRequest {
+    pub grant_type: String,
+    pub refresh_token: Option<String>,
+    /// 다른 조직으로 전환할 때만 지정. 없으면 기존 토큰의 조직을 유지
+    pub organization_id: Option<Uuid>,
+    pub code: Option<String>,
+    pub redirect_uri: Option<String>,
+    pub client_id: Option<String>,
+    /// `client_secret_post`. `client_secret_basic` 이면 Authorization 헤더로 받음
+    pub client_secret: Option<String>,
+    pub code_verifier: Option<String>,
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn token(
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(jwt_issuer): State<Arc<JwtIssuer>>,
+    State(state_store): State<Arc<dyn StateStore>>,
+    State(session_config): State<Arc<SessionCookieConfig>>,
+    State(lockout_tracker): State<Arc<LockoutTracker>>,
+    State(clients): State<Arc<ClientRegistry>>,
+    State(audit_log): State<Arc<AuditLog>>,
+    State(metrics): State<Arc<Metrics>>,
+    State(realm): State<Arc<Realm>>,
+    device: DeviceInfo,
+    headers: HeaderMap,
+    form: Result<Form<TokenRequest>, FormRejection>,
+) -> Result<Response, AllForOneError> {
+    let Form(mut request) = form?;
+    let basic = basic_credentials(&headers);
+    if let Some((client_id, _)) = &basic {
+        request.client_id.get_or_insert_with(|| client_id.clone());
+    }
+    let client_id = request.client_id.clone();
+    let grant_type = request.grant_type.clone();
+    let audit_device = device.clone();
+
+    match grant(
+        &db_client,
+        &jwt_issuer,
+        &realm,
+        state_store.as_ref(),
+        &session_config,
+        &lockout_tracker,
+        &clients,
+        basic,
+        request,
+        device,
+    )
+    .await
+    {
+        Ok((user_id, response_body)) => {
+            metrics.record_tokens(&realm.name, &grant_type, &response_body);
+            audit_log
+                .record(
+                    AuditEvent::success(AuditEventType::TokenIssue, &audit_device)
+                        .actor(user_id)
+                        .client(client_id.as_deref()),
+                )
+                .await;
+            Ok((StatusCode::OK, Json(response_body)).into_response())
+        }
+        Err(err) => {
+            audit_log
+                .record(
+                    AuditEvent::failure(AuditEventType::TokenIssue, &audit_device, &err.reason())
+                        .client(client_id.as_deref()),
+                )
+                .await;
+            Err(err)
+        }
+    }
+}
+
+/// grant 종류에 맞게 토큰을 발급하고 토큰의 사용자를 함께 돌려줌
+/// code 와 refresh token 은 요청을 받은 realm 에서 발급된 것만 받음
+#[allow(clippy::too_many_arguments)]
+async fn grant(
+    db_client: &DatabaseConnection,
+    jwt_issuer: &JwtIssuer,
+    realm: &Realm,
+    state_store: &dyn StateStore,
+    session_config: &SessionCookieConfig,
+    lockout_tracker: &LockoutTracker,
+    clients: &ClientRegistry,
+    basic: Option<(String, String)>,
+    mut request: TokenRequest,
+    device: DeviceInfo,
+) -> Result<(Uuid, Token), AllForOneError> {
+    let client = LockoutSubject::client(
+        clients,
+        request.client_id.as_deref(),
+        device.ip_address.clone(),
+    );
+    lockout_tracker.check(&[&client]).await?;
+    if let Err(err) = authenticate_client(clients, basic, &mut request) {
+        lockout_tracker.record_failure(&[&client]).await;
+        return Err(err);
+    }
+
+    let issued = match request.grant_type.as_str() {
+        "refresh_token" => {
+            refresh_token_grant(
+                db_client,
+                jwt_issuer,
+                realm,
+                lockout_tracker,
+                &client,
+                request,
+                device,
+            )
+            .await?
+        }
+        "authorization_code" => {
+            authorization_code_grant(
+                db_client,
+                jwt_issuer,
+                realm,
+                state_store,
+                session_config,
+                lockout_tracker,
+                &client,
+                request,
+                device,
+            )
+            .await?
+        }
+        grant_type => {
+            return Err(AllForOneError::Auth(format!(
+                "unsupported grant type: {}",
+                grant_type
+            )));
+        }
+    };
+
+    // 클라이언트와 IP 의 실패는 한 번 성공했다고 지우지 않음. 유효한 토큰 하나로 추측을 이어가지 못하게 함
+    let account = LockoutSubject::Account(issued.0);
+    lockout_tracker.record_success(&[&account]).await;
+
+    Ok(issued)
+}
+
+/// secret 이 등록된 클라이언트는 `client_secret_basic` 이나 `client_secret_post` 로 인증해야 함
+/// 한 요청에 두 방식을 함께 쓰거나, 헤더와 본문의 client_id 가 다르면 거절
+fn authenticate_client(
+    clients: &ClientRegistry,
+    basic: Option<(String, String)>,
+    request: &mut TokenRequest,
+) -> Result<(), AllForOneError> {
+    let client_secret = match basic {
+        Some(_) if request.client_secret.is_some() => {
+            return Err(AllForOneError::BadRequest(
+                "only one client authentication method is allowed".to_string(),
+            ));
+        }
+        Some((client_id, _)) if request.client_id.as_deref() != Some(client_id.as_str()) => {
+            return Err(AllForOneError::Auth(
+                "client_id does not match client credentials".to_string(),
+            ));
+        }
+        Some((_, client_secret)) => Some(client_secret),
+        None => request.client_secret.take(),
+    };
+
+    let Some(client) = request
+        .client_id
+        .as_deref()
+        .and_then(|client_id| clients.get(client_id))
+    else {
+        return Ok(());
+    };
+    if client.secret_hash.is_none() {
+        return Ok(());
+    }
+    match client_secret {
+        Some(client_secret) if client.authenticate(&client_secret) => Ok(()),
+        _ => Err(AllForOneError::Auth(
+            "client authentication failed".to_string(),
+        )),
+    }
+}
+
+/// `/oauth/authorize` 에서 받은 code 를 토큰으로 교환. `openid` scope 면 id token 도 발급
+/// 잘못된 code 는 클라이언트의 실패로, 다른 클라이언트나 verifier 로 쓴 code 는 계정의 실패로도 셈
+#[allow(clippy::too_many_arguments)]
+async fn authorization_code_grant(
+    db_client: &DatabaseConnection,
+    jwt_issuer: &JwtIssuer,
+    realm: &Realm,
+    state_store: &dyn StateStore,
+    session_config: &SessionCookieConfig,
+    lockout_tracker: &LockoutTracker,
+    client: &LockoutSubject,
+    request: TokenRequest,
+    device: DeviceInfo,
+) -> Result<(Uuid, Token), AllForOneError> {
+    let (Some(code), Some(redirect_uri), Some(client_id), Some(code_verifier)) = (
+        request.code,
+        request.redirect_uri,
+        request.client_id,
+        request.code_verifier,
+    ) else {
+        return Err(AllForOneError::Auth(
+            "code, redirect_uri, client_id and code_verifier are required".to_string(),
+        ));
+    };
+
+    let authorization_code =
+        match consume_authorization_code(state_store, &code, session_config.cache_ttl).await {
+            Ok(authorization_code) => authorization_code,
+            Err(StateConsumeError::NotFound) => {
+                lockout_tracker.record_failure(&[client]).await;
+                return Err(AllForOneError::Auth(
+                    "authorization code is invalid or expired".to_string(),
+                ));
+            }
+            Err(StateConsumeError::Replayed) => {
+                warn!(target: "security", "authorization code is reused by {}", client_id);
+                lockout_tracker.record_failure(&[client]).await;
+                return Err(AllForOneError::Replay(
+                    "authorization code is already used".to_string(),
+                ));
+            }
+            Err(StateConsumeError::Store(err)) => return Err(err.into()),
+        };
+    if authorization_code.realm != realm.name {
+        warn!(
+            target: "security",
+            "authorization code of realm {} is used in realm {}",
+            authorization_code.realm,
+            realm.name
+        );
+        lockout_tracker.record_failure(&[client]).await;
+        return Err(AllForOneError::Auth(
+            "authorization code is invalid or expired".to_string(),
+        ));
+    }
+
+    let account = LockoutSubject::Account(authorization_code.user_id);
+    lockout_tracker.check(&[&account]).await?;
+
+    let authorization_request = authorization_code.request;
+    if authorization_request.client_id != client_id
+        || authorization_request.redirect_uri != redirect_uri
+    {
+        lockout_tracker.record_failure(&[client, &account]).await;
+        return Err(AllForOneError::Auth(
+            "authorization code was not issued to this client".to_string(),
+        ));
+    }
+    if !verify_pkce(&code_verifier, &authorization_request.code_challenge) {
+        lockout_tracker.record_failure(&[client, &account]).await;
+        return Err(AllForOneError::Auth("code verifier is invalid".to_string()));
+    }
+
+    let txn = db_client.begin().await?;
+    let user = UsersRepo::new(&txn)
+        .get_user_by_id(authorization_code.user_id)
+        .await?
+        .filter(|user| user.realm == realm.name)
+        .ok_or_else(|| AllForOneError::Auth("user is not found".to_string()))?;
+    ensure_user_can_sign_in(&user)?;
+    let mut response_body = issue_tokens(
+        &txn,
+        jwt_issuer,
+        realm,
+        user.id,
+        TokenFamily::New {
+            idp: authorization_code.idp,
+            client_id: Some(&client_id),
+            device: &device,
+        },
+        None,
+        Some(authorization_code.sid),
+    )
+    .await?;
+    // 로그아웃을 이 세션에서 토큰을 받은 클라이언트에만 알리도록 남김
+    LogoutsRepo::new(&txn)
+        .add_session_client(&realm.name, authorization_code.sid, &client_id, user.id)
+        .await?;
+    txn.commit().await?;
+
+    let requests_openid = authorization_request
+        .scope
+        .as_deref()
+        .is_some_and(|scope| scope.split_whitespace().any(|s| s == "openid"));
+    if requests_openid {
+        let id_token = jwt_issuer
+            .issue_id_token(
+                user.id,
+                &client_id,
+                authorization_request.nonce,
+                authorization_code.auth_time,
+                authorization_code.sid,
+            )
+            .map_err(|e| AllForOneError::Auth(format!("fail to issue id token: {}", e)))?;
+        response_body.id_token = Some(id_token);
+    }
+
+    Ok((user.id, response_body))
+}
+
+/// refresh token 을 회전시키며 새 토큰을 발급. 폐기된 토큰이 다시 쓰이면 계열 전체를 폐기
+/// 없는 토큰은 클라이언트의 실패로, 폐기된 토큰의 재사용이나 다른 클라이언트의 사용은 계정의 실패로도 셈
+async fn refresh_token_grant(
+    db_client: &DatabaseConnection,
+    jwt_issuer: &JwtIssuer,
+    realm: &Realm,
+    lockout_tracker: &LockoutTracker,
+    client: &LockoutSubject,
+    request: TokenRequest,
+    device: DeviceInfo,
+) -> Result<(Uuid, Token), AllForOneError> {
+    let refresh_token = request
+        .refresh_token
+        .ok_or_else(|| AllForOneError::Auth("refresh token is not found".to_string()))?;
+
+    let txn = db_client.begin().await?;
+    let refresh_tokens_repo = RefreshTokensRepo::new(&txn);
+    let Some(stored_token) = refresh_tokens_repo
+        .get_refresh_token_by_hash(&realm.name, &hash_token(&refresh_token))
+        .await?
+    else {
+        lockout_tracker.record_failure(&[client]).await;
+        return Err(AllForOneError::Auth("refresh token is invalid".to_string()));
+    };
+
+    let account = LockoutSubject::Account(stored_token.user_id);
+    if stored_token.revoked_at.is_some() {
+        warn!(
+            "revoked refresh token is reused, revoke token family {}",
+            stored_token.family_id
+        );
+        refresh_tokens_repo
+            .revoke_refresh_token_family(stored_token.family_id)
+            .await?;
+        txn.commit().await?;
+        lockout_tracker.record_failure(&[client, &account]).await;
+        return Err(AllForOneError::Auth("refresh token is revoked".to_string()));
+    }
+    lockout_tracker.check(&[&account]).await?;
+
+    if stored_token.client_id != request.client_id {
+        lockout_tracker.record_failure(&[client, &account]).await;
+        return Err(AllForOneError::Auth(
+            "refresh token was not issued to this client".to_string(),
+        ));
+    }
+    if stored_token.expires_at <= chrono::Utc::now() {
+        return Err(AllForOneError::Auth("refresh token is expired".to_string()));
+    }
+
+    let user = UsersRepo::new(&txn)
+        .get_user_by_id(stored_token.user_id)
+        .await?
+        .ok_or_else(|| AllForOneError::Auth("user is not found".to_string()))?;
+    ensure_user_can_sign_in(&user)?;
+
+    // 같은 토큰으로 동시에 들어온 요청은 하나만 폐기에 성공하고, 나머지는 재사용으로 봄
+    let revoked = refresh_tokens_repo
+        .revoke_refresh_token(stored_token.id)
+        .await?;
+    if revoked != 1 {
+        warn!(
+            "refresh token is used concurrently, revoke token family {}",
+            stored_token.family_id
+        );
+        refresh_tokens_repo
+            .revoke_refresh_token_family(stored_token.family_id)
+            .await?;
+        txn.commit().await?;
+        lockout_tracker.record_failure(&[client, &account]).await;
+        return Err(AllForOneError::Auth("refresh token is revoked".to_string()));
+    }
+    let response_body = issue_tokens(
+        &txn,
+        jwt_issuer,
+        realm,
+        user.id,
+        TokenFamily::Rotate {
+            family_id: stored_token.family_id,
+            client_id: stored_token.client_id.as_deref(),
+            device: &device,
+        },
+        request.organization_id.or(stored_token.organization_id),
+        stored_token.session_id,
+    )
+    .await?;
+    txn.commit().await?;
+
+    Ok((user.id, response_body))
+}
+
+#[derive(Deserialize, Debug)]
+struct IntrospectionRequest {
+    pub token: String,
+}
+
+/// RFC 7662 에 따라 secret 이 있는 클라이언트나 admin 만 호출할 수 있음
+/// 비활성/정지 사용자나 다른 realm 사용자의 토큰은 `active: false`
+async fn introspect(
+    client_auth: Result<ClientAuth, AllForOneError>,
+    admin_auth: Result<AdminAuth, AllForOneError>,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(jwt_issuer): State<Arc<JwtIssuer>>,
+    State(realm): State<Arc<Realm>>,
+    form: Result<Form<IntrospectionRequest>, FormRejection>,
+) -> Result<Response, AllForOneError> {
+    if let (Err(err), Err(_)) = (client_auth, admin_auth) {
+        return Err(err);
+    }
+    let Form(request) = form?;
+    let db_client = db_client.as_ref();
+
+    let introspection = match jwt_issuer.verify_jwt(&request.token) {
+        Ok(claims) => match active_user(db_client, &realm, claims.sub).await? {
+            Some(_) => Introspection {
+                active: true,
+                token_type: Some("access_token".to_string()),
+                sub: Some(claims.sub),
+                iss: Some(claims.iss),
+                aud: Some(claims.aud),
+                exp: Some(claims.exp),
+                iat: Some(claims.iat),
+                jti: Some(claims.jti),
+                org_id: claims.org_id,
+            },
+            None => Introspection::default(),
+        },
+        Err(_) => {
+            let stored_token = RefreshTokensRepo::new(db_client)
+                .get_refresh_token_by_hash(&realm.name, &hash_token(&request.token))
+                .await?
+                .filter(|token| {
+                    token.revoked_at.is_none() && token.expires_at > chrono::Utc::now()
+                });
+            match stored_token {
+                Some(token)
+                    if active_user(db_client, &realm, token.user_id)
+                        .await?
+                        .is_some() =>
+                {
+                    Introspection {
+                        active: true,
+                        token_type: Some("refresh_token".to_string()),
+                        sub: Some(token.user_id),
+                        exp: Some(token.expires_at.timestamp()),
+                        iat: Some(token.created_at.timestamp()),
+                        org_id: token.organization_id,
+                        ..Default::default()
+                    }
+                }
+                _ => Introspection::default(),
+            }
+        }
+    };
+
+    Ok(Json(introspection).into_response())
+}
+
+async fn active_user<C: ConnectionTrait>(
+    conn: &C,
+    realm: &Realm,
+    user_id: Uuid,
+) -> Result<Option<users::Model>, AllForOneError> {
+    Ok(UsersRepo::new(conn)
+        .get_user_by_id(user_id)
+        .await?
+        .filter(|user| user.realm == realm.name && ensure_user_can_sign_in(user).is_ok()))
+}
+
+async fn userinfo(
+    auth_user: AuthUser,
+    State(db_client): State<Arc<DatabaseConnection>>,
+    State(realm): State<Arc<Realm>>,
+) -> Result<Response, AllForOneError> {
+    let user = UsersRepo::new(db_client.as_ref())
+        .get_user_by_id(auth_user.user_id)
+        .await?
+        .filter(|user| user.realm == realm.name)
+        .ok_or_else(|| AllForOneError::Auth("user is not found".to_string()))?;
+    ensure_user_can_sign_in(&user)?;
+
+    Ok(Json(UserInfo::from(user)).into_response())
+}
+
+pub async fn router(app_state: AppState) -> Router {
+    axum::Router::new()
+        .route("/token", post(token))
+        .route("/introspect", post(introspect))
+        .route("/userinfo", get(userinfo))
+        .route_layer(middleware::from_fn(no_store))
+        .with_state(app_state)
+}
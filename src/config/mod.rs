@@ -3,4 +3,5 @@ pub mod types;
 pub mod validation;
 
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
@@ -100,8 +100,15 @@ x_content_type_options = true
         assert_eq!(config.server.domain, "http://127.0.0.1");
         assert_eq!(config.server.port, 3000);
         assert_eq!(config.logger.level, "debug");
-        assert_eq!(config.postgres.connect_info.db_name, "test_db");
+        assert_eq!(
+            config.postgres.as_ref().unwrap().connect_info.db_name,
+            "test_db"
+        );
         assert_eq!(config.oidc.github.client_id, "test_client_id");
+        assert!(!config.oidc.github.email_verified);
+        assert_eq!(config.security.account_linking.policy, "disabled");
+        assert!(config.admin.token.is_none());
+        assert!(config.admin.user_ids.is_empty());
     }
 
     #[test]
@@ -152,7 +159,7 @@ x_content_type_options = true
     #[test]
     fn test_config_validation_empty_postgres_username() {
         let mut config = create_valid_test_config();
-        config.postgres.connect_info.username = "".to_string();
+        config.postgres.as_mut().unwrap().connect_info.username = "".to_string();
 
         let result = validation::check_config_validation(config);
         assert!(result.is_err());
@@ -164,6 +171,76 @@ x_content_type_options = true
         );
     }
 
+    #[test]
+    fn test_config_validation_sqlite_backend() {
+        let mut config = create_valid_test_config();
+        config.postgres = None;
+        config.sqlite = Some(SqliteConfig {
+            path: ":memory:".to_string(),
+            sqlx_logging: false,
+            auto_migrate: true,
+        });
+
+        let config = validation::check_config_validation(config).unwrap();
+        assert!(config.auto_migrate());
+    }
+
+    #[test]
+    fn test_config_validation_database_backend_exclusive() {
+        let mut config = create_valid_test_config();
+        config.sqlite = Some(SqliteConfig {
+            path: "./all-for-one.db".to_string(),
+            sqlx_logging: false,
+            auto_migrate: false,
+        });
+
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Only one of [postgres] and [sqlite]")
+        );
+
+        let mut config = create_valid_test_config();
+        config.postgres = None;
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Either [postgres] or [sqlite]")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_state_store_backend() {
+        let mut config = create_valid_test_config();
+        config.state_store.backend = "etcd".to_string();
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid state store backend")
+        );
+
+        let mut config = create_valid_test_config();
+        config.state_store.backend = "redis".to_string();
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("redis_url is required")
+        );
+
+        let mut config = create_valid_test_config();
+        config.state_store.backend = "redis".to_string();
+        config.state_store.redis_url = Some("redis://127.0.0.1:6379".to_string());
+        assert!(validation::check_config_validation(config).is_ok());
+    }
+
     #[test]
     fn test_config_validation_invalid_jwt_ttl() {
         let mut config = create_valid_test_config();
@@ -179,17 +256,58 @@ x_content_type_options = true
         );
     }
 
+    #[test]
+    fn test_config_account_linking_defaults_to_disabled() {
+        let config_content = r#"
+policy = "prompt"
+"#;
+        let linking: AccountLinkingSecurityConfig = toml::from_str(config_content).unwrap();
+        assert_eq!(linking.policy, "prompt");
+        assert_eq!(AccountLinkingSecurityConfig::default().policy, "disabled");
+    }
+
+    #[test]
+    fn test_config_validation_invalid_account_linking_policy() {
+        let mut config = create_valid_test_config();
+        config.security.account_linking.policy = "always".to_string();
+
+        let result = validation::check_config_validation(config);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid account linking policy")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_short_admin_token() {
+        let mut config = create_valid_test_config();
+        config.admin.token = Some("too-short".to_string());
+
+        let result = validation::check_config_validation(config);
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Admin token must be at least 32 characters")
+        );
+    }
+
     fn create_valid_test_config() -> Config {
         Config {
             server: Server {
                 domain: "http://127.0.0.1".to_string(),
                 port: 3000,
                 user_agent: "AllForOne/0.1.0".to_string(),
+                trusted_proxies: vec![],
             },
             logger: LoggerConfig {
                 level: "debug".to_string(),
             },
-            postgres: PostgresConfig {
+            postgres: Some(PostgresConfig {
                 connect_info: PostgresConnectConfig {
                     address: "127.0.0.1".to_string(),
                     port: 5432,
@@ -206,8 +324,10 @@ x_content_type_options = true
                     max_lifetime: 8,
                     sqlx_logging: true,
                     log_level: "debug".to_string(),
+                    auto_migrate: false,
                 },
-            },
+            }),
+            sqlite: None,
             memcached: MemCachedConfig {
                 connect_info: MemeCachedConnectConfig {
                     address: "127.0.0.1".to_string(),
@@ -220,6 +340,7 @@ x_content_type_options = true
                     write_timeout: 60,
                 },
             },
+            state_store: StateStoreConfig::default(),
             jwks: JwksConfig {
                 iss: "https://auth.example.com".to_string(),
                 aud: "AllForOne-Project-Service".to_string(),
@@ -235,6 +356,7 @@ x_content_type_options = true
                     resource_url: "https://api.github.com".to_string(),
                     auth_url: "https://github.com/login/oauth/authorize".to_string(),
                     token_url: "https://github.com/login/oauth/access_token".to_string(),
+                    email_verified: true,
                 },
             },
             security: SecurityConfig {
@@ -250,12 +372,15 @@ x_content_type_options = true
                     secure_cookies: true,
                     same_site: "Lax".to_string(),
                     http_only: true,
+                    sso_ttl: 28800,
                 },
                 rate_limiting: RateLimitingConfig {
                     enabled: true,
                     requests_per_minute: 60,
                     burst_size: 10,
                     cleanup_interval: 300,
+                    backend: "local".to_string(),
+                    route_groups: vec![],
                 },
                 cors: CorsConfig {
                     enabled: true,
@@ -263,6 +388,8 @@ x_content_type_options = true
                     allowed_methods: vec!["GET".to_string(), "POST".to_string()],
                     allowed_headers: vec!["Content-Type".to_string(), "Authorization".to_string()],
                     max_age: 3600,
+                    allow_credentials: false,
+                    excluded_paths: vec![],
                 },
                 security_headers: SecurityHeadersConfig {
                     hsts_enabled: true,
@@ -272,10 +399,91 @@ x_content_type_options = true
                     x_frame_options: "DENY".to_string(),
                     x_content_type_options: true,
                 },
+                account_linking: AccountLinkingSecurityConfig {
+                    policy: "disabled".to_string(),
+                },
+                lockout: LockoutSecurityConfig::default(),
             },
+            admin: AdminConfig::default(),
+            realms: vec![],
+            clients: vec![],
+            webhooks: WebhooksConfig::default(),
+            metrics: MetricsConfig::default(),
         }
     }
 
+    fn create_test_realm(name: &str, iss: &str) -> RealmConfig {
+        let config = create_valid_test_config();
+        RealmConfig {
+            name: name.to_string(),
+            hosts: vec![],
+            jwks: JwksConfig {
+                iss: iss.to_string(),
+                ..config.jwks
+            },
+            oidc: config.oidc,
+            clients: vec![],
+        }
+    }
+
+    #[test]
+    fn test_config_validation_valid_realm() {
+        let mut config = create_valid_test_config();
+        let mut realm = create_test_realm("shop", "https://shop.example.com");
+        realm.hosts = vec!["shop.example.com".to_string()];
+        config.realms.push(realm);
+
+        assert!(validation::check_config_validation(config).is_ok());
+    }
+
+    #[test]
+    fn test_config_validation_invalid_realm_name() {
+        let mut config = create_valid_test_config();
+        config
+            .realms
+            .push(create_test_realm("Shop", "https://shop.example.com"));
+
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid realm name")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_realm_cannot_reuse_issuer() {
+        let mut config = create_valid_test_config();
+        config
+            .realms
+            .push(create_test_realm("shop", "https://auth.example.com"));
+
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("issuer is already used")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_realm_cannot_use_default_name() {
+        let mut config = create_valid_test_config();
+        config
+            .realms
+            .push(create_test_realm("default", "https://shop.example.com"));
+
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Duplicated realm name")
+        );
+    }
+
     #[test]
     fn test_config_read_from_file() {
         let config_content = r#"
@@ -382,4 +590,310 @@ x_content_type_options = true
         assert_eq!(config.server.domain, "http://127.0.0.1");
         assert_eq!(config.logger.level, "info");
     }
+
+    #[test]
+    fn test_config_validation_clients() {
+        let mut config = create_valid_test_config();
+        config.clients = vec![ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec![],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }];
+        assert!(validation::check_config_validation(config).is_ok());
+
+        let mut config = create_valid_test_config();
+        config.clients = vec![
+            ClientConfig {
+                client_id: "web".to_string(),
+                redirect_uris: vec!["https://app.example.com/callback".to_string()],
+                post_logout_redirect_uris: vec![],
+                backchannel_logout_uri: None,
+                frontchannel_logout_uri: None,
+                client_secret: None,
+            },
+            ClientConfig {
+                client_id: "web".to_string(),
+                redirect_uris: vec!["https://other.example.com/callback".to_string()],
+                post_logout_redirect_uris: vec![],
+                backchannel_logout_uri: None,
+                frontchannel_logout_uri: None,
+                client_secret: None,
+            },
+        ];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Duplicated client_id")
+        );
+
+        let mut config = create_valid_test_config();
+        config.clients = vec![ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback#token".to_string()],
+            post_logout_redirect_uris: vec![],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid client redirect_uri")
+        );
+
+        let mut config = create_valid_test_config();
+        config.clients = vec![ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec!["not a url".to_string()],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid client post_logout_redirect_uri")
+        );
+
+        let mut config = create_valid_test_config();
+        config.clients = vec![ClientConfig {
+            client_id: "web".to_string(),
+            redirect_uris: vec!["https://app.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec![],
+            backchannel_logout_uri: Some("/logout".to_string()),
+            frontchannel_logout_uri: None,
+            client_secret: None,
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid client logout uri")
+        );
+
+        let mut config = create_valid_test_config();
+        config.clients = vec![ClientConfig {
+            client_id: "api".to_string(),
+            redirect_uris: vec!["https://api.example.com/callback".to_string()],
+            post_logout_redirect_uris: vec![],
+            backchannel_logout_uri: None,
+            frontchannel_logout_uri: None,
+            client_secret: Some("short".to_string()),
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("client_secret must be at least 32 characters")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_rate_limit_route_groups() {
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.route_groups = vec![RateLimitRouteGroupConfig {
+            name: "token".to_string(),
+            path_prefix: "/api/v1/oauth/token".to_string(),
+            requests_per_minute: 10,
+            burst_size: 5,
+        }];
+        assert!(validation::check_config_validation(config).is_ok());
+
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.route_groups = vec![RateLimitRouteGroupConfig {
+            name: "token".to_string(),
+            path_prefix: "api/v1/oauth/token".to_string(),
+            requests_per_minute: 10,
+            burst_size: 5,
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("path_prefix must start with '/'")
+        );
+
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.route_groups = vec![RateLimitRouteGroupConfig {
+            name: "token".to_string(),
+            path_prefix: "/api/v1/oauth/token".to_string(),
+            requests_per_minute: 0,
+            burst_size: 5,
+        }];
+        assert!(validation::check_config_validation(config).is_err());
+
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.route_groups = vec![RateLimitRouteGroupConfig {
+            name: "default".to_string(),
+            path_prefix: "/api/v1/oauth/token".to_string(),
+            requests_per_minute: 10,
+            burst_size: 5,
+        }];
+        let result = validation::check_config_validation(config);
+        assert!(result.unwrap_err().to_string().contains("is reserved"));
+    }
+
+    #[test]
+    fn test_config_validation_rate_limit_backend() {
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.backend = "memcached".to_string();
+        assert!(validation::check_config_validation(config).is_ok());
+
+        let mut config = create_valid_test_config();
+        config.security.rate_limiting.backend = "redis".to_string();
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid rate limiting backend")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_cors_origins() {
+        let mut config = create_valid_test_config();
+        config.security.cors.allowed_origins = vec![
+            "https://app.example.com".to_string(),
+            "https://*.example.com".to_string(),
+            "http://localhost:3000".to_string(),
+        ];
+        config.security.cors.allow_credentials = true;
+        assert!(validation::check_config_validation(config).is_ok());
+
+        for origin in [
+            "https://app.example.com/",
+            "app.example.com",
+            "https://app.*.example.com",
+            "ftp://example.com",
+        ] {
+            let mut config = create_valid_test_config();
+            config.security.cors.allowed_origins = vec![origin.to_string()];
+            let result = validation::check_config_validation(config);
+            assert!(
+                result
+                    .unwrap_err()
+                    .to_string()
+                    .contains("Invalid CORS origin"),
+                "{origin}"
+            );
+        }
+
+        let mut config = create_valid_test_config();
+        config.security.cors.allowed_origins = vec!["*".to_string()];
+        config.security.cors.allow_credentials = true;
+        assert!(validation::check_config_validation(config).is_err());
+    }
+
+    #[test]
+    fn test_config_validation_lockout() {
+        let mut config = create_valid_test_config();
+        config.security.lockout.max_failures = config.security.lockout.free_attempts;
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("must be greater than free_attempts")
+        );
+
+        let mut config = create_valid_test_config();
+        config.security.lockout.enabled = false;
+        config.security.lockout.max_failures = 0;
+        assert!(validation::check_config_validation(config).is_ok());
+    }
+
+    fn create_test_webhook_endpoint(name: &str) -> WebhookEndpointConfig {
+        WebhookEndpointConfig {
+            name: name.to_string(),
+            url: "https://crm.example.com/hooks/allforone".to_string(),
+            secret: "0123456789abcdef0123456789abcdef".to_string(),
+            events: vec!["user.registered".to_string()],
+            realms: vec![],
+        }
+    }
+
+    #[test]
+    fn test_config_validation_webhooks() {
+        let mut config = create_valid_test_config();
+        config.webhooks.endpoints = vec![create_test_webhook_endpoint("crm")];
+        assert!(validation::check_config_validation(config).is_ok());
+
+        let mut config = create_valid_test_config();
+        config.webhooks.endpoints = vec![
+            create_test_webhook_endpoint("crm"),
+            create_test_webhook_endpoint("crm"),
+        ];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Duplicated webhook endpoint name")
+        );
+
+        let mut endpoint = create_test_webhook_endpoint("crm");
+        endpoint.secret = "short".to_string();
+        let mut config = create_valid_test_config();
+        config.webhooks.endpoints = vec![endpoint];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("secret must be at least 32 characters")
+        );
+
+        let mut endpoint = create_test_webhook_endpoint("crm");
+        endpoint.events = vec!["user.updated".to_string()];
+        let mut config = create_valid_test_config();
+        config.webhooks.endpoints = vec![endpoint];
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Invalid webhook event")
+        );
+
+        let mut config = create_valid_test_config();
+        config.webhooks.max_backoff = config.webhooks.initial_backoff - 1;
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("must not be less than initial_backoff")
+        );
+    }
+
+    #[test]
+    fn test_config_validation_metrics() {
+        let mut config = create_valid_test_config();
+        config.metrics.token = Some("0123456789abcdef0123456789abcdef".to_string());
+        assert!(validation::check_config_validation(config).is_ok());
+
+        let mut config = create_valid_test_config();
+        config.metrics.token = Some("short".to_string());
+        let result = validation::check_config_validation(config);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("Metrics token must be at least 32 characters")
+        );
+    }
 }
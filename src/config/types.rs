@@ -1,3 +1,5 @@
+use std::net::IpAddr;
+
 use sonic_rs::Deserialize;
 use uuid::Uuid;
 
@@ -5,11 +7,29 @@ use uuid::Uuid;
 pub struct Config {
     pub server: Server,
     pub logger: LoggerConfig,
-    pub postgres: PostgresConfig,
+    /// postgres, sqlite 중 정확히 하나만 설정
+    #[serde(default)]
+    pub postgres: Option<PostgresConfig>,
+    #[serde(default)]
+    pub sqlite: Option<SqliteConfig>,
     pub memcached: MemCachedConfig,
+    #[serde(default)]
+    pub state_store: StateStoreConfig,
     pub jwks: JwksConfig,
     pub oidc: OIDCProviderConfig,
     pub security: SecurityConfig,
+    #[serde(default)]
+    pub admin: AdminConfig,
+    /// 기본 realm 외에 사용자 풀을 분리할 realm 목록
+    #[serde(default)]
+    pub realms: Vec<RealmConfig>,
+    /// 기본 realm 의 클라이언트
+    #[serde(default)]
+    pub clients: Vec<ClientConfig>,
+    #[serde(default)]
+    pub webhooks: WebhooksConfig,
+    #[serde(default)]
+    pub metrics: MetricsConfig,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -17,6 +37,9 @@ pub struct Server {
     pub domain: String,
     pub port: u16,
     pub user_agent: String,
+    /// 이 주소에서 온 요청만 `X-Forwarded-For` 로 클라이언트 주소를 판단
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
@@ -49,6 +72,21 @@ pub struct PostgresRuntimeConfig {
     pub max_lifetime: u64,
     pub sqlx_logging: bool,
     pub log_level: String,
+    /// 서버 시작 시 대기 중인 마이그레이션 적용
+    #[serde(default)]
+    pub auto_migrate: bool,
+}
+
+/// 개발용, 단일 노드용 SQLite 백엔드
+#[derive(Deserialize, Debug)]
+pub struct SqliteConfig {
+    /// 데이터베이스 파일 경로. `:memory:` 이면 인메모리
+    pub path: String,
+    #[serde(default)]
+    pub sqlx_logging: bool,
+    /// 서버 시작 시 대기 중인 마이그레이션 적용
+    #[serde(default)]
+    pub auto_migrate: bool,
 }
 
 #[derive(Deserialize, Debug)]
@@ -96,6 +134,9 @@ pub struct GithubConfig {
     pub resource_url: String,
     pub auth_url: String,
     pub token_url: String,
+    /// provider 가 email 소유를 검증한다고 신뢰할지 여부 (계정 자동 연결에 사용)
+    #[serde(default)]
+    pub email_verified: bool,
 }
 
 #[derive(Deserialize, Debug)]
@@ -105,6 +146,10 @@ pub struct SecurityConfig {
     pub rate_limiting: RateLimitingConfig,
     pub cors: CorsConfig,
     pub security_headers: SecurityHeadersConfig,
+    #[serde(default)]
+    pub account_linking: AccountLinkingSecurityConfig,
+    #[serde(default)]
+    pub lockout: LockoutSecurityConfig,
 }
 
 #[derive(Deserialize, Debug)]
@@ -122,6 +167,13 @@ pub struct SessionSecurityConfig {
     pub secure_cookies: bool,
     pub same_site: String,
     pub http_only: bool,
+    /// 로그인한 브라우저를 기억하는 SSO 세션 수명
+    #[serde(default = "default_sso_ttl")]
+    pub sso_ttl: u64,
+}
+
+fn default_sso_ttl() -> u64 {
+    28800
 }
 
 #[derive(Deserialize, Debug)]
@@ -130,15 +182,41 @@ pub struct RateLimitingConfig {
     pub requests_per_minute: u32,
     pub burst_size: u32,
     pub cleanup_interval: u64,
+    /// local 은 프로세스마다 따로 세고, memcached 는 replica 끼리 `burst_size` 크기의 window 카운터를 공유
+    #[serde(default = "default_rate_limiting_backend")]
+    pub backend: String,
+    /// 경로별로 따로 세는 한도. 맞는 그룹이 없으면 위의 기본 한도
+    #[serde(default)]
+    pub route_groups: Vec<RateLimitRouteGroupConfig>,
+}
+
+fn default_rate_limiting_backend() -> String {
+    "local".to_string()
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct RateLimitRouteGroupConfig {
+    pub name: String,
+    /// realm 경로(`/realms/{name}`)를 뗀 경로의 prefix. 예: `/api/v1/oauth/token`
+    pub path_prefix: String,
+    pub requests_per_minute: u32,
+    pub burst_size: u32,
 }
 
 #[derive(Deserialize, Debug)]
 pub struct CorsConfig {
     pub enabled: bool,
+    /// 정확한 origin, `https://*.example.com` 같은 서브도메인 wildcard, 또는 모든 origin 인 `*`
     pub allowed_origins: Vec<String>,
     pub allowed_methods: Vec<String>,
     pub allowed_headers: Vec<String>,
     pub max_age: u64,
+    /// 쿠키를 실은 요청 허용. `*` origin 과 같이 쓸 수 없음
+    #[serde(default)]
+    pub allow_credentials: bool,
+    /// CORS 를 허용하지 않을 경로 prefix(realm 경로 제외). `/api/admin` 은 항상 제외됨
+    #[serde(default)]
+    pub excluded_paths: Vec<String>,
 }
 
 #[derive(Deserialize, Debug)]
@@ -150,3 +228,178 @@ pub struct SecurityHeadersConfig {
     pub x_frame_options: String,
     pub x_content_type_options: bool,
 }
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct AccountLinkingSecurityConfig {
+    pub policy: String,
+}
+
+impl Config {
+    pub fn auto_migrate(&self) -> bool {
+        match (&self.postgres, &self.sqlite) {
+            (Some(postgres), _) => postgres.runtime_options.auto_migrate,
+            (None, Some(sqlite)) => sqlite.auto_migrate,
+            (None, None) => false,
+        }
+    }
+}
+
+/// 토큰 엔드포인트의 인증 실패를 계정/클라이언트별로 세서 점점 늦추다가 잠금
+#[derive(Deserialize, Debug, Clone)]
+pub struct LockoutSecurityConfig {
+    pub enabled: bool,
+    /// 이 횟수까지는 지연 없이 다시 시도할 수 있음
+    pub free_attempts: u32,
+    /// 이 횟수에 닿으면 `lockout_duration` 동안 잠금
+    pub max_failures: u32,
+    /// 실패할 때마다 1, 2, 4 ... 초로 늘어나는 지연의 상한(초)
+    pub max_delay: u64,
+    pub lockout_duration: u64,
+    /// 첫 실패부터 이 시간(초) 동안 실패를 세고 지나면 잊음
+    pub failure_window: u64,
+}
+
+impl Default for LockoutSecurityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            free_attempts: 3,
+            max_failures: 10,
+            max_delay: 60,
+            lockout_duration: 900,
+            failure_window: 900,
+        }
+    }
+}
+
+impl Default for AccountLinkingSecurityConfig {
+    fn default() -> Self {
+        Self {
+            policy: "disabled".to_string(),
+        }
+    }
+}
+
+/// 로그인 흐름의 임시 상태를 보관할 저장소
+#[derive(Deserialize, Debug)]
+pub struct StateStoreConfig {
+    /// memcached, redis, database, memory 중 하나
+    pub backend: String,
+    /// backend 가 redis 일 때 접속 주소
+    pub redis_url: Option<String>,
+}
+
+impl Default for StateStoreConfig {
+    fn default() -> Self {
+        Self {
+            backend: "memcached".to_string(),
+            redis_url: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+pub struct AdminConfig {
+    /// `/api/admin` 에 쓸 수 있는 고정 bearer credential
+    pub token: Option<String>,
+    /// 발급되는 access token 에 `admin` scope 를 받는 사용자
+    #[serde(default)]
+    pub user_ids: Vec<Uuid>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct RealmConfig {
+    /// `/realms/{name}/api` 경로로 라우팅
+    pub name: String,
+    /// 이 host 로 들어온 요청은 `/api` 경로로 바로 이 realm 에 라우팅
+    #[serde(default)]
+    pub hosts: Vec<String>,
+    pub jwks: JwksConfig,
+    pub oidc: OIDCProviderConfig,
+    #[serde(default)]
+    pub clients: Vec<ClientConfig>,
+}
+
+/// `/oauth/authorize` 로 로그인을 요청할 수 있는 클라이언트
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClientConfig {
+    pub client_id: String,
+    pub redirect_uris: Vec<String>,
+    /// 로그아웃 후 돌아갈 수 있는 주소
+    #[serde(default)]
+    pub post_logout_redirect_uris: Vec<String>,
+    /// 로그아웃 토큰을 POST 로 받을 주소 (OIDC back-channel logout)
+    pub backchannel_logout_uri: Option<String>,
+    /// 로그아웃 페이지에서 iframe 으로 열 주소 (OIDC front-channel logout)
+    pub frontchannel_logout_uri: Option<String>,
+    /// 토큰 introspection 을 호출할 때 HTTP Basic 으로 보내는 secret. 32자 이상
+    pub client_secret: Option<String>,
+}
+
+/// 사용자 lifecycle 이벤트를 HMAC 서명해서 보낼 webhook
+#[derive(Deserialize, Debug, Clone)]
+pub struct WebhooksConfig {
+    #[serde(default)]
+    pub endpoints: Vec<WebhookEndpointConfig>,
+    /// 이 횟수만큼 실패하면 더 보내지 않음
+    pub max_attempts: u32,
+    /// 첫 재시도까지 기다리는 시간(초). 실패할 때마다 두 배로 늘어남
+    pub initial_backoff: u64,
+    pub max_backoff: u64,
+    /// 보낼 이벤트를 찾는 주기(초)
+    pub poll_interval: u64,
+    /// 요청 하나의 timeout(초)
+    pub timeout: u64,
+    /// 이 시간(초)이 지난 이벤트와 전송 기록은 지움
+    pub retention: u64,
+}
+
+impl Default for WebhooksConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: Vec::new(),
+            max_attempts: 10,
+            initial_backoff: 30,
+            max_backoff: 3600,
+            poll_interval: 5,
+            timeout: 10,
+            retention: 2592000,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct WebhookEndpointConfig {
+    /// 전송 기록에 남는 이름
+    pub name: String,
+    pub url: String,
+    /// `X-Webhook-Signature` 의 HMAC-SHA256 키
+    pub secret: String,
+    /// 받을 이벤트. 비어 있으면 모든 이벤트
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// 받을 realm. 비어 있으면 모든 realm
+    #[serde(default)]
+    pub realms: Vec<String>,
+}
+
+/// `/metrics` 로 내보내는 Prometheus 지표
+#[derive(Deserialize, Debug)]
+pub struct MetricsConfig {
+    pub enabled: bool,
+    /// `Authorization: Bearer {token}` 으로만 볼 수 있음
+    pub token: Option<String>,
+    /// token 없이 누구나 볼 수 있게 함. 포트가 내부망에만 열려 있을 때만 켬
+    #[serde(default)]
+    pub public: bool,
+}
+
+impl Default for MetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            token: None,
+            public: false,
+        }
+    }
+}
@@ -1,17 +1,29 @@
-use anyhow::{Result, anyhow};
-use std::path::Path;
+use anyhow::{Context, Result, anyhow};
+use axum::http::HeaderName;
+use std::{collections::HashSet, path::Path};
 use url::Url;
 
-use super::types::Config;
+use super::types::{
+    ClientConfig, Config, PostgresConfig, SqliteConfig, StateStoreConfig, WebhooksConfig,
+};
+use crate::api::types::{
+    rate_limit::DEFAULT_ROUTE_GROUP, realm::DEFAULT_REALM, webhook::WebhookEventType,
+};
 
 pub fn check_config_validation(config: Config) -> Result<Config> {
     validate_server(&config)?;
     validate_logger(&config)?;
-    validate_postgres(&config)?;
+    validate_database(&config)?;
     validate_memcached(&config)?;
-    validate_jwks(&config)?;
-    validate_github_config(&config)?;
+    validate_state_store(&config.state_store)?;
+    validate_jwks(&config.jwks)?;
+    validate_github_config(&config.oidc.github)?;
     validate_security(&config)?;
+    validate_admin(&config)?;
+    validate_realms(&config)?;
+    validate_clients(&config.clients)?;
+    validate_webhooks(&config.webhooks)?;
+    validate_metrics(&config)?;
 
     Ok(config)
 }
@@ -47,9 +59,26 @@ fn validate_logger(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn validate_postgres(config: &Config) -> Result<()> {
-    let pg = &config.postgres;
+fn validate_database(config: &Config) -> Result<()> {
+    match (&config.postgres, &config.sqlite) {
+        (Some(postgres), None) => validate_postgres(postgres),
+        (None, Some(sqlite)) => validate_sqlite(sqlite),
+        (Some(_), Some(_)) => Err(anyhow!(
+            "Only one of [postgres] and [sqlite] can be configured"
+        )),
+        (None, None) => Err(anyhow!("Either [postgres] or [sqlite] must be configured")),
+    }
+}
+
+fn validate_sqlite(sqlite: &SqliteConfig) -> Result<()> {
+    if sqlite.path.trim().is_empty() {
+        return Err(anyhow!("Sqlite path cannot be empty"));
+    }
 
+    Ok(())
+}
+
+fn validate_postgres(pg: &PostgresConfig) -> Result<()> {
     // Validate connection info
     if pg.connect_info.address.trim().is_empty() {
         return Err(anyhow!("Postgres address cannot be empty"));
@@ -117,9 +146,7 @@ fn validate_memcached(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn validate_jwks(config: &Config) -> Result<()> {
-    let jwks = &config.jwks;
-
+fn validate_jwks(jwks: &super::types::JwksConfig) -> Result<()> {
     // Validate issuer URL
     Url::parse(&jwks.iss).map_err(|_| anyhow!("Invalid JWKS issuer URL: {}", jwks.iss))?;
 
@@ -149,9 +176,7 @@ fn validate_jwks(config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn validate_github_config(config: &Config) -> Result<()> {
-    let github = &config.oidc.github;
-
+fn validate_github_config(github: &super::types::GithubConfig) -> Result<()> {
     // Validate GitHub OIDC configuration
     if github.client_id.trim().is_empty() {
         return Err(anyhow!("GitHub client_id cannot be empty"));
@@ -182,6 +207,8 @@ fn validate_security(config: &Config) -> Result<()> {
     validate_rate_limiting(&security.rate_limiting)?;
     validate_cors(&security.cors)?;
     validate_security_headers(&security.security_headers)?;
+    validate_account_linking(&security.account_linking)?;
+    validate_lockout(&security.lockout)?;
 
     Ok(())
 }
@@ -231,6 +258,10 @@ fn validate_session_security(session: &super::types::SessionSecurityConfig) -> R
         return Err(anyhow!("Session cache_ttl must be greater than 0"));
     }
 
+    if session.sso_ttl == 0 {
+        return Err(anyhow!("Session sso_ttl must be greater than 0"));
+    }
+
     // Validate same_site values
     let valid_same_site = ["Strict", "Lax", "None"];
     if !valid_same_site.contains(&session.same_site.as_str()) {
@@ -263,6 +294,51 @@ fn validate_rate_limiting(rate_limit: &super::types::RateLimitingConfig) -> Resu
                 "Rate limiting cleanup_interval must be greater than 0 when enabled"
             ));
         }
+
+        let valid_backends = ["local", "memcached"];
+        if !valid_backends.contains(&rate_limit.backend.as_str()) {
+            return Err(anyhow!(
+                "Invalid rate limiting backend: {}. Must be one of: {}",
+                rate_limit.backend,
+                valid_backends.join(", ")
+            ));
+        }
+
+        let mut group_names = HashSet::new();
+        for group in &rate_limit.route_groups {
+            if group.name.trim().is_empty() {
+                return Err(anyhow!("Rate limiting route group name cannot be empty"));
+            }
+
+            // 어느 그룹에도 맞지 않는 경로의 공유 카운터와 키가 겹침
+            if group.name == DEFAULT_ROUTE_GROUP {
+                return Err(anyhow!(
+                    "Rate limiting route group name {} is reserved",
+                    DEFAULT_ROUTE_GROUP
+                ));
+            }
+
+            if !group_names.insert(group.name.as_str()) {
+                return Err(anyhow!(
+                    "Duplicated rate limiting route group: {}",
+                    group.name
+                ));
+            }
+
+            if !group.path_prefix.starts_with('/') {
+                return Err(anyhow!(
+                    "Rate limiting route group {} path_prefix must start with '/'",
+                    group.name
+                ));
+            }
+
+            if group.requests_per_minute == 0 || group.burst_size == 0 {
+                return Err(anyhow!(
+                    "Rate limiting route group {} requests_per_minute and burst_size must be greater than 0",
+                    group.name
+                ));
+            }
+        }
     }
 
     Ok(())
@@ -299,11 +375,51 @@ fn validate_cors(cors: &super::types::CorsConfig) -> Result<()> {
                 ));
             }
         }
+
+        for header in &cors.allowed_headers {
+            if HeaderName::from_bytes(header.as_bytes()).is_err() {
+                return Err(anyhow!("Invalid CORS header: {}", header));
+            }
+        }
+
+        for origin in &cors.allowed_origins {
+            if origin != "*" && !is_valid_origin_pattern(origin) {
+                return Err(anyhow!("Invalid CORS origin: {}", origin));
+            }
+        }
+
+        if cors.allow_credentials && cors.allowed_origins.iter().any(|origin| origin == "*") {
+            return Err(anyhow!(
+                "CORS allow_credentials cannot be used with '*' origin"
+            ));
+        }
+
+        for path in &cors.excluded_paths {
+            if !path.starts_with('/') {
+                return Err(anyhow!("CORS excluded path must start with '/': {}", path));
+            }
+        }
     }
 
     Ok(())
 }
 
+/// 경로 없는 `scheme://host[:port]`. host 맨 앞의 `*.` 만 wildcard 로 허용
+fn is_valid_origin_pattern(origin: &str) -> bool {
+    let Some((scheme, host)) = origin.split_once("://") else {
+        return false;
+    };
+    if scheme != "http" && scheme != "https" {
+        return false;
+    }
+    let host = host.strip_prefix("*.").unwrap_or(host);
+    if host.is_empty() || host.contains(['*', '/', '?', '#', '@']) {
+        return false;
+    }
+
+    Url::parse(&format!("{scheme}://{host}")).is_ok_and(|url| url.host_str().is_some())
+}
+
 fn validate_security_headers(headers: &super::types::SecurityHeadersConfig) -> Result<()> {
     if headers.hsts_enabled && headers.hsts_max_age == 0 {
         return Err(anyhow!(
@@ -327,3 +443,252 @@ fn validate_security_headers(headers: &super::types::SecurityHeadersConfig) -> R
 
     Ok(())
 }
+
+fn validate_lockout(lockout: &super::types::LockoutSecurityConfig) -> Result<()> {
+    if lockout.enabled {
+        if lockout.max_failures <= lockout.free_attempts {
+            return Err(anyhow!(
+                "Lockout max_failures ({}) must be greater than free_attempts ({})",
+                lockout.max_failures,
+                lockout.free_attempts
+            ));
+        }
+
+        if lockout.max_delay == 0 || lockout.lockout_duration == 0 || lockout.failure_window == 0 {
+            return Err(anyhow!(
+                "Lockout max_delay, lockout_duration and failure_window must be greater than 0"
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_account_linking(linking: &super::types::AccountLinkingSecurityConfig) -> Result<()> {
+    let valid_policies = ["disabled", "auto", "prompt"];
+    if !valid_policies.contains(&linking.policy.as_str()) {
+        return Err(anyhow!(
+            "Invalid account linking policy: {}. Must be one of: {}",
+            linking.policy,
+            valid_policies.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+fn validate_state_store(state_store: &StateStoreConfig) -> Result<()> {
+    let valid_backends = ["memcached", "redis", "database", "memory"];
+    if !valid_backends.contains(&state_store.backend.as_str()) {
+        return Err(anyhow!(
+            "Invalid state store backend: {}. Must be one of: {}",
+            state_store.backend,
+            valid_backends.join(", ")
+        ));
+    }
+
+    if state_store.backend == "redis" {
+        let redis_url = state_store
+            .redis_url
+            .as_deref()
+            .ok_or_else(|| anyhow!("State store redis_url is required for redis backend"))?;
+        let url = Url::parse(redis_url).map_err(|_| anyhow!("Invalid redis url: {}", redis_url))?;
+        if !matches!(url.scheme(), "redis" | "rediss") {
+            return Err(anyhow!("Invalid redis url: {}", redis_url));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_admin(config: &Config) -> Result<()> {
+    if let Some(token) = &config.admin.token
+        && token.trim().len() < 32
+    {
+        return Err(anyhow!("Admin token must be at least 32 characters"));
+    }
+
+    Ok(())
+}
+
+/// realm 이름은 경로에 쓰이므로 영문 소문자, 숫자, `-` 만 허용. issuer 와 host 는 realm 끼리 겹칠 수 없음
+fn validate_realms(config: &Config) -> Result<()> {
+    let mut names = HashSet::new();
+    let mut issuers = HashSet::from([config.jwks.iss.as_str()]);
+    let mut hosts = HashSet::new();
+
+    for realm in &config.realms {
+        if realm.name.is_empty()
+            || !realm
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(anyhow!("Invalid realm name: {}", realm.name));
+        }
+
+        if realm.name == DEFAULT_REALM || !names.insert(&realm.name) {
+            return Err(anyhow!("Duplicated realm name: {}", realm.name));
+        }
+
+        if !issuers.insert(realm.jwks.iss.as_str()) {
+            return Err(anyhow!(
+                "Realm {} issuer is already used: {}",
+                realm.name,
+                realm.jwks.iss
+            ));
+        }
+
+        for host in &realm.hosts {
+            if !hosts.insert(host.to_lowercase()) {
+                return Err(anyhow!("Realm host is already used: {}", host));
+            }
+        }
+
+        validate_jwks(&realm.jwks).with_context(|| format!("invalid realm {}", realm.name))?;
+        validate_github_config(&realm.oidc.github)
+            .with_context(|| format!("invalid realm {}", realm.name))?;
+        validate_clients(&realm.clients)
+            .with_context(|| format!("invalid realm {}", realm.name))?;
+    }
+
+    Ok(())
+}
+
+fn validate_clients(clients: &[ClientConfig]) -> Result<()> {
+    let mut client_ids = HashSet::new();
+    for client in clients {
+        if client.client_id.trim().is_empty() {
+            return Err(anyhow!("Client client_id cannot be empty"));
+        }
+
+        if !client_ids.insert(client.client_id.as_str()) {
+            return Err(anyhow!("Duplicated client_id: {}", client.client_id));
+        }
+
+        if client.redirect_uris.is_empty() {
+            return Err(anyhow!(
+                "Client {} must have at least one redirect_uri",
+                client.client_id
+            ));
+        }
+
+        if let Some(client_secret) = &client.client_secret
+            && client_secret.trim().len() < 32
+        {
+            return Err(anyhow!(
+                "Client {} client_secret must be at least 32 characters",
+                client.client_id
+            ));
+        }
+
+        // redirect_uri 는 정확히 일치해야 하므로 fragment 는 허용하지 않음
+        for redirect_uri in &client.redirect_uris {
+            let url = Url::parse(redirect_uri)
+                .map_err(|_| anyhow!("Invalid client redirect_uri: {}", redirect_uri))?;
+            if url.fragment().is_some() {
+                return Err(anyhow!("Invalid client redirect_uri: {}", redirect_uri));
+            }
+        }
+
+        for redirect_uri in &client.post_logout_redirect_uris {
+            let url = Url::parse(redirect_uri).map_err(|_| {
+                anyhow!("Invalid client post_logout_redirect_uri: {}", redirect_uri)
+            })?;
+            if url.fragment().is_some() {
+                return Err(anyhow!(
+                    "Invalid client post_logout_redirect_uri: {}",
+                    redirect_uri
+                ));
+            }
+        }
+
+        for logout_uri in client
+            .backchannel_logout_uri
+            .iter()
+            .chain(client.frontchannel_logout_uri.iter())
+        {
+            let url = Url::parse(logout_uri)
+                .map_err(|_| anyhow!("Invalid client logout uri: {}", logout_uri))?;
+            if url.fragment().is_some() {
+                return Err(anyhow!("Invalid client logout uri: {}", logout_uri));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_metrics(config: &Config) -> Result<()> {
+    if let Some(token) = &config.metrics.token
+        && token.trim().len() < 32
+    {
+        return Err(anyhow!("Metrics token must be at least 32 characters"));
+    }
+
+    Ok(())
+}
+
+fn validate_webhooks(webhooks: &WebhooksConfig) -> Result<()> {
+    if webhooks.max_attempts == 0
+        || webhooks.initial_backoff == 0
+        || webhooks.poll_interval == 0
+        || webhooks.timeout == 0
+        || webhooks.retention == 0
+    {
+        return Err(anyhow!(
+            "Webhook max_attempts, initial_backoff, poll_interval, timeout and retention must be greater than 0"
+        ));
+    }
+
+    if webhooks.max_backoff < webhooks.initial_backoff {
+        return Err(anyhow!(
+            "Webhook max_backoff ({}) must not be less than initial_backoff ({})",
+            webhooks.max_backoff,
+            webhooks.initial_backoff
+        ));
+    }
+
+    let mut names = HashSet::new();
+    for endpoint in &webhooks.endpoints {
+        if endpoint.name.trim().is_empty() {
+            return Err(anyhow!("Webhook endpoint name cannot be empty"));
+        }
+
+        if !names.insert(endpoint.name.as_str()) {
+            return Err(anyhow!(
+                "Duplicated webhook endpoint name: {}",
+                endpoint.name
+            ));
+        }
+
+        let url = Url::parse(&endpoint.url)
+            .map_err(|_| anyhow!("Invalid webhook endpoint url: {}", endpoint.url))?;
+        if !matches!(url.scheme(), "http" | "https") {
+            return Err(anyhow!("Invalid webhook endpoint url: {}", endpoint.url));
+        }
+
+        if endpoint.secret.trim().len() < 32 {
+            return Err(anyhow!(
+                "Webhook endpoint {} secret must be at least 32 characters",
+                endpoint.name
+            ));
+        }
+
+        for event in &endpoint.events {
+            if WebhookEventType::parse(event).is_none() {
+                return Err(anyhow!(
+                    "Invalid webhook event: {}. Must be one of: {}",
+                    event,
+                    WebhookEventType::ALL
+                        .iter()
+                        .map(WebhookEventType::as_str)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -1,12 +1,21 @@
 use std::time::Duration;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, anyhow};
 use sea_orm::DatabaseConnection;
 
-use crate::config::types::Config;
+use crate::config::types::{Config, PostgresConfig, SqliteConfig};
 
-pub async fn postgres_connect(config: &Config) -> Result<DatabaseConnection> {
-    let connect_info = &config.postgres.connect_info;
+/// 설정된 백엔드(postgres, sqlite)로 연결
+pub async fn database_connect(config: &Config) -> Result<DatabaseConnection> {
+    match (&config.postgres, &config.sqlite) {
+        (Some(postgres), _) => postgres_connect(postgres).await,
+        (None, Some(sqlite)) => sqlite_connect(sqlite).await,
+        (None, None) => Err(anyhow!("database is not configured")),
+    }
+}
+
+pub async fn postgres_connect(postgres: &PostgresConfig) -> Result<DatabaseConnection> {
+    let connect_info = &postgres.connect_info;
     let database_url = url::Url::parse(
         format!(
             "postgres://{}:{}@{}:{}/{}",
@@ -19,7 +28,7 @@ pub async fn postgres_connect(config: &Config) -> Result<DatabaseConnection> {
         .as_str(),
     )?;
 
-    let runtime_options = &config.postgres.runtime_options;
+    let runtime_options = &postgres.runtime_options;
     let mut opt = sea_orm::ConnectOptions::new(database_url.to_string());
     opt.min_connections(runtime_options.min_pool_size)
         .connect_timeout(Duration::from_secs(runtime_options.connect_timeout))
@@ -34,3 +43,39 @@ pub async fn postgres_connect(config: &Config) -> Result<DatabaseConnection> {
 
     Ok(db)
 }
+
+/// SQLite 는 단일 커넥션으로 연결. 인메모리 DB 는 커넥션이 닫히면 사라지므로 하나를 계속 유지
+pub async fn sqlite_connect(sqlite: &SqliteConfig) -> Result<DatabaseConnection> {
+    let database_url = if sqlite.path == ":memory:" {
+        "sqlite::memory:".to_string()
+    } else {
+        format!("sqlite://{}?mode=rwc", sqlite.path)
+    };
+
+    let mut opt = sea_orm::ConnectOptions::new(database_url);
+    opt.max_connections(1)
+        .min_connections(1)
+        .sqlx_logging(sqlite.sqlx_logging);
+
+    let db = sea_orm::Database::connect(opt)
+        .await
+        .context("fail to database connection")?;
+
+    Ok(db)
+}
+
+/// 마이그레이션까지 적용된 인메모리 SQLite. 외부 서비스 없이 돌리는 테스트용
+#[cfg(test)]
+pub async fn memory_connect() -> DatabaseConnection {
+    use sea_orm_migration::MigratorTrait;
+
+    let db = sqlite_connect(&SqliteConfig {
+        path: ":memory:".to_string(),
+        sqlx_logging: false,
+        auto_migrate: true,
+    })
+    .await
+    .unwrap();
+    crate::db::migration::Migrator::up(&db, None).await.unwrap();
+    db
+}
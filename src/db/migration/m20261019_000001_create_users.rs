@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 마이그레이션이 생기기 전부터 손으로 만들어 쓰던 `users` 테이블.
+/// 이미 있는 배포에서는 건너뛰고, 이후 마이그레이션이 지금 모양으로 바꿈
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Users::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Users::Id))
+                    .col(string_null(Users::Username))
+                    .col(string_null(Users::Email))
+                    .col(boolean(Users::IsActive).default(true))
+                    .col(timestamp_with_time_zone(Users::CreatedAt))
+                    .col(timestamp_with_time_zone(Users::UpdatedAt))
+                    .col(string(Users::Idp))
+                    .col(string(Users::IdpUid))
+                    .to_owned(),
+            )
+            .await?;
+
+        // SQLite 는 컬럼의 UNIQUE 제약을 지울 수 없어서 이름 있는 인덱스로 만듦
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_username")
+                    .table(Users::Table)
+                    .col(Users::Username)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_email")
+                    .table(Users::Table)
+                    .col(Users::Email)
+                    .unique()
+                    .if_not_exists()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Users::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum Users {
+    Table,
+    Id,
+    Username,
+    Email,
+    IsActive,
+    CreatedAt,
+    UpdatedAt,
+    Idp,
+    IdpUid,
+}
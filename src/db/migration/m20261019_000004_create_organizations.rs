@@ -0,0 +1,244 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20261019_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Organizations::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Organizations::Id))
+                    .col(string(Organizations::Realm))
+                    .col(string(Organizations::Name))
+                    .col(string(Organizations::Slug))
+                    .col(timestamp_with_time_zone(Organizations::CreatedAt))
+                    .col(timestamp_with_time_zone(Organizations::UpdatedAt))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_organizations_realm_slug")
+                    .table(Organizations::Table)
+                    .col(Organizations::Realm)
+                    .col(Organizations::Slug)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationMembers::Table)
+                    .if_not_exists()
+                    .col(uuid(OrganizationMembers::OrganizationId))
+                    .col(uuid(OrganizationMembers::UserId))
+                    .col(string(OrganizationMembers::Role))
+                    .col(timestamp_with_time_zone(OrganizationMembers::CreatedAt))
+                    .col(timestamp_with_time_zone(OrganizationMembers::UpdatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(OrganizationMembers::OrganizationId)
+                            .col(OrganizationMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_members_organization_id")
+                            .from(
+                                OrganizationMembers::Table,
+                                OrganizationMembers::OrganizationId,
+                            )
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_members_user_id")
+                            .from(OrganizationMembers::Table, OrganizationMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(OrganizationInvitations::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(OrganizationInvitations::Id))
+                    .col(uuid(OrganizationInvitations::OrganizationId))
+                    .col(string(OrganizationInvitations::Email))
+                    .col(string(OrganizationInvitations::Role))
+                    .col(string_uniq(OrganizationInvitations::TokenHash))
+                    .col(uuid(OrganizationInvitations::InvitedBy))
+                    .col(timestamp_with_time_zone(OrganizationInvitations::ExpiresAt))
+                    .col(timestamp_with_time_zone_null(
+                        OrganizationInvitations::AcceptedAt,
+                    ))
+                    .col(timestamp_with_time_zone(OrganizationInvitations::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_invitations_organization_id")
+                            .from(
+                                OrganizationInvitations::Table,
+                                OrganizationInvitations::OrganizationId,
+                            )
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_organization_invitations_invited_by")
+                            .from(
+                                OrganizationInvitations::Table,
+                                OrganizationInvitations::InvitedBy,
+                            )
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(Groups::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(Groups::Id))
+                    .col(uuid(Groups::OrganizationId))
+                    .col(string(Groups::Name))
+                    .col(text_null(Groups::Description))
+                    .col(timestamp_with_time_zone(Groups::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_groups_organization_id")
+                            .from(Groups::Table, Groups::OrganizationId)
+                            .to(Organizations::Table, Organizations::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_groups_organization_id_name")
+                    .table(Groups::Table)
+                    .col(Groups::OrganizationId)
+                    .col(Groups::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(GroupMembers::Table)
+                    .if_not_exists()
+                    .col(uuid(GroupMembers::GroupId))
+                    .col(uuid(GroupMembers::UserId))
+                    .col(timestamp_with_time_zone(GroupMembers::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(GroupMembers::GroupId)
+                            .col(GroupMembers::UserId),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_group_id")
+                            .from(GroupMembers::Table, GroupMembers::GroupId)
+                            .to(Groups::Table, Groups::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_group_members_user_id")
+                            .from(GroupMembers::Table, GroupMembers::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in [
+            GroupMembers::Table.into_iden(),
+            Groups::Table.into_iden(),
+            OrganizationInvitations::Table.into_iden(),
+            OrganizationMembers::Table.into_iden(),
+            Organizations::Table.into_iden(),
+        ] {
+            manager
+                .drop_table(Table::drop().table(table).to_owned())
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Organizations {
+    Table,
+    Id,
+    Realm,
+    Name,
+    Slug,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationMembers {
+    Table,
+    OrganizationId,
+    UserId,
+    Role,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum OrganizationInvitations {
+    Table,
+    Id,
+    OrganizationId,
+    Email,
+    Role,
+    TokenHash,
+    InvitedBy,
+    ExpiresAt,
+    AcceptedAt,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Groups {
+    Table,
+    Id,
+    OrganizationId,
+    Name,
+    Description,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum GroupMembers {
+    Table,
+    GroupId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(StateEntries::Table)
+                    .if_not_exists()
+                    .col(string(StateEntries::Key).primary_key())
+                    .col(text(StateEntries::Value))
+                    .col(timestamp_with_time_zone(StateEntries::ExpiresAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        // 만료 항목 정리용
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_state_entries_expires_at")
+                    .table(StateEntries::Table)
+                    .col(StateEntries::ExpiresAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(StateEntries::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum StateEntries {
+    Table,
+    Key,
+    Value,
+    ExpiresAt,
+}
@@ -0,0 +1,71 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자가 지워져도 기록은 남아야 하므로 users 에 외래 키를 걸지 않음
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuditEvents::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(AuditEvents::Id))
+                    .col(string(AuditEvents::Realm))
+                    .col(string(AuditEvents::EventType))
+                    .col(string(AuditEvents::Outcome))
+                    .col(uuid_null(AuditEvents::ActorId))
+                    .col(string_null(AuditEvents::ClientId))
+                    .col(string_null(AuditEvents::IpAddress))
+                    .col(text_null(AuditEvents::UserAgent))
+                    .col(text_null(AuditEvents::Reason))
+                    .col(timestamp_with_time_zone(AuditEvents::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_events_realm_created_at")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::Realm)
+                    .col(AuditEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_events_actor_id")
+                    .table(AuditEvents::Table)
+                    .col(AuditEvents::ActorId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuditEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuditEvents {
+    Table,
+    Id,
+    Realm,
+    EventType,
+    Outcome,
+    ActorId,
+    ClientId,
+    IpAddress,
+    UserAgent,
+    Reason,
+    CreatedAt,
+}
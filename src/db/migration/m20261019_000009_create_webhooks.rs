@@ -0,0 +1,129 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 사용자 변경과 같은 트랜잭션에서 남기는 outbox. 지워진 사용자의 이벤트도 보내야 하므로 외래 키 없음
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookEvents::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(WebhookEvents::Id))
+                    .col(string(WebhookEvents::Realm))
+                    .col(string(WebhookEvents::EventType))
+                    .col(uuid(WebhookEvents::UserId))
+                    .col(text(WebhookEvents::Payload))
+                    .col(timestamp_with_time_zone(WebhookEvents::CreatedAt))
+                    .col(timestamp_with_time_zone_null(WebhookEvents::DispatchedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_events_dispatched_at")
+                    .table(WebhookEvents::Table)
+                    .col(WebhookEvents::DispatchedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        // endpoint 별 전송 상태. 재시도 큐이면서 전송 기록
+        manager
+            .create_table(
+                Table::create()
+                    .table(WebhookDeliveries::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(WebhookDeliveries::Id))
+                    .col(uuid(WebhookDeliveries::EventId))
+                    .col(string(WebhookDeliveries::Realm))
+                    .col(string(WebhookDeliveries::EventType))
+                    .col(string(WebhookDeliveries::Endpoint))
+                    .col(string(WebhookDeliveries::Status))
+                    .col(integer(WebhookDeliveries::Attempts))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::NextAttemptAt))
+                    .col(integer_null(WebhookDeliveries::LastStatusCode))
+                    .col(text_null(WebhookDeliveries::LastError))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::CreatedAt))
+                    .col(timestamp_with_time_zone(WebhookDeliveries::UpdatedAt))
+                    .col(timestamp_with_time_zone_null(
+                        WebhookDeliveries::DeliveredAt,
+                    ))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_event_id")
+                            .from(WebhookDeliveries::Table, WebhookDeliveries::EventId)
+                            .to(WebhookEvents::Table, WebhookEvents::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_status_next_attempt_at")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Status)
+                    .col(WebhookDeliveries::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_realm_created_at")
+                    .table(WebhookDeliveries::Table)
+                    .col(WebhookDeliveries::Realm)
+                    .col(WebhookDeliveries::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(WebhookDeliveries::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(WebhookEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum WebhookEvents {
+    Table,
+    Id,
+    Realm,
+    EventType,
+    UserId,
+    Payload,
+    CreatedAt,
+    DispatchedAt,
+}
+
+#[derive(DeriveIden)]
+enum WebhookDeliveries {
+    Table,
+    Id,
+    EventId,
+    Realm,
+    EventType,
+    Endpoint,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastStatusCode,
+    LastError,
+    CreatedAt,
+    UpdatedAt,
+    DeliveredAt,
+}
@@ -0,0 +1,134 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// realm, 프로필, 정지 컬럼을 더하고 username/email 의 유일성을 realm 단위로 바꿈
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 기존 사용자는 모두 기본 realm 소속
+        let columns = [
+            string(Users::Realm).default("default").to_owned(),
+            string_null(Users::DisplayName),
+            string_null(Users::AvatarUrl),
+            timestamp_with_time_zone_null(Users::SuspendedAt),
+            timestamp_with_time_zone_null(Users::SuspendedUntil),
+            text_null(Users::SuspensionReason),
+        ];
+        // SQLite 는 ALTER TABLE 하나에 변경 하나만 받음
+        for column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .add_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        for name in ["uq_users_username", "uq_users_email"] {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(name)
+                        .table(Users::Table)
+                        .if_exists()
+                        .to_owned(),
+                )
+                .await?;
+        }
+        // 손으로 만든 테이블은 컬럼의 UNIQUE 제약으로 유일성을 걸어둠
+        if manager.get_database_backend() == DbBackend::Postgres {
+            let db = manager.get_connection();
+            db.execute_unprepared("ALTER TABLE users DROP CONSTRAINT IF EXISTS users_username_key")
+                .await?;
+            db.execute_unprepared("ALTER TABLE users DROP CONSTRAINT IF EXISTS users_email_key")
+                .await?;
+        }
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_realm_username")
+                    .table(Users::Table)
+                    .col(Users::Realm)
+                    .col(Users::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_realm_email")
+                    .table(Users::Table)
+                    .col(Users::Realm)
+                    .col(Users::Email)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for name in ["uq_users_realm_username", "uq_users_realm_email"] {
+            manager
+                .drop_index(Index::drop().name(name).table(Users::Table).to_owned())
+                .await?;
+        }
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_username")
+                    .table(Users::Table)
+                    .col(Users::Username)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_users_email")
+                    .table(Users::Table)
+                    .col(Users::Email)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        for column in [
+            Users::Realm,
+            Users::DisplayName,
+            Users::AvatarUrl,
+            Users::SuspendedAt,
+            Users::SuspendedUntil,
+            Users::SuspensionReason,
+        ] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(Users::Table)
+                        .drop_column(column)
+                        .to_owned(),
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Username,
+    Email,
+    Realm,
+    DisplayName,
+    AvatarUrl,
+    SuspendedAt,
+    SuspendedUntil,
+    SuspensionReason,
+}
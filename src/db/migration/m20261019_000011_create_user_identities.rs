@@ -0,0 +1,77 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use super::m20261019_000001_create_users::Users;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserIdentities::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(UserIdentities::Id))
+                    .col(uuid(UserIdentities::UserId))
+                    .col(string(UserIdentities::Realm))
+                    .col(string(UserIdentities::Idp))
+                    .col(string(UserIdentities::IdpUid))
+                    .col(timestamp_with_time_zone(UserIdentities::CreatedAt))
+                    .col(timestamp_with_time_zone(UserIdentities::UpdatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_user_identities_user_id")
+                            .from(UserIdentities::Table, UserIdentities::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_identities_realm_idp_idp_uid")
+                    .table(UserIdentities::Table)
+                    .col(UserIdentities::Realm)
+                    .col(UserIdentities::Idp)
+                    .col(UserIdentities::IdpUid)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+        // 한 사용자에게 provider 별 identity 는 하나
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_user_identities_user_id_idp")
+                    .table(UserIdentities::Table)
+                    .col(UserIdentities::UserId)
+                    .col(UserIdentities::Idp)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserIdentities::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum UserIdentities {
+    Table,
+    Id,
+    UserId,
+    Realm,
+    Idp,
+    IdpUid,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 마이그레이션 이전 사용자의 `users.idp`/`idp_uid` 를 `user_identities` 로 옮김.
+/// 옮기기 전 사용자는 identity 가 하나뿐이라 사용자 id 를 identity id 로 그대로 씀
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "INSERT INTO user_identities (id, user_id, realm, idp, idp_uid, created_at, updated_at) \
+                 SELECT id, id, realm, idp, idp_uid, created_at, updated_at FROM users",
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// 가장 먼저 연결된 identity 를 `users` 로 되돌림
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE users SET \
+                 idp = COALESCE((SELECT idp FROM user_identities \
+                 WHERE user_identities.user_id = users.id ORDER BY created_at LIMIT 1), ''), \
+                 idp_uid = COALESCE((SELECT idp_uid FROM user_identities \
+                 WHERE user_identities.user_id = users.id ORDER BY created_at LIMIT 1), '')",
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SSO 세션(`sid`)에서 토큰을 받은 클라이언트. 로그아웃은 이 클라이언트들에만 알림
+        manager
+            .create_table(
+                Table::create()
+                    .table(SessionClients::Table)
+                    .if_not_exists()
+                    .col(uuid(SessionClients::SessionId))
+                    .col(string(SessionClients::ClientId))
+                    .col(string(SessionClients::Realm))
+                    .col(uuid(SessionClients::UserId))
+                    .col(timestamp_with_time_zone(SessionClients::CreatedAt))
+                    .primary_key(
+                        Index::create()
+                            .col(SessionClients::SessionId)
+                            .col(SessionClients::ClientId),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_session_clients_user_id")
+                    .table(SessionClients::Table)
+                    .col(SessionClients::UserId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 보내지 못한 back-channel 로그아웃. 보내거나 포기하면 지움
+        manager
+            .create_table(
+                Table::create()
+                    .table(BackchannelLogouts::Table)
+                    .if_not_exists()
+                    .col(pk_uuid(BackchannelLogouts::Id))
+                    .col(string(BackchannelLogouts::Realm))
+                    .col(string(BackchannelLogouts::ClientId))
+                    .col(uuid(BackchannelLogouts::UserId))
+                    .col(uuid_null(BackchannelLogouts::SessionId))
+                    .col(integer(BackchannelLogouts::Attempts))
+                    .col(timestamp_with_time_zone(BackchannelLogouts::NextAttemptAt))
+                    .col(timestamp_with_time_zone(BackchannelLogouts::CreatedAt))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_backchannel_logouts_realm_next_attempt_at")
+                    .table(BackchannelLogouts::Table)
+                    .col(BackchannelLogouts::Realm)
+                    .col(BackchannelLogouts::NextAttemptAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(BackchannelLogouts::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(SessionClients::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SessionClients {
+    Table,
+    SessionId,
+    ClientId,
+    Realm,
+    UserId,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum BackchannelLogouts {
+    Table,
+    Id,
+    Realm,
+    ClientId,
+    UserId,
+    SessionId,
+    Attempts,
+    NextAttemptAt,
+    CreatedAt,
+}
@@ -0,0 +1,141 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DbBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+/// 역할과 권한을 realm 에 묶고 이름의 유일성을 realm 단위로 바꿈
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 기존 역할과 권한은 모두 기본 realm 소속
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Roles::Table)
+                    .add_column(string(Roles::Realm).default("default"))
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Permissions::Table)
+                    .add_column(string(Permissions::Realm).default("default"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uq_roles_name_audience")
+                    .table(Roles::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_roles_realm_name_audience")
+                    .table(Roles::Table)
+                    .col(Roles::Realm)
+                    .col(Roles::Name)
+                    .col(Roles::Audience)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        // 권한 이름은 컬럼의 UNIQUE 제약이라 Postgres 에서만 지움.
+        // SQLite 는 테이블을 다시 만들지 않고는 지울 수 없어서 이름이 전체에서 유일하게 남음
+        if manager.get_database_backend() == DbBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    "ALTER TABLE permissions DROP CONSTRAINT IF EXISTS permissions_name_key",
+                )
+                .await?;
+        }
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_permissions_realm_name")
+                    .table(Permissions::Table)
+                    .col(Permissions::Realm)
+                    .col(Permissions::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uq_permissions_realm_name")
+                    .table(Permissions::Table)
+                    .to_owned(),
+            )
+            .await?;
+        if manager.get_database_backend() == DbBackend::Postgres {
+            manager
+                .get_connection()
+                .execute_unprepared(
+                    "ALTER TABLE permissions ADD CONSTRAINT permissions_name_key UNIQUE (name)",
+                )
+                .await?;
+        }
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("uq_roles_realm_name_audience")
+                    .table(Roles::Table)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .create_index(
+                Index::create()
+                    .name("uq_roles_name_audience")
+                    .table(Roles::Table)
+                    .col(Roles::Name)
+                    .col(Roles::Audience)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Permissions::Table)
+                    .drop_column(Permissions::Realm)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Roles::Table)
+                    .drop_column(Roles::Realm)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Roles {
+    Table,
+    Realm,
+    Name,
+    Audience,
+}
+
+#[derive(DeriveIden)]
+enum Permissions {
+    Table,
+    Realm,
+    Name,
+}
@@ -0,0 +1,62 @@
+use anyhow::{Result, bail};
+use sea_orm::DatabaseConnection;
+use sea_orm_migration::prelude::*;
+
+mod m20261019_000001_create_users;
+mod m20261019_000002_create_refresh_tokens;
+mod m20261019_000003_create_roles;
+mod m20261019_000004_create_organizations;
+mod m20261019_000005_create_state_entries;
+mod m20261019_000006_add_refresh_token_session_id;
+mod m20261019_000007_create_sessions;
+mod m20261019_000008_create_audit_events;
+mod m20261019_000009_create_webhooks;
+mod m20261019_000010_alter_users;
+mod m20261019_000011_create_user_identities;
+mod m20261019_000012_copy_users_idp_to_identities;
+mod m20261019_000013_drop_users_idp;
+mod m20261019_000014_create_logout_queue;
+mod m20261019_000015_add_refresh_token_client_id;
+mod m20261019_000016_add_realm_to_roles;
+
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests;
+
+pub struct Migrator;
+
+#[async_trait::async_trait]
+impl MigratorTrait for Migrator {
+    fn migrations() -> Vec<Box<dyn MigrationTrait>> {
+        vec![
+            Box::new(m20261019_000001_create_users::Migration),
+            Box::new(m20261019_000002_create_refresh_tokens::Migration),
+            Box::new(m20261019_000003_create_roles::Migration),
+            Box::new(m20261019_000004_create_organizations::Migration),
+            Box::new(m20261019_000005_create_state_entries::Migration),
+            Box::new(m20261019_000006_add_refresh_token_session_id::Migration),
+            Box::new(m20261019_000007_create_sessions::Migration),
+            Box::new(m20261019_000008_create_audit_events::Migration),
+            Box::new(m20261019_000009_create_webhooks::Migration),
+            Box::new(m20261019_000010_alter_users::Migration),
+            Box::new(m20261019_000011_create_user_identities::Migration),
+            Box::new(m20261019_000012_copy_users_idp_to_identities::Migration),
+            Box::new(m20261019_000013_drop_users_idp::Migration),
+            Box::new(m20261019_000014_create_logout_queue::Migration),
+            Box::new(m20261019_000015_add_refresh_token_client_id::Migration),
+            Box::new(m20261019_000016_add_realm_to_roles::Migration),
+        ]
+    }
+}
+
+/// `migrate [up|down|status|fresh]` 서브커맨드. 인자가 없으면 up
+pub async fn run_migrate_command(db: &DatabaseConnection, command: Option<&str>) -> Result<()> {
+    match command.unwrap_or("up") {
+        "up" => Migrator::up(db, None).await?,
+        "down" => Migrator::down(db, Some(1)).await?,
+        "status" => Migrator::status(db).await?,
+        "fresh" => Migrator::fresh(db).await?,
+        other => bail!("unknown migrate command: {other} (expected up, down, status or fresh)"),
+    }
+    Ok(())
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use sea_orm::ConnectionTrait;
+    use sea_orm_migration::prelude::*;
+    use uuid::Uuid;
+
+    use crate::{
+        config::types::SqliteConfig,
+        db::{
+            connect::{memory_connect, sqlite_connect},
+            migration::Migrator,
+            repo::{user_identities::UserIdentitiesRepo, users::UsersRepo},
+        },
+        provider::types::idp::OAuthProvider,
+    };
+
+    #[test]
+    fn test_migration_names_are_ordered_and_unique() {
+        let names: Vec<String> = Migrator::migrations()
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+
+        let mut sorted = names.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(names, sorted);
+    }
+
+    #[tokio::test]
+    async fn test_migrations_up_and_down_on_sqlite() {
+        let db = memory_connect().await;
+        let manager = SchemaManager::new(&db);
+        for table in [
+            "users",
+            "user_identities",
+            "refresh_tokens",
+            "groups",
+            "audit_events",
+            "webhook_events",
+            "webhook_deliveries",
+        ] {
+            assert!(manager.has_table(table).await.unwrap());
+        }
+
+        Migrator::reset(&db).await.unwrap();
+        assert!(!manager.has_table("users").await.unwrap());
+
+        Migrator::up(&db, None).await.unwrap();
+        assert!(manager.has_table("users").await.unwrap());
+    }
+
+    /// 마이그레이션 이전에 손으로 만든 `users` 테이블과 데이터가 지금 모양으로 바뀌어야 함
+    #[tokio::test]
+    async fn test_migrations_upgrade_baseline_users_table() {
+        let db = sqlite_connect(&SqliteConfig {
+            path: ":memory:".to_string(),
+            sqlx_logging: false,
+            auto_migrate: false,
+        })
+        .await
+        .unwrap();
+        Migrator::up(&db, Some(1)).await.unwrap();
+
+        let user_id = Uuid::now_v7();
+        let now = chrono::Utc::now().fixed_offset();
+        let insert = Query::insert()
+            .into_table(Alias::new("users"))
+            .columns([
+                Alias::new("id"),
+                Alias::new("username"),
+                Alias::new("email"),
+                Alias::new("is_active"),
+                Alias::new("created_at"),
+                Alias::new("updated_at"),
+                Alias::new("idp"),
+                Alias::new("idp_uid"),
+            ])
+            .values_panic([
+                user_id.into(),
+                "octocat".into(),
+                "octocat@example.com".into(),
+                true.into(),
+                now.into(),
+                now.into(),
+                "github".into(),
+                "1".into(),
+            ])
+            .to_owned();
+        db.execute(db.get_database_backend().build(&insert))
+            .await
+            .unwrap();
+
+        Migrator::up(&db, None).await.unwrap();
+
+        let manager = SchemaManager::new(&db);
+        assert!(!manager.has_column("users", "idp").await.unwrap());
+        let user = UsersRepo::new(&db)
+            .get_user_by_id(user_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(user.realm, "default");
+        assert_eq!(user.username.as_deref(), Some("octocat"));
+        assert!(user.suspended_at.is_none());
+
+        // 기존 provider identity 로 다시 로그인하면 같은 계정을 찾아야 함
+        let identity = UserIdentitiesRepo::new(&db)
+            .get_identity_by_idp_and_idp_uid("default", OAuthProvider::Github, "1".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(identity.user_id, user_id);
+    }
+}
@@ -0,0 +1,92 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, prelude::DateTimeWithTimeZone,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::types::audit::{AuditEvent, AuditEventType, AuditOutcome},
+    entity::audit_events,
+};
+
+/// 감사 로그 조회 조건. 없는 값은 거르지 않음
+#[derive(Debug, Default)]
+pub struct AuditEventFilter {
+    pub event_type: Option<AuditEventType>,
+    pub outcome: Option<AuditOutcome>,
+    pub actor_id: Option<Uuid>,
+    pub client_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub from: Option<DateTimeWithTimeZone>,
+    pub to: Option<DateTimeWithTimeZone>,
+}
+
+pub struct AuditEventsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> AuditEventsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    pub async fn create_audit_event(
+        &self,
+        realm: &str,
+        event: AuditEvent,
+    ) -> Result<audit_events::Model, DbErr> {
+        let new_event = audit_events::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            realm: Set(realm.to_string()),
+            event_type: Set(event.event_type.as_str().to_string()),
+            outcome: Set(event.outcome.as_str().to_string()),
+            actor_id: Set(event.actor_id),
+            client_id: Set(event.client_id),
+            ip_address: Set(event.ip_address),
+            user_agent: Set(event.user_agent),
+            reason: Set(event.reason),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_event.insert(self.conn).await
+    }
+
+    /// 최근 이벤트부터. `page` 는 0 부터 시작
+    pub async fn list_audit_events(
+        &self,
+        realm: &str,
+        filter: &AuditEventFilter,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<audit_events::Model>, u64), DbErr> {
+        let mut select = audit_events::Entity::find()
+            .filter(audit_events::Column::Realm.eq(realm))
+            .order_by_desc(audit_events::Column::CreatedAt)
+            .order_by_desc(audit_events::Column::Id);
+        if let Some(event_type) = filter.event_type {
+            select = select.filter(audit_events::Column::EventType.eq(event_type.as_str()));
+        }
+        if let Some(outcome) = filter.outcome {
+            select = select.filter(audit_events::Column::Outcome.eq(outcome.as_str()));
+        }
+        if let Some(actor_id) = filter.actor_id {
+            select = select.filter(audit_events::Column::ActorId.eq(actor_id));
+        }
+        if let Some(client_id) = &filter.client_id {
+            select = select.filter(audit_events::Column::ClientId.eq(client_id.as_str()));
+        }
+        if let Some(ip_address) = &filter.ip_address {
+            select = select.filter(audit_events::Column::IpAddress.eq(ip_address.as_str()));
+        }
+        if let Some(from) = filter.from {
+            select = select.filter(audit_events::Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = filter.to {
+            select = select.filter(audit_events::Column::CreatedAt.lt(to));
+        }
+
+        let paginator = select.paginate(self.conn, per_page);
+        let total = paginator.num_items().await?;
+        let events = paginator.fetch_page(page).await?;
+        Ok((events, total))
+    }
+}
@@ -0,0 +1,197 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        api::{
+            extractor::device_info::DeviceInfo,
+            state::types::audit_log::AuditLog,
+            types::{
+                audit::{AuditEvent, AuditEventType, AuditOutcome},
+                realm::Realm,
+            },
+        },
+        db::{
+            connect::memory_connect,
+            repo::audit_events::{AuditEventFilter, AuditEventsRepo},
+        },
+        utils::error::AllForOneError,
+    };
+
+    fn device(ip_address: &str) -> DeviceInfo {
+        DeviceInfo {
+            ip_address: Some(ip_address.to_string()),
+            user_agent: Some("curl/8.0".to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_audit_event_keeps_device_and_reason() {
+        let db = memory_connect().await;
+        let repo = AuditEventsRepo::new(&db);
+
+        let event = repo
+            .create_audit_event(
+                "default",
+                AuditEvent::failure(
+                    AuditEventType::Login,
+                    &device("10.0.0.1"),
+                    "csrf token is invalid",
+                ),
+            )
+            .await
+            .unwrap();
+        assert_eq!(event.event_type, "login");
+        assert_eq!(event.outcome, "failure");
+        assert_eq!(event.actor_id, None);
+        assert_eq!(event.ip_address.as_deref(), Some("10.0.0.1"));
+        assert_eq!(event.user_agent.as_deref(), Some("curl/8.0"));
+        assert_eq!(event.reason.as_deref(), Some("csrf token is invalid"));
+    }
+
+    #[tokio::test]
+    async fn test_audit_log_records_specific_error_reason() {
+        let db = Arc::new(memory_connect().await);
+        let audit_log = AuditLog::new(db.clone(), &Realm::new("default"));
+        let err = AllForOneError::Auth("refresh token is reused".to_string());
+
+        audit_log
+            .record(AuditEvent::failure(
+                AuditEventType::TokenIssue,
+                &device("10.0.0.1"),
+                &err.reason(),
+            ))
+            .await;
+
+        let (events, _) = AuditEventsRepo::new(db.as_ref())
+            .list_audit_events("default", &AuditEventFilter::default(), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].reason.as_deref(), Some("refresh token is reused"));
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_events_filters_by_realm_and_fields() {
+        let db = memory_connect().await;
+        let repo = AuditEventsRepo::new(&db);
+        let user_id = Uuid::now_v7();
+
+        repo.create_audit_event(
+            "default",
+            AuditEvent::success(AuditEventType::Login, &device("10.0.0.1")).actor(user_id),
+        )
+        .await
+        .unwrap();
+        repo.create_audit_event(
+            "default",
+            AuditEvent::success(AuditEventType::TokenIssue, &device("10.0.0.1"))
+                .actor(user_id)
+                .client(Some("web")),
+        )
+        .await
+        .unwrap();
+        repo.create_audit_event(
+            "default",
+            AuditEvent::failure(AuditEventType::TokenIssue, &device("10.0.0.2"), "bad code")
+                .client(Some("web")),
+        )
+        .await
+        .unwrap();
+        repo.create_audit_event(
+            "shop",
+            AuditEvent::success(AuditEventType::Login, &device("10.0.0.1")).actor(user_id),
+        )
+        .await
+        .unwrap();
+
+        let (events, total) = repo
+            .list_audit_events("default", &AuditEventFilter::default(), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(events.len(), 3);
+
+        let (events, total) = repo
+            .list_audit_events(
+                "default",
+                &AuditEventFilter {
+                    actor_id: Some(user_id),
+                    ..Default::default()
+                },
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total, 2);
+        assert!(events.iter().all(|event| event.actor_id == Some(user_id)));
+
+        let (events, total) = repo
+            .list_audit_events(
+                "default",
+                &AuditEventFilter {
+                    event_type: Some(AuditEventType::TokenIssue),
+                    outcome: Some(AuditOutcome::Failure),
+                    client_id: Some("web".to_string()),
+                    ..Default::default()
+                },
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(events[0].ip_address.as_deref(), Some("10.0.0.2"));
+        assert_eq!(events[0].reason.as_deref(), Some("bad code"));
+
+        let (_, total) = repo
+            .list_audit_events(
+                "default",
+                &AuditEventFilter {
+                    from: Some((chrono::Utc::now() + chrono::Duration::hours(1)).into()),
+                    ..Default::default()
+                },
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_audit_events_pages_newest_first() {
+        let db = memory_connect().await;
+        let repo = AuditEventsRepo::new(&db);
+
+        let mut created = Vec::new();
+        for _ in 0..3 {
+            created.push(
+                repo.create_audit_event(
+                    "default",
+                    AuditEvent::success(AuditEventType::Logout, &device("10.0.0.1")),
+                )
+                .await
+                .unwrap(),
+            );
+        }
+
+        let (first_page, total) = repo
+            .list_audit_events("default", &AuditEventFilter::default(), 0, 2)
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+        assert_eq!(first_page.len(), 2);
+        assert_eq!(first_page[0].id, created[2].id);
+
+        let (second_page, _) = repo
+            .list_audit_events("default", &AuditEventFilter::default(), 1, 2)
+            .await
+            .unwrap();
+        assert_eq!(second_page.len(), 1);
+        assert_eq!(second_page[0].id, created[0].id);
+    }
+}
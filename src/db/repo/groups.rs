@@ -0,0 +1,150 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+use uuid::Uuid;
+
+use crate::entity::{group_members, groups};
+
+pub struct GroupsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> GroupsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    pub async fn create_group(
+        &self,
+        organization_id: Uuid,
+        name: String,
+        description: Option<String>,
+    ) -> Result<groups::Model, DbErr> {
+        let new_group = groups::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            organization_id: Set(organization_id),
+            name: Set(name),
+            description: Set(description),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_group.insert(self.conn).await
+    }
+
+    /// 다른 조직의 그룹은 찾지 않음
+    pub async fn get_group(
+        &self,
+        organization_id: Uuid,
+        group_id: Uuid,
+    ) -> Result<Option<groups::Model>, DbErr> {
+        groups::Entity::find_by_id(group_id)
+            .filter(groups::Column::OrganizationId.eq(organization_id))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn get_group_by_name(
+        &self,
+        organization_id: Uuid,
+        name: &str,
+    ) -> Result<Option<groups::Model>, DbErr> {
+        groups::Entity::find()
+            .filter(groups::Column::OrganizationId.eq(organization_id))
+            .filter(groups::Column::Name.eq(name))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn list_groups(&self, organization_id: Uuid) -> Result<Vec<groups::Model>, DbErr> {
+        groups::Entity::find()
+            .filter(groups::Column::OrganizationId.eq(organization_id))
+            .order_by_asc(groups::Column::Name)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn delete_group(&self, group_id: Uuid) -> Result<u64, DbErr> {
+        let result = groups::Entity::delete_by_id(group_id)
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn list_group_members(
+        &self,
+        group_id: Uuid,
+    ) -> Result<Vec<group_members::Model>, DbErr> {
+        group_members::Entity::find()
+            .filter(group_members::Column::GroupId.eq(group_id))
+            .order_by_asc(group_members::Column::CreatedAt)
+            .all(self.conn)
+            .await
+    }
+
+    /// 이미 그룹에 속해 있으면 아무것도 하지 않음
+    pub async fn add_group_member(&self, group_id: Uuid, user_id: Uuid) -> Result<(), DbErr> {
+        let existing = group_members::Entity::find_by_id((group_id, user_id))
+            .one(self.conn)
+            .await?;
+        if existing.is_some() {
+            return Ok(());
+        }
+
+        let new_member = group_members::ActiveModel {
+            group_id: Set(group_id),
+            user_id: Set(user_id),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_member.insert(self.conn).await?;
+        Ok(())
+    }
+
+    pub async fn remove_group_member(&self, group_id: Uuid, user_id: Uuid) -> Result<u64, DbErr> {
+        let result = group_members::Entity::delete_by_id((group_id, user_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 조직을 떠난 사용자를 그 조직의 모든 그룹에서 제거
+    pub async fn remove_user_from_organization_groups(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<u64, DbErr> {
+        let group_ids = self
+            .list_groups(organization_id)
+            .await?
+            .into_iter()
+            .map(|group| group.id)
+            .collect::<Vec<_>>();
+        if group_ids.is_empty() {
+            return Ok(0);
+        }
+
+        let result = group_members::Entity::delete_many()
+            .filter(group_members::Column::GroupId.is_in(group_ids))
+            .filter(group_members::Column::UserId.eq(user_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 토큰에 실을 그룹 이름
+    pub async fn list_group_names_by_user_id(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Vec<String>, DbErr> {
+        groups::Entity::find()
+            .join(JoinType::InnerJoin, groups::Relation::GroupMembers.def())
+            .filter(groups::Column::OrganizationId.eq(organization_id))
+            .filter(group_members::Column::UserId.eq(user_id))
+            .order_by_asc(groups::Column::Name)
+            .select_only()
+            .column(groups::Column::Name)
+            .into_tuple()
+            .all(self.conn)
+            .await
+    }
+}
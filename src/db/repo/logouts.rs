@@ -0,0 +1,178 @@
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait,
+    prelude::DateTimeWithTimeZone,
+    sea_query::{Expr, OnConflict},
+};
+use uuid::Uuid;
+
+use crate::entity::{backchannel_logouts, refresh_tokens, session_clients};
+
+pub struct LogoutsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> LogoutsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    /// 같은 세션에서 같은 클라이언트가 여러 번 토큰을 받아도 한 번만 남김
+    pub async fn add_session_client(
+        &self,
+        realm: &str,
+        session_id: Uuid,
+        client_id: &str,
+        user_id: Uuid,
+    ) -> Result<(), DbErr> {
+        let session_client = session_clients::ActiveModel {
+            session_id: Set(session_id),
+            client_id: Set(client_id.to_string()),
+            realm: Set(realm.to_string()),
+            user_id: Set(user_id),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        session_clients::Entity::insert(session_client)
+            .on_conflict(
+                OnConflict::columns([
+                    session_clients::Column::SessionId,
+                    session_clients::Column::ClientId,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .do_nothing()
+            .exec(self.conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 로그아웃한 세션의 클라이언트를 꺼내고 지움. `session_id` 가 없으면 사용자의 모든 세션
+    pub async fn take_session_clients(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+    ) -> Result<Vec<String>, DbErr> {
+        let mut select = session_clients::Entity::find()
+            .filter(session_clients::Column::Realm.eq(realm))
+            .filter(session_clients::Column::UserId.eq(user_id));
+        let mut delete = session_clients::Entity::delete_many()
+            .filter(session_clients::Column::Realm.eq(realm))
+            .filter(session_clients::Column::UserId.eq(user_id));
+        if let Some(session_id) = session_id {
+            select = select.filter(session_clients::Column::SessionId.eq(session_id));
+            delete = delete.filter(session_clients::Column::SessionId.eq(session_id));
+        }
+
+        let mut client_ids: Vec<String> = select
+            .all(self.conn)
+            .await?
+            .into_iter()
+            .map(|session_client| session_client.client_id)
+            .collect();
+        client_ids.sort();
+        client_ids.dedup();
+        delete.exec(self.conn).await?;
+        Ok(client_ids)
+    }
+
+    /// 로그아웃 없이 끝난 세션의 기록을 지움. 살아 있는 refresh token 이 남은 세션은 남김
+    pub async fn delete_expired_session_clients(
+        &self,
+        before: DateTimeWithTimeZone,
+    ) -> Result<u64, DbErr> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let live_sessions = refresh_tokens::Entity::find()
+            .select_only()
+            .column(refresh_tokens::Column::SessionId)
+            .filter(refresh_tokens::Column::SessionId.is_not_null())
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .filter(refresh_tokens::Column::ExpiresAt.gt(now))
+            .into_query();
+        let result = session_clients::Entity::delete_many()
+            .filter(session_clients::Column::CreatedAt.lt(before))
+            .filter(session_clients::Column::SessionId.not_in_subquery(live_sessions))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn enqueue_backchannel_logouts(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        session_id: Option<Uuid>,
+        client_ids: &[&str],
+    ) -> Result<(), DbErr> {
+        if client_ids.is_empty() {
+            return Ok(());
+        }
+
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let logouts = client_ids
+            .iter()
+            .map(|client_id| backchannel_logouts::ActiveModel {
+                id: Set(Uuid::now_v7()),
+                realm: Set(realm.to_string()),
+                client_id: Set(client_id.to_string()),
+                user_id: Set(user_id),
+                session_id: Set(session_id),
+                attempts: Set(0),
+                next_attempt_at: Set(now),
+                created_at: Set(now),
+            });
+        backchannel_logouts::Entity::insert_many(logouts)
+            .exec(self.conn)
+            .await?;
+        Ok(())
+    }
+
+    /// 보낼 시각이 된 realm 의 back-channel 로그아웃. 오래된 것부터
+    pub async fn list_due_backchannel_logouts(
+        &self,
+        realm: &str,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> Result<Vec<backchannel_logouts::Model>, DbErr> {
+        backchannel_logouts::Entity::find()
+            .filter(backchannel_logouts::Column::Realm.eq(realm))
+            .filter(backchannel_logouts::Column::NextAttemptAt.lte(now))
+            .order_by_asc(backchannel_logouts::Column::NextAttemptAt)
+            .limit(limit)
+            .all(self.conn)
+            .await
+    }
+
+    /// 시도 횟수를 늘리고 `next_attempt_at` 까지 다른 replica 가 가져가지 않게 함
+    /// 그 사이 다른 replica 가 먼저 가져갔으면 false
+    pub async fn claim_backchannel_logout(
+        &self,
+        logout: &backchannel_logouts::Model,
+        next_attempt_at: DateTimeWithTimeZone,
+    ) -> Result<bool, DbErr> {
+        let result = backchannel_logouts::Entity::update_many()
+            .col_expr(
+                backchannel_logouts::Column::Attempts,
+                Expr::value(logout.attempts + 1),
+            )
+            .col_expr(
+                backchannel_logouts::Column::NextAttemptAt,
+                Expr::value(next_attempt_at),
+            )
+            .filter(backchannel_logouts::Column::Id.eq(logout.id))
+            .filter(backchannel_logouts::Column::Attempts.eq(logout.attempts))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected == 1)
+    }
+
+    /// 보냈거나 포기한 로그아웃
+    pub async fn delete_backchannel_logout(&self, logout_id: Uuid) -> Result<(), DbErr> {
+        backchannel_logouts::Entity::delete_by_id(logout_id)
+            .exec(self.conn)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,208 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        db::{
+            connect::memory_connect,
+            repo::{logouts::LogoutsRepo, refresh_tokens::RefreshTokensRepo, users::UsersRepo},
+        },
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    #[tokio::test]
+    async fn test_take_session_clients_by_session_and_user() {
+        let db = memory_connect().await;
+        let repo = LogoutsRepo::new(&db);
+        let user_id = Uuid::now_v7();
+        let (first, second) = (Uuid::now_v7(), Uuid::now_v7());
+
+        for (session_id, client_id) in [
+            (first, "web"),
+            (first, "web"),
+            (first, "cli"),
+            (second, "admin"),
+        ] {
+            repo.add_session_client("default", session_id, client_id, user_id)
+                .await
+                .unwrap();
+        }
+        repo.add_session_client("other", Uuid::now_v7(), "web", user_id)
+            .await
+            .unwrap();
+
+        let client_ids = repo
+            .take_session_clients("default", user_id, Some(first))
+            .await
+            .unwrap();
+        assert_eq!(client_ids, ["cli", "web"]);
+        assert!(
+            repo.take_session_clients("default", user_id, Some(first))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let client_ids = repo
+            .take_session_clients("default", user_id, None)
+            .await
+            .unwrap();
+        assert_eq!(client_ids, ["admin"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_session_clients_keeps_live_sessions() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let repo = LogoutsRepo::new(&db);
+        let (live, ended) = (Uuid::now_v7(), Uuid::now_v7());
+        for session_id in [live, ended] {
+            repo.add_session_client("default", session_id, "web", user.id)
+                .await
+                .unwrap();
+        }
+        RefreshTokensRepo::new(&db)
+            .create_refresh_token(
+                user.id,
+                Uuid::now_v7(),
+                None,
+                Some(live),
+                None,
+                "a".to_string(),
+                60,
+            )
+            .await
+            .unwrap();
+
+        let before = chrono::Utc::now() + chrono::Duration::seconds(1);
+        assert_eq!(
+            repo.delete_expired_session_clients(before.into())
+                .await
+                .unwrap(),
+            1
+        );
+        assert_eq!(
+            repo.take_session_clients("default", user.id, Some(live))
+                .await
+                .unwrap(),
+            ["web"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_backchannel_logout_is_claimed_once() {
+        let db = memory_connect().await;
+        let repo = LogoutsRepo::new(&db);
+        let user_id = Uuid::now_v7();
+        let sid = Some(Uuid::now_v7());
+
+        repo.enqueue_backchannel_logouts("default", user_id, sid, &["web", "cli"])
+            .await
+            .unwrap();
+        repo.enqueue_backchannel_logouts("default", user_id, sid, &[])
+            .await
+            .unwrap();
+
+        let now = chrono::Utc::now();
+        let due = repo
+            .list_due_backchannel_logouts("default", now.into(), 10)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 2);
+        assert!(
+            repo.list_due_backchannel_logouts("other", now.into(), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let later = now + chrono::Duration::seconds(60);
+        assert!(
+            repo.claim_backchannel_logout(&due[0], later.into())
+                .await
+                .unwrap()
+        );
+        // 이미 가져간 전송은 다른 replica 가 다시 가져가지 못함
+        assert!(
+            !repo
+                .claim_backchannel_logout(&due[0], later.into())
+                .await
+                .unwrap()
+        );
+        repo.delete_backchannel_logout(due[1].id).await.unwrap();
+
+        assert!(
+            repo.list_due_backchannel_logouts("default", now.into(), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        let retried = repo
+            .list_due_backchannel_logouts("default", later.into(), 10)
+            .await
+            .unwrap();
+        assert_eq!(retried.len(), 1);
+        assert_eq!(retried[0].attempts, 1);
+    }
+
+    #[tokio::test]
+    async fn test_backchannel_logouts_are_enqueued_for_deleted_user() {
+        let db = memory_connect().await;
+        let users_repo = UsersRepo::new(&db);
+        let user = users_repo
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let repo = LogoutsRepo::new(&db);
+        for (session_id, client_id) in [(Uuid::now_v7(), "web"), (Uuid::now_v7(), "cli")] {
+            repo.add_session_client("default", session_id, client_id, user.id)
+                .await
+                .unwrap();
+        }
+        assert_eq!(users_repo.delete_user(user.id).await.unwrap(), 1);
+
+        // 관리자가 정지하거나 삭제한 사용자는 모든 세션의 클라이언트에 알림
+        let client_ids = repo
+            .take_session_clients("default", user.id, None)
+            .await
+            .unwrap();
+        assert_eq!(client_ids, ["cli", "web"]);
+        let client_ids: Vec<&str> = client_ids.iter().map(String::as_str).collect();
+        repo.enqueue_backchannel_logouts("default", user.id, None, &client_ids)
+            .await
+            .unwrap();
+
+        let due = repo
+            .list_due_backchannel_logouts("default", chrono::Utc::now().into(), 10)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 2);
+        assert!(
+            due.iter()
+                .all(|logout| logout.user_id == user.id && logout.session_id.is_none())
+        );
+    }
+}
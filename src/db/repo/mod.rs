@@ -1 +1,26 @@
+pub mod audit_events;
+pub mod groups;
+pub mod logouts;
+pub mod organizations;
+pub mod permissions;
+pub mod refresh_tokens;
+pub mod roles;
+pub mod sessions;
+pub mod user_identities;
 pub mod users;
+pub mod webhooks;
+
+#[cfg(test)]
+mod audit_events_tests;
+#[cfg(test)]
+mod logouts_tests;
+#[cfg(test)]
+mod refresh_tokens_tests;
+#[cfg(test)]
+mod roles_tests;
+#[cfg(test)]
+mod sessions_tests;
+#[cfg(test)]
+mod users_tests;
+#[cfg(test)]
+mod webhooks_tests;
@@ -0,0 +1,234 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::types::organization::OrganizationRole,
+    entity::{organization_invitations, organization_members, organizations},
+};
+
+pub struct OrganizationsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> OrganizationsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    /// 조직을 만들고 만든 사용자를 owner 로 등록
+    pub async fn create_organization(
+        &self,
+        realm: &str,
+        name: String,
+        slug: String,
+        owner_id: Uuid,
+    ) -> Result<(organizations::Model, organization_members::Model), DbErr> {
+        let now = chrono::Utc::now().into();
+        let new_organization = organizations::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            realm: Set(realm.to_string()),
+            name: Set(name),
+            slug: Set(slug),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        let organization = new_organization.insert(self.conn).await?;
+
+        let owner = self
+            .add_member(organization.id, owner_id, OrganizationRole::Owner)
+            .await?;
+        Ok((organization, owner))
+    }
+
+    pub async fn get_organization_by_id(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Option<organizations::Model>, DbErr> {
+        organizations::Entity::find_by_id(organization_id)
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn get_organization_by_slug(
+        &self,
+        realm: &str,
+        slug: &str,
+    ) -> Result<Option<organizations::Model>, DbErr> {
+        organizations::Entity::find()
+            .filter(organizations::Column::Realm.eq(realm))
+            .filter(organizations::Column::Slug.eq(slug))
+            .one(self.conn)
+            .await
+    }
+
+    /// 사용자가 속한 조직과 그 조직에서의 역할
+    pub async fn list_organizations_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<(organizations::Model, organization_members::Model)>, DbErr> {
+        let memberships = organization_members::Entity::find()
+            .filter(organization_members::Column::UserId.eq(user_id))
+            .find_also_related(organizations::Entity)
+            .order_by_asc(organization_members::Column::CreatedAt)
+            .all(self.conn)
+            .await?;
+
+        Ok(memberships
+            .into_iter()
+            .filter_map(|(member, organization)| organization.map(|org| (org, member)))
+            .collect())
+    }
+
+    pub async fn delete_organization(&self, organization_id: Uuid) -> Result<u64, DbErr> {
+        let result = organizations::Entity::delete_by_id(organization_id)
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn get_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<organization_members::Model>, DbErr> {
+        organization_members::Entity::find_by_id((organization_id, user_id))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn list_members(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<organization_members::Model>, DbErr> {
+        organization_members::Entity::find()
+            .filter(organization_members::Column::OrganizationId.eq(organization_id))
+            .order_by_asc(organization_members::Column::CreatedAt)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn count_members_by_role(
+        &self,
+        organization_id: Uuid,
+        role: OrganizationRole,
+    ) -> Result<u64, DbErr> {
+        organization_members::Entity::find()
+            .filter(organization_members::Column::OrganizationId.eq(organization_id))
+            .filter(organization_members::Column::Role.eq(role.as_str()))
+            .count(self.conn)
+            .await
+    }
+
+    pub async fn add_member(
+        &self,
+        organization_id: Uuid,
+        user_id: Uuid,
+        role: OrganizationRole,
+    ) -> Result<organization_members::Model, DbErr> {
+        let now = chrono::Utc::now().into();
+        let new_member = organization_members::ActiveModel {
+            organization_id: Set(organization_id),
+            user_id: Set(user_id),
+            role: Set(role.as_str().to_string()),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        new_member.insert(self.conn).await
+    }
+
+    pub async fn update_member_role(
+        &self,
+        member: organization_members::Model,
+        role: OrganizationRole,
+    ) -> Result<organization_members::Model, DbErr> {
+        let mut active_member: organization_members::ActiveModel = member.into();
+        active_member.role = Set(role.as_str().to_string());
+        active_member.updated_at = Set(chrono::Utc::now().into());
+        active_member.update(self.conn).await
+    }
+
+    pub async fn remove_member(&self, organization_id: Uuid, user_id: Uuid) -> Result<u64, DbErr> {
+        let result = organization_members::Entity::delete_by_id((organization_id, user_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn create_invitation(
+        &self,
+        organization_id: Uuid,
+        email: String,
+        role: OrganizationRole,
+        token_hash: String,
+        invited_by: Uuid,
+        ttl: i64,
+    ) -> Result<organization_invitations::Model, DbErr> {
+        let now = chrono::Utc::now();
+        let new_invitation = organization_invitations::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            organization_id: Set(organization_id),
+            email: Set(email),
+            role: Set(role.as_str().to_string()),
+            token_hash: Set(token_hash),
+            invited_by: Set(invited_by),
+            expires_at: Set((now + chrono::Duration::seconds(ttl)).into()),
+            accepted_at: Set(None),
+            created_at: Set(now.into()),
+        };
+        new_invitation.insert(self.conn).await
+    }
+
+    pub async fn get_invitation_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<organization_invitations::Model>, DbErr> {
+        organization_invitations::Entity::find()
+            .filter(organization_invitations::Column::TokenHash.eq(token_hash))
+            .one(self.conn)
+            .await
+    }
+
+    /// 아직 수락되지 않았고 만료되지 않은 초대
+    pub async fn list_pending_invitations(
+        &self,
+        organization_id: Uuid,
+    ) -> Result<Vec<organization_invitations::Model>, DbErr> {
+        organization_invitations::Entity::find()
+            .filter(organization_invitations::Column::OrganizationId.eq(organization_id))
+            .filter(organization_invitations::Column::AcceptedAt.is_null())
+            .filter(organization_invitations::Column::ExpiresAt.gt(chrono::Utc::now()))
+            .order_by_asc(organization_invitations::Column::CreatedAt)
+            .all(self.conn)
+            .await
+    }
+
+    /// 이미 수락된 초대면 0 을 반환
+    pub async fn accept_invitation(&self, invitation_id: Uuid) -> Result<u64, DbErr> {
+        let result = organization_invitations::Entity::update_many()
+            .col_expr(
+                organization_invitations::Column::AcceptedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(organization_invitations::Column::Id.eq(invitation_id))
+            .filter(organization_invitations::Column::AcceptedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn delete_invitation(
+        &self,
+        organization_id: Uuid,
+        invitation_id: Uuid,
+    ) -> Result<u64, DbErr> {
+        let result = organization_invitations::Entity::delete_many()
+            .filter(organization_invitations::Column::Id.eq(invitation_id))
+            .filter(organization_invitations::Column::OrganizationId.eq(organization_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,73 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::entity::permissions;
+
+pub struct PermissionsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> PermissionsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    pub async fn create_permission(
+        &self,
+        realm: &str,
+        name: String,
+        description: Option<String>,
+    ) -> Result<permissions::Model, DbErr> {
+        let new_permission = permissions::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            realm: Set(realm.to_string()),
+            name: Set(name),
+            description: Set(description),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_permission.insert(self.conn).await
+    }
+
+    /// 다른 realm 의 권한은 없는 것으로 봄
+    pub async fn get_permission_by_id(
+        &self,
+        realm: &str,
+        permission_id: Uuid,
+    ) -> Result<Option<permissions::Model>, DbErr> {
+        permissions::Entity::find_by_id(permission_id)
+            .filter(permissions::Column::Realm.eq(realm))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn get_permission_by_name(
+        &self,
+        realm: &str,
+        name: &str,
+    ) -> Result<Option<permissions::Model>, DbErr> {
+        permissions::Entity::find()
+            .filter(permissions::Column::Realm.eq(realm))
+            .filter(permissions::Column::Name.eq(name))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn list_permissions(&self, realm: &str) -> Result<Vec<permissions::Model>, DbErr> {
+        permissions::Entity::find()
+            .filter(permissions::Column::Realm.eq(realm))
+            .order_by_asc(permissions::Column::Name)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn delete_permission(&self, realm: &str, permission_id: Uuid) -> Result<u64, DbErr> {
+        let result = permissions::Entity::delete_by_id(permission_id)
+            .filter(permissions::Column::Realm.eq(realm))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
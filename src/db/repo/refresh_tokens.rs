@@ -0,0 +1,115 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait, JoinType,
+    QueryFilter, QuerySelect, RelationTrait, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::entity::{refresh_tokens, users};
+
+pub struct RefreshTokensRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> RefreshTokensRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn create_refresh_token(
+        &self,
+        user_id: Uuid,
+        family_id: Uuid,
+        organization_id: Option<Uuid>,
+        session_id: Option<Uuid>,
+        client_id: Option<String>,
+        token_hash: String,
+        ttl: i64,
+    ) -> Result<refresh_tokens::Model, DbErr> {
+        let now = chrono::Utc::now();
+        let new_token = refresh_tokens::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user_id),
+            family_id: Set(family_id),
+            organization_id: Set(organization_id),
+            session_id: Set(session_id),
+            client_id: Set(client_id),
+            token_hash: Set(token_hash),
+            expires_at: Set((now + chrono::Duration::seconds(ttl)).into()),
+            revoked_at: Set(None),
+            created_at: Set(now.into()),
+        };
+        new_token.insert(self.conn).await
+    }
+
+    /// 다른 realm 사용자의 토큰은 없는 것으로 봄
+    pub async fn get_refresh_token_by_hash(
+        &self,
+        realm: &str,
+        token_hash: &str,
+    ) -> Result<Option<refresh_tokens::Model>, DbErr> {
+        refresh_tokens::Entity::find()
+            .join(JoinType::InnerJoin, refresh_tokens::Relation::Users.def())
+            .filter(users::Column::Realm.eq(realm))
+            .filter(refresh_tokens::Column::TokenHash.eq(token_hash))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn revoke_refresh_token(&self, token_id: Uuid) -> Result<u64, DbErr> {
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::Id.eq(token_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 재사용이 감지된 토큰 계열 전체를 폐기
+    pub async fn revoke_refresh_token_family(&self, family_id: Uuid) -> Result<u64, DbErr> {
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::FamilyId.eq(family_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn revoke_refresh_tokens_by_user_id(&self, user_id: Uuid) -> Result<u64, DbErr> {
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::UserId.eq(user_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 로그아웃한 SSO 세션에서 발급된 토큰을 모두 폐기
+    pub async fn revoke_refresh_tokens_by_session_id(
+        &self,
+        session_id: Uuid,
+    ) -> Result<u64, DbErr> {
+        let result = refresh_tokens::Entity::update_many()
+            .col_expr(
+                refresh_tokens::Column::RevokedAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .filter(refresh_tokens::Column::SessionId.eq(session_id))
+            .filter(refresh_tokens::Column::RevokedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,157 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        db::{
+            connect::memory_connect,
+            repo::{refresh_tokens::RefreshTokensRepo, users::UsersRepo},
+        },
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    #[tokio::test]
+    async fn test_revoke_refresh_tokens_by_session_id() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let repo = RefreshTokensRepo::new(&db);
+
+        let session_id = Uuid::now_v7();
+        for token_hash in ["a", "b"] {
+            repo.create_refresh_token(
+                user.id,
+                Uuid::now_v7(),
+                None,
+                Some(session_id),
+                None,
+                token_hash.to_string(),
+                60,
+            )
+            .await
+            .unwrap();
+        }
+        repo.create_refresh_token(
+            user.id,
+            Uuid::now_v7(),
+            None,
+            None,
+            Some("web".to_string()),
+            "c".to_string(),
+            60,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            repo.revoke_refresh_tokens_by_session_id(session_id)
+                .await
+                .unwrap(),
+            2
+        );
+        let other = repo
+            .get_refresh_token_by_hash("default", "c")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(other.revoked_at.is_none());
+        assert_eq!(other.client_id.as_deref(), Some("web"));
+        assert_eq!(
+            repo.revoke_refresh_tokens_by_session_id(session_id)
+                .await
+                .unwrap(),
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_refresh_token_by_hash_is_scoped_to_realm() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "shop",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let repo = RefreshTokensRepo::new(&db);
+        repo.create_refresh_token(
+            user.id,
+            Uuid::now_v7(),
+            None,
+            None,
+            None,
+            "a".to_string(),
+            60,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            repo.get_refresh_token_by_hash("shop", "a")
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            repo.get_refresh_token_by_hash("default", "a")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_revoke_refresh_token_succeeds_only_once() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let repo = RefreshTokensRepo::new(&db);
+        let token = repo
+            .create_refresh_token(
+                user.id,
+                Uuid::now_v7(),
+                None,
+                None,
+                None,
+                "a".to_string(),
+                60,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(repo.revoke_refresh_token(token.id).await.unwrap(), 1);
+        assert_eq!(repo.revoke_refresh_token(token.id).await.unwrap(), 0);
+    }
+}
@@ -0,0 +1,213 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+use uuid::Uuid;
+
+use crate::entity::{permissions, role_permissions, roles, user_roles};
+
+pub struct RolesRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> RolesRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    pub async fn create_role(
+        &self,
+        realm: &str,
+        name: String,
+        audience: Option<String>,
+        description: Option<String>,
+    ) -> Result<roles::Model, DbErr> {
+        let new_role = roles::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            realm: Set(realm.to_string()),
+            name: Set(name),
+            audience: Set(audience),
+            description: Set(description),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_role.insert(self.conn).await
+    }
+
+    /// 다른 realm 의 역할은 없는 것으로 봄
+    pub async fn get_role_by_id(
+        &self,
+        realm: &str,
+        role_id: Uuid,
+    ) -> Result<Option<roles::Model>, DbErr> {
+        roles::Entity::find_by_id(role_id)
+            .filter(roles::Column::Realm.eq(realm))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn get_role_by_name_and_audience(
+        &self,
+        realm: &str,
+        name: &str,
+        audience: Option<&str>,
+    ) -> Result<Option<roles::Model>, DbErr> {
+        let audience_condition = match audience {
+            Some(audience) => roles::Column::Audience.eq(audience),
+            None => roles::Column::Audience.is_null(),
+        };
+        roles::Entity::find()
+            .filter(roles::Column::Realm.eq(realm))
+            .filter(roles::Column::Name.eq(name))
+            .filter(audience_condition)
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn list_roles(&self, realm: &str) -> Result<Vec<roles::Model>, DbErr> {
+        roles::Entity::find()
+            .filter(roles::Column::Realm.eq(realm))
+            .order_by_asc(roles::Column::Name)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn delete_role(&self, realm: &str, role_id: Uuid) -> Result<u64, DbErr> {
+        let result = roles::Entity::delete_by_id(role_id)
+            .filter(roles::Column::Realm.eq(realm))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn list_permissions_by_role_id(
+        &self,
+        realm: &str,
+        role_id: Uuid,
+    ) -> Result<Vec<permissions::Model>, DbErr> {
+        permissions::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                permissions::Relation::RolePermissions.def(),
+            )
+            .filter(permissions::Column::Realm.eq(realm))
+            .filter(role_permissions::Column::RoleId.eq(role_id))
+            .order_by_asc(permissions::Column::Name)
+            .all(self.conn)
+            .await
+    }
+
+    /// 이미 부여된 권한이면 아무것도 하지 않음
+    pub async fn grant_permission(&self, role_id: Uuid, permission_id: Uuid) -> Result<(), DbErr> {
+        let existing = role_permissions::Entity::find_by_id((role_id, permission_id))
+            .one(self.conn)
+            .await?;
+        if existing.is_some() {
+            return Ok(());
+        }
+
+        let new_role_permission = role_permissions::ActiveModel {
+            role_id: Set(role_id),
+            permission_id: Set(permission_id),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_role_permission.insert(self.conn).await?;
+        Ok(())
+    }
+
+    pub async fn revoke_permission(
+        &self,
+        role_id: Uuid,
+        permission_id: Uuid,
+    ) -> Result<u64, DbErr> {
+        let result = role_permissions::Entity::delete_by_id((role_id, permission_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    pub async fn list_roles_by_user_id(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+    ) -> Result<Vec<roles::Model>, DbErr> {
+        roles::Entity::find()
+            .join(JoinType::InnerJoin, roles::Relation::UserRoles.def())
+            .filter(roles::Column::Realm.eq(realm))
+            .filter(user_roles::Column::UserId.eq(user_id))
+            .order_by_asc(roles::Column::Name)
+            .all(self.conn)
+            .await
+    }
+
+    /// 이미 부여된 역할이면 아무것도 하지 않음
+    pub async fn assign_role(&self, user_id: Uuid, role_id: Uuid) -> Result<(), DbErr> {
+        let existing = user_roles::Entity::find_by_id((user_id, role_id))
+            .one(self.conn)
+            .await?;
+        if existing.is_some() {
+            return Ok(());
+        }
+
+        let new_user_role = user_roles::ActiveModel {
+            user_id: Set(user_id),
+            role_id: Set(role_id),
+            created_at: Set(chrono::Utc::now().into()),
+        };
+        new_user_role.insert(self.conn).await?;
+        Ok(())
+    }
+
+    pub async fn unassign_role(&self, user_id: Uuid, role_id: Uuid) -> Result<u64, DbErr> {
+        let result = user_roles::Entity::delete_by_id((user_id, role_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 토큰에 실을 역할과 권한 이름. audience 가 없는 역할은 모든 audience 에 적용
+    /// 토큰을 발급하는 realm 의 역할과 권한만 실음
+    pub async fn get_user_authorization(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        audience: &str,
+    ) -> Result<(Vec<String>, Vec<String>), DbErr> {
+        let audience_condition = Condition::any()
+            .add(roles::Column::Audience.is_null())
+            .add(roles::Column::Audience.eq(audience));
+
+        let roles = roles::Entity::find()
+            .join(JoinType::InnerJoin, roles::Relation::UserRoles.def())
+            .filter(roles::Column::Realm.eq(realm))
+            .filter(user_roles::Column::UserId.eq(user_id))
+            .filter(audience_condition)
+            .order_by_asc(roles::Column::Name)
+            .all(self.conn)
+            .await?;
+        if roles.is_empty() {
+            return Ok((vec![], vec![]));
+        }
+
+        let role_ids = roles.iter().map(|role| role.id).collect::<Vec<_>>();
+        let permissions = permissions::Entity::find()
+            .join(
+                JoinType::InnerJoin,
+                permissions::Relation::RolePermissions.def(),
+            )
+            .filter(permissions::Column::Realm.eq(realm))
+            .filter(role_permissions::Column::RoleId.is_in(role_ids))
+            .order_by_asc(permissions::Column::Name)
+            .all(self.conn)
+            .await?;
+
+        let mut role_names = roles.into_iter().map(|role| role.name).collect::<Vec<_>>();
+        role_names.dedup();
+        let mut permission_names = permissions
+            .into_iter()
+            .map(|permission| permission.name)
+            .collect::<Vec<_>>();
+        permission_names.dedup();
+
+        Ok((role_names, permission_names))
+    }
+}
@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{
+            connect::memory_connect,
+            repo::{permissions::PermissionsRepo, roles::RolesRepo, users::UsersRepo},
+        },
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    #[tokio::test]
+    async fn test_user_authorization_only_includes_roles_of_realm() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "a",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let roles_repo = RolesRepo::new(&db);
+        let permissions_repo = PermissionsRepo::new(&db);
+
+        let role = roles_repo
+            .create_role("a", "editor".to_string(), None, None)
+            .await
+            .unwrap();
+        let permission = permissions_repo
+            .create_permission("a", "docs:write".to_string(), None)
+            .await
+            .unwrap();
+        roles_repo
+            .grant_permission(role.id, permission.id)
+            .await
+            .unwrap();
+        roles_repo.assign_role(user.id, role.id).await.unwrap();
+        // 같은 이름의 역할을 다른 realm 에 만들 수 있음
+        roles_repo
+            .create_role("b", "editor".to_string(), None, None)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            roles_repo
+                .get_user_authorization("a", user.id, "all-for-one")
+                .await
+                .unwrap(),
+            (vec!["editor".to_string()], vec!["docs:write".to_string()])
+        );
+        assert_eq!(
+            roles_repo
+                .get_user_authorization("b", user.id, "all-for-one")
+                .await
+                .unwrap(),
+            (vec![], vec![])
+        );
+        assert!(
+            roles_repo
+                .list_roles_by_user_id("b", user.id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            roles_repo
+                .get_role_by_id("b", role.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert_eq!(roles_repo.delete_role("b", role.id).await.unwrap(), 0);
+        assert!(
+            permissions_repo
+                .list_permissions("b")
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,103 @@
+use sea_orm::{
+    ActiveModelTrait,
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder,
+    sea_query::{Expr, Query},
+};
+use uuid::Uuid;
+
+use crate::{
+    entity::{refresh_tokens, sessions},
+    provider::types::idp::OAuthProvider,
+};
+
+pub struct SessionsRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> SessionsRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    /// 새 refresh token 계열을 시작할 때 기기 세션을 기록
+    pub async fn create_session(
+        &self,
+        family_id: Uuid,
+        user_id: Uuid,
+        idp: OAuthProvider,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+    ) -> Result<sessions::Model, DbErr> {
+        let now = chrono::Utc::now();
+        let new_session = sessions::ActiveModel {
+            id: Set(family_id),
+            user_id: Set(user_id),
+            idp: Set(idp.as_str().to_string()),
+            user_agent: Set(user_agent),
+            ip_address: Set(ip_address),
+            created_at: Set(now.into()),
+            last_seen_at: Set(now.into()),
+        };
+        new_session.insert(self.conn).await
+    }
+
+    /// 토큰을 회전할 때 마지막 사용 시각과 기기 정보를 갱신
+    pub async fn touch_session(
+        &self,
+        family_id: Uuid,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+    ) -> Result<u64, DbErr> {
+        let result = sessions::Entity::update_many()
+            .col_expr(
+                sessions::Column::LastSeenAt,
+                Expr::value(chrono::Utc::now().fixed_offset()),
+            )
+            .col_expr(sessions::Column::UserAgent, Expr::value(user_agent))
+            .col_expr(sessions::Column::IpAddress, Expr::value(ip_address))
+            .filter(sessions::Column::Id.eq(family_id))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// 아직 쓸 수 있는 refresh token 이 남은 세션만 최근 사용 순으로
+    pub async fn list_active_sessions_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<sessions::Model>, DbErr> {
+        let active_families = Query::select()
+            .column(refresh_tokens::Column::FamilyId)
+            .from(refresh_tokens::Entity)
+            .and_where(refresh_tokens::Column::UserId.eq(user_id))
+            .and_where(refresh_tokens::Column::RevokedAt.is_null())
+            .and_where(refresh_tokens::Column::ExpiresAt.gt(chrono::Utc::now().fixed_offset()))
+            .to_owned();
+
+        sessions::Entity::find()
+            .filter(sessions::Column::UserId.eq(user_id))
+            .filter(sessions::Column::Id.in_subquery(active_families))
+            .order_by_desc(sessions::Column::LastSeenAt)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn get_session_by_user_id(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<Option<sessions::Model>, DbErr> {
+        sessions::Entity::find_by_id(session_id)
+            .filter(sessions::Column::UserId.eq(user_id))
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn delete_session(&self, session_id: Uuid) -> Result<u64, DbErr> {
+        let result = sessions::Entity::delete_by_id(session_id)
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
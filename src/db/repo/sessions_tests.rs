@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use crate::{
+        db::{
+            connect::memory_connect,
+            repo::{refresh_tokens::RefreshTokensRepo, sessions::SessionsRepo, users::UsersRepo},
+        },
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    #[tokio::test]
+    async fn test_list_active_sessions_by_user_id() {
+        let db = memory_connect().await;
+        let user = UsersRepo::new(&db)
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                UserProfile {
+                    idp_uid: "1".to_string(),
+                    login: Some("octocat".to_string()),
+                    display_name: None,
+                    email: None,
+                    avatar_url: None,
+                },
+            )
+            .await
+            .unwrap();
+        let sessions_repo = SessionsRepo::new(&db);
+        let refresh_tokens_repo = RefreshTokensRepo::new(&db);
+
+        let mut family_ids = Vec::new();
+        for token_hash in ["a", "b"] {
+            let family_id = Uuid::now_v7();
+            sessions_repo
+                .create_session(
+                    family_id,
+                    user.id,
+                    OAuthProvider::Github,
+                    Some("Mozilla/5.0".to_string()),
+                    Some("127.0.0.1".to_string()),
+                )
+                .await
+                .unwrap();
+            refresh_tokens_repo
+                .create_refresh_token(
+                    user.id,
+                    family_id,
+                    None,
+                    None,
+                    None,
+                    token_hash.to_string(),
+                    60,
+                )
+                .await
+                .unwrap();
+            family_ids.push(family_id);
+        }
+
+        let touched = sessions_repo
+            .touch_session(family_ids[0], Some("curl/8.0".to_string()), None)
+            .await
+            .unwrap();
+        assert_eq!(touched, 1);
+
+        let sessions = sessions_repo
+            .list_active_sessions_by_user_id(user.id)
+            .await
+            .unwrap();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[0].id, family_ids[0]);
+        assert_eq!(sessions[0].user_agent.as_deref(), Some("curl/8.0"));
+        assert_eq!(sessions[0].idp, "github");
+
+        refresh_tokens_repo
+            .revoke_refresh_token_family(family_ids[1])
+            .await
+            .unwrap();
+        let sessions = sessions_repo
+            .list_active_sessions_by_user_id(user.id)
+            .await
+            .unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert!(
+            sessions_repo
+                .get_session_by_user_id(Uuid::now_v7(), family_ids[0])
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
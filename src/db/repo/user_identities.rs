@@ -0,0 +1,158 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::types::webhook::{WebhookEventType, WebhookUserData},
+    db::repo::webhooks::WebhooksRepo,
+    entity::user_identities,
+    provider::types::idp::OAuthProvider,
+};
+
+/// identity 연결 시도 결과
+pub enum LinkIdentityResult {
+    Linked,
+    AlreadyLinked,
+    LinkedToOtherUser,
+    ProviderAlreadyLinked,
+}
+
+pub struct UserIdentitiesRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> UserIdentitiesRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    pub async fn create_identity(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        idp: OAuthProvider,
+        idp_uid: String,
+    ) -> Result<user_identities::Model, DbErr> {
+        let now = chrono::Utc::now().into();
+        let new_identity = user_identities::ActiveModel {
+            id: Set(Uuid::now_v7()),
+            user_id: Set(user_id),
+            realm: Set(realm.to_string()),
+            idp: Set(idp.as_str().to_string()),
+            idp_uid: Set(idp_uid),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+        new_identity.insert(self.conn).await
+    }
+
+    /// 이미 다른 사용자에게 연결된 identity 나 같은 provider 의 두 번째 identity 는 연결하지 않음
+    pub async fn link_identity(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        idp: OAuthProvider,
+        idp_uid: String,
+    ) -> Result<LinkIdentityResult, DbErr> {
+        if let Some(identity) = self
+            .get_identity_by_idp_and_idp_uid(realm, idp.clone(), idp_uid.clone())
+            .await?
+        {
+            if identity.user_id == user_id {
+                return Ok(LinkIdentityResult::AlreadyLinked);
+            }
+            return Ok(LinkIdentityResult::LinkedToOtherUser);
+        }
+
+        if self
+            .get_identity_by_user_id_and_idp(user_id, idp.clone())
+            .await?
+            .is_some()
+        {
+            return Ok(LinkIdentityResult::ProviderAlreadyLinked);
+        }
+
+        self.create_identity(realm, user_id, idp.clone(), idp_uid)
+            .await?;
+        WebhooksRepo::new(self.conn)
+            .enqueue_event(
+                realm,
+                WebhookEventType::IdentityLinked,
+                WebhookUserData {
+                    idp: Some(idp.as_str().to_string()),
+                    ..WebhookUserData::new(user_id)
+                },
+            )
+            .await?;
+        Ok(LinkIdentityResult::Linked)
+    }
+
+    /// 같은 provider 계정이라도 realm 이 다르면 다른 identity
+    pub async fn get_identity_by_idp_and_idp_uid(
+        &self,
+        realm: &str,
+        idp: OAuthProvider,
+        idp_uid: String,
+    ) -> Result<Option<user_identities::Model>, DbErr> {
+        user_identities::Entity::find()
+            .filter(user_identities::Column::Realm.eq(realm))
+            .filter(
+                user_identities::Column::Idp
+                    .eq(idp.as_str())
+                    .and(user_identities::Column::IdpUid.eq(idp_uid)),
+            )
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn get_identity_by_user_id_and_idp(
+        &self,
+        user_id: Uuid,
+        idp: OAuthProvider,
+    ) -> Result<Option<user_identities::Model>, DbErr> {
+        user_identities::Entity::find()
+            .filter(
+                user_identities::Column::UserId
+                    .eq(user_id)
+                    .and(user_identities::Column::Idp.eq(idp.as_str())),
+            )
+            .one(self.conn)
+            .await
+    }
+
+    pub async fn list_identities_by_user_id(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<user_identities::Model>, DbErr> {
+        user_identities::Entity::find()
+            .filter(user_identities::Column::UserId.eq(user_id))
+            .order_by_asc(user_identities::Column::CreatedAt)
+            .all(self.conn)
+            .await
+    }
+
+    pub async fn count_identities_by_user_id(&self, user_id: Uuid) -> Result<u64, DbErr> {
+        user_identities::Entity::find()
+            .filter(user_identities::Column::UserId.eq(user_id))
+            .count(self.conn)
+            .await
+    }
+
+    pub async fn touch_identity(
+        &self,
+        identity: user_identities::Model,
+    ) -> Result<user_identities::Model, DbErr> {
+        let mut active_identity: user_identities::ActiveModel = identity.into();
+        active_identity.updated_at = Set(chrono::Utc::now().into());
+        active_identity.update(self.conn).await
+    }
+
+    pub async fn delete_identity(&self, identity_id: Uuid) -> Result<u64, DbErr> {
+        let result = user_identities::Entity::delete_by_id(identity_id)
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected)
+    }
+}
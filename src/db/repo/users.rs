@@ -1,10 +1,20 @@
 use sea_orm::{
-    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
-    QueryFilter,
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition, ConnectionTrait, DbErr,
+    EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, SqlErr, TransactionTrait,
+    prelude::DateTimeWithTimeZone,
 };
+use tracing::warn;
 use uuid::Uuid;
 
-use crate::{entity::users, provider::types::idp::OAuthProvider};
+use crate::{
+    api::types::webhook::{WebhookEventType, WebhookUserData},
+    db::repo::{user_identities::UserIdentitiesRepo, webhooks::WebhooksRepo},
+    entity::users,
+    provider::types::{idp::OAuthProvider, profile::UserProfile},
+};
+
+/// 확인한 뒤 다른 사용자가 같은 username/email 을 먼저 저장했을 때 다시 고르는 횟수
+const UNIQUE_VIOLATION_RETRIES: usize = 3;
 
 pub struct UsersRepo<'a, C: ConnectionTrait> {
     pub conn: &'a C,
@@ -15,45 +25,336 @@ impl<'a, C: ConnectionTrait> UsersRepo<'a, C> {
         Self { conn }
     }
 
-    pub async fn get_or_create_user_if_not_exist(
+    /// 첫 로그인 시 사용자와 identity 를 만들고, 이후 로그인마다 provider 프로필로 갱신
+    pub async fn upsert_user_by_profile(
         &self,
+        realm: &str,
         idp: OAuthProvider,
-        idp_uid: String,
-    ) -> Result<users::Model, DbErr> {
-        let existing_user = self
-            .get_user_by_idp_and_idp_uid(idp.clone(), idp_uid.clone())
+        profile: UserProfile,
+    ) -> Result<users::Model, DbErr>
+    where
+        C: TransactionTrait,
+    {
+        let identities_repo = UserIdentitiesRepo::new(self.conn);
+        let existing_identity = identities_repo
+            .get_identity_by_idp_and_idp_uid(realm, idp.clone(), profile.idp_uid.clone())
             .await?;
 
-        if let Some(user) = existing_user {
-            return Ok(user);
-        }
-
-        let now = chrono::Utc::now().into();
-        let new_user = users::ActiveModel {
-            id: Set(Uuid::now_v7()),
-            username: Set(None),
-            email: Set(None),
-            is_active: Set(true),
-            idp: Set(idp.as_str().to_string()),
-            idp_uid: Set(idp_uid),
-            created_at: Set(now),
-            updated_at: Set(now),
+        let Some(identity) = existing_identity else {
+            return self.create_user_with_identity(realm, idp, profile).await;
         };
-        new_user.insert(self.conn).await
+
+        let user = self
+            .get_user_by_id(identity.user_id)
+            .await?
+            .ok_or_else(|| {
+                DbErr::RecordNotFound(format!("user of identity {} is not found", identity.id))
+            })?;
+        identities_repo.touch_identity(identity).await?;
+        self.update_user_profile(user, &idp, profile).await
     }
 
-    pub async fn get_user_by_idp_and_idp_uid(
+    async fn create_user_with_identity(
         &self,
+        realm: &str,
         idp: OAuthProvider,
-        idp_uid: String,
+        profile: UserProfile,
+    ) -> Result<users::Model, DbErr>
+    where
+        C: TransactionTrait,
+    {
+        let user_id = Uuid::now_v7();
+        let mut attempt = 1;
+        let user = loop {
+            let username = self
+                .resolve_username(realm, user_id, &idp, profile.login.clone())
+                .await?;
+            let email = self
+                .resolve_email(realm, user_id, profile.email.clone())
+                .await?;
+            let now = chrono::Utc::now().into();
+
+            let new_user = users::ActiveModel {
+                id: Set(user_id),
+                realm: Set(realm.to_string()),
+                username: Set(username),
+                email: Set(email),
+                display_name: Set(profile.display_name.clone()),
+                avatar_url: Set(profile.avatar_url.clone()),
+                is_active: Set(true),
+                suspended_at: Set(None),
+                suspended_until: Set(None),
+                suspension_reason: Set(None),
+                created_at: Set(now),
+                updated_at: Set(now),
+            };
+            // 실패해도 바깥 트랜잭션을 쓸 수 있도록 savepoint 안에서 저장
+            let savepoint = self.conn.begin().await?;
+            match new_user.insert(&savepoint).await {
+                Ok(user) => {
+                    savepoint.commit().await?;
+                    break user;
+                }
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    if !is_unique_violation(&err) || attempt >= UNIQUE_VIOLATION_RETRIES {
+                        return Err(err);
+                    }
+                    warn!(
+                        "username or email is taken while creating user, retry: {}",
+                        err
+                    );
+                    attempt += 1;
+                }
+            }
+        };
+
+        UserIdentitiesRepo::new(self.conn)
+            .create_identity(realm, user.id, idp.clone(), profile.idp_uid)
+            .await?;
+        WebhooksRepo::new(self.conn)
+            .enqueue_event(
+                realm,
+                WebhookEventType::UserRegistered,
+                WebhookUserData {
+                    idp: Some(idp.as_str().to_string()),
+                    email: user.email.clone(),
+                    username: user.username.clone(),
+                    ..WebhookUserData::new(user.id)
+                },
+            )
+            .await?;
+        Ok(user)
+    }
+
+    async fn update_user_profile(
+        &self,
+        user: users::Model,
+        idp: &OAuthProvider,
+        profile: UserProfile,
+    ) -> Result<users::Model, DbErr>
+    where
+        C: TransactionTrait,
+    {
+        let mut attempt = 1;
+        loop {
+            let username = self
+                .resolve_username(&user.realm, user.id, idp, profile.login.clone())
+                .await?
+                .or(user.username.clone());
+            let email = self
+                .resolve_email(&user.realm, user.id, profile.email.clone())
+                .await?
+                .or(user.email.clone());
+
+            let mut active_user: users::ActiveModel = user.clone().into();
+            active_user.username = Set(username);
+            active_user.email = Set(email);
+            active_user.display_name = Set(profile.display_name.clone());
+            active_user.avatar_url = Set(profile.avatar_url.clone());
+            active_user.updated_at = Set(chrono::Utc::now().into());
+
+            let savepoint = self.conn.begin().await?;
+            match active_user.update(&savepoint).await {
+                Ok(user) => {
+                    savepoint.commit().await?;
+                    return Ok(user);
+                }
+                Err(err) => {
+                    savepoint.rollback().await?;
+                    if !is_unique_violation(&err) || attempt >= UNIQUE_VIOLATION_RETRIES {
+                        return Err(err);
+                    }
+                    warn!(
+                        "username or email is taken while updating user, retry: {}",
+                        err
+                    );
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    pub async fn get_user_by_id(&self, user_id: Uuid) -> Result<Option<users::Model>, DbErr> {
+        users::Entity::find_by_id(user_id).one(self.conn).await
+    }
+
+    pub async fn get_user_by_email(
+        &self,
+        realm: &str,
+        email: &str,
     ) -> Result<Option<users::Model>, DbErr> {
         users::Entity::find()
-            .filter(
-                users::Column::Idp
-                    .eq(idp.as_str())
-                    .and(users::Column::IdpUid.eq(idp_uid)),
-            )
+            .filter(users::Column::Realm.eq(realm))
+            .filter(users::Column::Email.eq(email))
             .one(self.conn)
             .await
     }
+
+    /// 관리자용 사용자 목록. `query` 는 username, email, display name 에서 부분 일치로 검색
+    pub async fn list_users(
+        &self,
+        realm: &str,
+        page: u64,
+        per_page: u64,
+        query: Option<&str>,
+    ) -> Result<(Vec<users::Model>, u64), DbErr> {
+        let mut select = users::Entity::find()
+            .filter(users::Column::Realm.eq(realm))
+            .order_by_asc(users::Column::CreatedAt);
+        if let Some(query) = query {
+            select = select.filter(
+                Condition::any()
+                    .add(users::Column::Username.contains(query))
+                    .add(users::Column::Email.contains(query))
+                    .add(users::Column::DisplayName.contains(query)),
+            );
+        }
+
+        let paginator = select.paginate(self.conn, per_page);
+        let total = paginator.num_items().await?;
+        let users = paginator.fetch_page(page).await?;
+        Ok((users, total))
+    }
+
+    pub async fn set_user_active(
+        &self,
+        user: users::Model,
+        is_active: bool,
+    ) -> Result<users::Model, DbErr> {
+        let was_active = user.is_active;
+        let mut active_user: users::ActiveModel = user.into();
+        active_user.is_active = Set(is_active);
+        active_user.updated_at = Set(chrono::Utc::now().into());
+        let user = active_user.update(self.conn).await?;
+
+        if was_active && !is_active {
+            WebhooksRepo::new(self.conn)
+                .enqueue_event(
+                    &user.realm,
+                    WebhookEventType::UserDeactivated,
+                    WebhookUserData::new(user.id),
+                )
+                .await?;
+        }
+        Ok(user)
+    }
+
+    pub async fn suspend_user(
+        &self,
+        user: users::Model,
+        reason: String,
+        until: Option<DateTimeWithTimeZone>,
+    ) -> Result<users::Model, DbErr> {
+        let now = chrono::Utc::now().into();
+        let mut active_user: users::ActiveModel = user.into();
+        active_user.suspended_at = Set(Some(now));
+        active_user.suspended_until = Set(until);
+        active_user.suspension_reason = Set(Some(reason));
+        active_user.updated_at = Set(now);
+        active_user.update(self.conn).await
+    }
+
+    pub async fn lift_user_suspension(&self, user: users::Model) -> Result<users::Model, DbErr> {
+        let mut active_user: users::ActiveModel = user.into();
+        active_user.suspended_at = Set(None);
+        active_user.suspended_until = Set(None);
+        active_user.suspension_reason = Set(None);
+        active_user.updated_at = Set(chrono::Utc::now().into());
+        active_user.update(self.conn).await
+    }
+
+    pub async fn delete_user(&self, user_id: Uuid) -> Result<u64, DbErr> {
+        let Some(user) = self.get_user_by_id(user_id).await? else {
+            return Ok(0);
+        };
+        let result = users::Entity::delete_by_id(user_id).exec(self.conn).await?;
+        if result.rows_affected == 0 {
+            return Ok(0);
+        }
+
+        WebhooksRepo::new(self.conn)
+            .enqueue_event(
+                &user.realm,
+                WebhookEventType::UserDeleted,
+                WebhookUserData {
+                    email: user.email,
+                    username: user.username,
+                    ..WebhookUserData::new(user.id)
+                },
+            )
+            .await?;
+        Ok(result.rows_affected)
+    }
+
+    /// provider login 을 그대로 쓰되, 다른 사용자가 선점했으면 `{login}-{idp}` 로 대체
+    async fn resolve_username(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        idp: &OAuthProvider,
+        login: Option<String>,
+    ) -> Result<Option<String>, DbErr> {
+        let Some(login) = login else {
+            return Ok(None);
+        };
+
+        let candidates = [login.clone(), format!("{}-{}", login, idp.as_str())];
+        for candidate in candidates {
+            if !self
+                .is_taken_by_other(realm, users::Column::Username, &candidate, user_id)
+                .await?
+            {
+                return Ok(Some(candidate));
+            }
+        }
+
+        warn!(
+            "username {} is already taken, skip updating username",
+            login
+        );
+        Ok(None)
+    }
+
+    /// 다른 사용자가 같은 email 을 쓰고 있으면 저장하지 않음
+    async fn resolve_email(
+        &self,
+        realm: &str,
+        user_id: Uuid,
+        email: Option<String>,
+    ) -> Result<Option<String>, DbErr> {
+        let Some(email) = email else {
+            return Ok(None);
+        };
+
+        if self
+            .is_taken_by_other(realm, users::Column::Email, &email, user_id)
+            .await?
+        {
+            warn!("email is already used by another user, skip updating email");
+            return Ok(None);
+        }
+
+        Ok(Some(email))
+    }
+
+    async fn is_taken_by_other(
+        &self,
+        realm: &str,
+        column: users::Column,
+        value: &str,
+        user_id: Uuid,
+    ) -> Result<bool, DbErr> {
+        let count = users::Entity::find()
+            .filter(users::Column::Realm.eq(realm))
+            .filter(column.eq(value))
+            .filter(users::Column::Id.ne(user_id))
+            .count(self.conn)
+            .await?;
+        Ok(count > 0)
+    }
+}
+
+fn is_unique_violation(err: &DbErr) -> bool {
+    matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_)))
 }
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use crate::{
+        db::{connect::memory_connect, repo::users::UsersRepo},
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    fn profile(idp_uid: &str, login: &str, email: &str) -> UserProfile {
+        UserProfile {
+            idp_uid: idp_uid.to_string(),
+            login: Some(login.to_string()),
+            display_name: Some(login.to_string()),
+            email: Some(email.to_string()),
+            avatar_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upsert_user_by_profile_creates_then_updates() {
+        let db = memory_connect().await;
+        let repo = UsersRepo::new(&db);
+
+        let created = repo
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                profile("1", "octocat", "octocat@example.com"),
+            )
+            .await
+            .unwrap();
+        assert_eq!(created.username.as_deref(), Some("octocat"));
+        assert!(created.is_active);
+
+        let mut renamed = profile("1", "octocat", "octocat@example.com");
+        renamed.display_name = Some("The Octocat".to_string());
+        let updated = repo
+            .upsert_user_by_profile("default", OAuthProvider::Github, renamed)
+            .await
+            .unwrap();
+        assert_eq!(updated.id, created.id);
+        assert_eq!(updated.display_name.as_deref(), Some("The Octocat"));
+
+        let found = repo.get_user_by_id(created.id).await.unwrap().unwrap();
+        assert_eq!(found.display_name.as_deref(), Some("The Octocat"));
+    }
+
+    #[tokio::test]
+    async fn test_users_are_partitioned_by_realm() {
+        let db = memory_connect().await;
+        let repo = UsersRepo::new(&db);
+
+        let default_user = repo
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                profile("1", "octocat", "octocat@example.com"),
+            )
+            .await
+            .unwrap();
+        let shop_user = repo
+            .upsert_user_by_profile(
+                "shop",
+                OAuthProvider::Github,
+                profile("1", "octocat", "octocat@example.com"),
+            )
+            .await
+            .unwrap();
+        assert_ne!(default_user.id, shop_user.id);
+        assert_eq!(shop_user.username.as_deref(), Some("octocat"));
+
+        let found = repo
+            .get_user_by_email("shop", "octocat@example.com")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.id, shop_user.id);
+
+        let (users, total) = repo.list_users("default", 0, 10, None).await.unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(users[0].id, default_user.id);
+    }
+
+    #[tokio::test]
+    async fn test_list_users_query_and_delete() {
+        let db = memory_connect().await;
+        let repo = UsersRepo::new(&db);
+
+        let octocat = repo
+            .upsert_user_by_profile(
+                "default",
+                OAuthProvider::Github,
+                profile("1", "octocat", "octocat@example.com"),
+            )
+            .await
+            .unwrap();
+        repo.upsert_user_by_profile(
+            "default",
+            OAuthProvider::Github,
+            profile("2", "hubot", "hubot@example.com"),
+        )
+        .await
+        .unwrap();
+
+        let (users, total) = repo
+            .list_users("default", 0, 10, Some("octo"))
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(users[0].id, octocat.id);
+
+        assert_eq!(repo.delete_user(octocat.id).await.unwrap(), 1);
+        assert!(repo.get_user_by_id(octocat.id).await.unwrap().is_none());
+        let (_, total) = repo.list_users("default", 0, 10, None).await.unwrap();
+        assert_eq!(total, 1);
+    }
+}
@@ -0,0 +1,269 @@
+use sea_orm::{
+    ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait, DbErr, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, QueryTrait,
+    prelude::DateTimeWithTimeZone, sea_query::Expr,
+};
+use uuid::Uuid;
+
+use crate::{
+    api::types::webhook::{
+        WebhookDeliveryStatus, WebhookEventType, WebhookUserData, webhook_payload,
+    },
+    entity::{webhook_deliveries, webhook_events},
+};
+
+/// 전송 기록 조회 조건. 없는 값은 거르지 않음
+#[derive(Debug, Default)]
+pub struct WebhookDeliveryFilter {
+    pub status: Option<WebhookDeliveryStatus>,
+    pub endpoint: Option<String>,
+    pub event_type: Option<WebhookEventType>,
+}
+
+pub struct WebhooksRepo<'a, C: ConnectionTrait> {
+    pub conn: &'a C,
+}
+
+impl<'a, C: ConnectionTrait> WebhooksRepo<'a, C> {
+    pub fn new(conn: &'a C) -> Self {
+        Self { conn }
+    }
+
+    /// 사용자 변경과 같은 연결(트랜잭션)에서 이벤트를 남김. 전송은 dispatcher 가 따로 함
+    pub async fn enqueue_event(
+        &self,
+        realm: &str,
+        event_type: WebhookEventType,
+        data: WebhookUserData,
+    ) -> Result<webhook_events::Model, DbErr> {
+        let id = Uuid::now_v7();
+        let now = chrono::Utc::now();
+        let payload = webhook_payload(id, event_type, realm, now.timestamp(), &data)
+            .map_err(|e| DbErr::Custom(format!("fail to serialize webhook payload: {}", e)))?;
+
+        let new_event = webhook_events::ActiveModel {
+            id: Set(id),
+            realm: Set(realm.to_string()),
+            event_type: Set(event_type.as_str().to_string()),
+            user_id: Set(data.user_id),
+            payload: Set(payload),
+            created_at: Set(now.into()),
+            dispatched_at: Set(None),
+        };
+        new_event.insert(self.conn).await
+    }
+
+    pub async fn get_event(&self, event_id: Uuid) -> Result<Option<webhook_events::Model>, DbErr> {
+        webhook_events::Entity::find_by_id(event_id)
+            .one(self.conn)
+            .await
+    }
+
+    /// 아직 endpoint 별 전송으로 나누지 않은 이벤트. 오래된 것부터
+    pub async fn list_undispatched_events(
+        &self,
+        limit: u64,
+    ) -> Result<Vec<webhook_events::Model>, DbErr> {
+        webhook_events::Entity::find()
+            .filter(webhook_events::Column::DispatchedAt.is_null())
+            .order_by_asc(webhook_events::Column::CreatedAt)
+            .limit(limit)
+            .all(self.conn)
+            .await
+    }
+
+    /// 이벤트를 받을 endpoint 마다 전송을 만듦. 다른 replica 가 먼저 나눴으면 false
+    pub async fn dispatch_event(
+        &self,
+        event: &webhook_events::Model,
+        endpoints: &[&str],
+    ) -> Result<bool, DbErr> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let claimed = webhook_events::Entity::update_many()
+            .col_expr(webhook_events::Column::DispatchedAt, Expr::value(now))
+            .filter(webhook_events::Column::Id.eq(event.id))
+            .filter(webhook_events::Column::DispatchedAt.is_null())
+            .exec(self.conn)
+            .await?;
+        if claimed.rows_affected == 0 {
+            return Ok(false);
+        }
+
+        for endpoint in endpoints {
+            let new_delivery = webhook_deliveries::ActiveModel {
+                id: Set(Uuid::now_v7()),
+                event_id: Set(event.id),
+                realm: Set(event.realm.clone()),
+                event_type: Set(event.event_type.clone()),
+                endpoint: Set(endpoint.to_string()),
+                status: Set(WebhookDeliveryStatus::Pending.as_str().to_string()),
+                attempts: Set(0),
+                next_attempt_at: Set(now),
+                last_status_code: Set(None),
+                last_error: Set(None),
+                created_at: Set(now),
+                updated_at: Set(now),
+                delivered_at: Set(None),
+            };
+            new_delivery.insert(self.conn).await?;
+        }
+        Ok(true)
+    }
+
+    /// 다시 보낼 시각이 된 전송. 오래된 것부터
+    pub async fn list_due_deliveries(
+        &self,
+        now: DateTimeWithTimeZone,
+        limit: u64,
+    ) -> Result<Vec<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Status.eq(WebhookDeliveryStatus::Pending.as_str()))
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(now))
+            .order_by_asc(webhook_deliveries::Column::NextAttemptAt)
+            .limit(limit)
+            .all(self.conn)
+            .await
+    }
+
+    /// 시도 횟수를 늘리고 `lease_until` 까지 다른 replica 가 가져가지 않게 함
+    /// 그 사이 다른 replica 가 먼저 가져갔으면 false
+    pub async fn claim_delivery(
+        &self,
+        delivery: &webhook_deliveries::Model,
+        lease_until: DateTimeWithTimeZone,
+    ) -> Result<bool, DbErr> {
+        let result = webhook_deliveries::Entity::update_many()
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::value(delivery.attempts + 1),
+            )
+            .col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(lease_until),
+            )
+            .filter(webhook_deliveries::Column::Id.eq(delivery.id))
+            .filter(webhook_deliveries::Column::Status.eq(WebhookDeliveryStatus::Pending.as_str()))
+            .filter(webhook_deliveries::Column::Attempts.eq(delivery.attempts))
+            .exec(self.conn)
+            .await?;
+        Ok(result.rows_affected == 1)
+    }
+
+    pub async fn mark_delivered(&self, delivery_id: Uuid, status_code: i32) -> Result<(), DbErr> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        webhook_deliveries::ActiveModel {
+            id: Set(delivery_id),
+            status: Set(WebhookDeliveryStatus::Delivered.as_str().to_string()),
+            last_status_code: Set(Some(status_code)),
+            last_error: Set(None),
+            updated_at: Set(now),
+            delivered_at: Set(Some(now)),
+            ..Default::default()
+        }
+        .update(self.conn)
+        .await?;
+        Ok(())
+    }
+
+    /// `next_attempt_at` 이 없으면 더 보내지 않음
+    pub async fn mark_failed(
+        &self,
+        delivery_id: Uuid,
+        status_code: Option<i32>,
+        error: String,
+        next_attempt_at: Option<DateTimeWithTimeZone>,
+    ) -> Result<(), DbErr> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let status = match next_attempt_at {
+            Some(_) => WebhookDeliveryStatus::Pending,
+            None => WebhookDeliveryStatus::Failed,
+        };
+        webhook_deliveries::ActiveModel {
+            id: Set(delivery_id),
+            status: Set(status.as_str().to_string()),
+            next_attempt_at: Set(next_attempt_at.unwrap_or(now)),
+            last_status_code: Set(status_code),
+            last_error: Set(Some(error)),
+            updated_at: Set(now),
+            ..Default::default()
+        }
+        .update(self.conn)
+        .await?;
+        Ok(())
+    }
+
+    pub async fn get_delivery(
+        &self,
+        delivery_id: Uuid,
+    ) -> Result<Option<webhook_deliveries::Model>, DbErr> {
+        webhook_deliveries::Entity::find_by_id(delivery_id)
+            .one(self.conn)
+            .await
+    }
+
+    /// 관리자용 전송 기록. 최근 것부터, `page` 는 0 부터 시작
+    pub async fn list_deliveries(
+        &self,
+        realm: &str,
+        filter: &WebhookDeliveryFilter,
+        page: u64,
+        per_page: u64,
+    ) -> Result<(Vec<webhook_deliveries::Model>, u64), DbErr> {
+        let mut select = webhook_deliveries::Entity::find()
+            .filter(webhook_deliveries::Column::Realm.eq(realm))
+            .order_by_desc(webhook_deliveries::Column::CreatedAt)
+            .order_by_desc(webhook_deliveries::Column::Id);
+        if let Some(status) = filter.status {
+            select = select.filter(webhook_deliveries::Column::Status.eq(status.as_str()));
+        }
+        if let Some(endpoint) = &filter.endpoint {
+            select = select.filter(webhook_deliveries::Column::Endpoint.eq(endpoint.as_str()));
+        }
+        if let Some(event_type) = filter.event_type {
+            select = select.filter(webhook_deliveries::Column::EventType.eq(event_type.as_str()));
+        }
+
+        let paginator = select.paginate(self.conn, per_page);
+        let total = paginator.num_items().await?;
+        let deliveries = paginator.fetch_page(page).await?;
+        Ok((deliveries, total))
+    }
+
+    /// 관리자가 실패한 전송을 처음부터 다시 시도
+    pub async fn retry_delivery(
+        &self,
+        delivery: webhook_deliveries::Model,
+    ) -> Result<webhook_deliveries::Model, DbErr> {
+        let now: DateTimeWithTimeZone = chrono::Utc::now().into();
+        let mut active_delivery: webhook_deliveries::ActiveModel = delivery.into();
+        active_delivery.status = Set(WebhookDeliveryStatus::Pending.as_str().to_string());
+        active_delivery.attempts = Set(0);
+        active_delivery.next_attempt_at = Set(now);
+        active_delivery.updated_at = Set(now);
+        active_delivery.update(self.conn).await
+    }
+
+    /// 보관 기간이 지난 끝난 전송과, 전송이 모두 끝나서 지워진 이벤트를 지움
+    /// 아직 보내는 중인 전송과 그 이벤트는 남김
+    pub async fn delete_expired(&self, before: DateTimeWithTimeZone) -> Result<u64, DbErr> {
+        let deliveries = webhook_deliveries::Entity::delete_many()
+            .filter(webhook_deliveries::Column::CreatedAt.lt(before))
+            .filter(webhook_deliveries::Column::Status.is_in([
+                WebhookDeliveryStatus::Delivered.as_str(),
+                WebhookDeliveryStatus::Failed.as_str(),
+            ]))
+            .exec(self.conn)
+            .await?;
+        let remaining_deliveries = webhook_deliveries::Entity::find()
+            .select_only()
+            .column(webhook_deliveries::Column::EventId)
+            .into_query();
+        let events = webhook_events::Entity::delete_many()
+            .filter(webhook_events::Column::CreatedAt.lt(before))
+            .filter(webhook_events::Column::DispatchedAt.is_not_null())
+            .filter(webhook_events::Column::Id.not_in_subquery(remaining_deliveries))
+            .exec(self.conn)
+            .await?;
+        Ok(deliveries.rows_affected + events.rows_affected)
+    }
+}
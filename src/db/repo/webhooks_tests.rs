@@ -0,0 +1,233 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use sonic_rs::JsonValueTrait;
+
+    use crate::{
+        api::types::webhook::{WebhookDeliveryStatus, WebhookEventType, WebhookUserData},
+        db::{
+            connect::memory_connect,
+            repo::{
+                user_identities::{LinkIdentityResult, UserIdentitiesRepo},
+                users::UsersRepo,
+                webhooks::{WebhookDeliveryFilter, WebhooksRepo},
+            },
+        },
+        provider::types::{idp::OAuthProvider, profile::UserProfile},
+    };
+
+    fn profile(idp_uid: &str, login: &str) -> UserProfile {
+        UserProfile {
+            idp_uid: idp_uid.to_string(),
+            login: Some(login.to_string()),
+            display_name: None,
+            email: Some(format!("{}@example.com", login)),
+            avatar_url: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_user_lifecycle_enqueues_webhook_events() {
+        let db = memory_connect().await;
+        let users_repo = UsersRepo::new(&db);
+        let identities_repo = UserIdentitiesRepo::new(&db);
+
+        let user = users_repo
+            .upsert_user_by_profile("default", OAuthProvider::Github, profile("1", "octocat"))
+            .await
+            .unwrap();
+        // 다시 로그인해도 가입 이벤트는 한 번만
+        users_repo
+            .upsert_user_by_profile("default", OAuthProvider::Github, profile("1", "octocat"))
+            .await
+            .unwrap();
+
+        let identity = identities_repo
+            .get_identity_by_user_id_and_idp(user.id, OAuthProvider::Github)
+            .await
+            .unwrap()
+            .unwrap();
+        identities_repo.delete_identity(identity.id).await.unwrap();
+        let linked = identities_repo
+            .link_identity("default", user.id, OAuthProvider::Github, "2".to_string())
+            .await
+            .unwrap();
+        assert!(matches!(linked, LinkIdentityResult::Linked));
+
+        let user = users_repo.set_user_active(user, false).await.unwrap();
+        // 이미 비활성인 사용자는 다시 알리지 않음
+        let user = users_repo.set_user_active(user, false).await.unwrap();
+        assert_eq!(users_repo.delete_user(user.id).await.unwrap(), 1);
+        assert_eq!(users_repo.delete_user(user.id).await.unwrap(), 0);
+
+        let events = WebhooksRepo::new(&db)
+            .list_undispatched_events(10)
+            .await
+            .unwrap();
+        let event_types: Vec<&str> = events
+            .iter()
+            .map(|event| event.event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            [
+                "user.registered",
+                "identity.linked",
+                "user.deactivated",
+                "user.deleted"
+            ]
+        );
+        assert!(events.iter().all(|event| event.user_id == user.id));
+
+        let registered: sonic_rs::Value = sonic_rs::from_str(&events[0].payload).unwrap();
+        assert_eq!(registered["data"]["idp"].as_str(), Some("github"));
+        assert_eq!(
+            registered["data"]["email"].as_str(),
+            Some("octocat@example.com")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_claim_and_retry_deliveries() {
+        let db = memory_connect().await;
+        let repo = WebhooksRepo::new(&db);
+        let user_id = uuid::Uuid::now_v7();
+
+        let event = repo
+            .enqueue_event(
+                "default",
+                WebhookEventType::UserDeleted,
+                WebhookUserData::new(user_id),
+            )
+            .await
+            .unwrap();
+        assert!(
+            repo.dispatch_event(&event, &["crm", "audit"])
+                .await
+                .unwrap()
+        );
+        // 다른 replica 가 이미 나눈 이벤트
+        assert!(
+            !repo
+                .dispatch_event(&event, &["crm", "audit"])
+                .await
+                .unwrap()
+        );
+        assert!(repo.list_undispatched_events(10).await.unwrap().is_empty());
+
+        let now = chrono::Utc::now();
+        let due = repo.list_due_deliveries(now.into(), 10).await.unwrap();
+        assert_eq!(due.len(), 2);
+
+        let lease_until = now + Duration::from_secs(60);
+        let crm = due.iter().find(|d| d.endpoint == "crm").unwrap().clone();
+        let audit = due.iter().find(|d| d.endpoint == "audit").unwrap().clone();
+        assert!(repo.claim_delivery(&crm, lease_until.into()).await.unwrap());
+        assert!(!repo.claim_delivery(&crm, lease_until.into()).await.unwrap());
+        assert!(
+            repo.claim_delivery(&audit, lease_until.into())
+                .await
+                .unwrap()
+        );
+        assert!(
+            repo.list_due_deliveries(now.into(), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        repo.mark_delivered(crm.id, 204).await.unwrap();
+        repo.mark_failed(
+            audit.id,
+            Some(500),
+            "endpoint responded with 500".to_string(),
+            Some((now + Duration::from_secs(30)).into()),
+        )
+        .await
+        .unwrap();
+        let audit = repo.get_delivery(audit.id).await.unwrap().unwrap();
+        assert_eq!(audit.status, "pending");
+        assert_eq!(audit.attempts, 1);
+        assert_eq!(audit.last_status_code, Some(500));
+        assert!(
+            repo.list_due_deliveries(now.into(), 10)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        repo.mark_failed(audit.id, None, "timed out".to_string(), None)
+            .await
+            .unwrap();
+        let (failed, total) = repo
+            .list_deliveries(
+                "default",
+                &WebhookDeliveryFilter {
+                    status: Some(WebhookDeliveryStatus::Failed),
+                    ..Default::default()
+                },
+                0,
+                20,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert_eq!(failed[0].endpoint, "audit");
+        assert_eq!(failed[0].last_error.as_deref(), Some("timed out"));
+
+        let retried = repo.retry_delivery(failed[0].clone()).await.unwrap();
+        assert_eq!(retried.status, "pending");
+        assert_eq!(retried.attempts, 0);
+        let due = repo
+            .list_due_deliveries(chrono::Utc::now().into(), 10)
+            .await
+            .unwrap();
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].id, audit.id);
+
+        let (_, total) = repo
+            .list_deliveries("shop", &WebhookDeliveryFilter::default(), 0, 20)
+            .await
+            .unwrap();
+        assert_eq!(total, 0);
+    }
+
+    #[tokio::test]
+    async fn test_delete_expired_keeps_unfinished_events_and_deliveries() {
+        let db = memory_connect().await;
+        let repo = WebhooksRepo::new(&db);
+
+        let dispatched = repo
+            .enqueue_event(
+                "default",
+                WebhookEventType::UserRegistered,
+                WebhookUserData::new(uuid::Uuid::now_v7()),
+            )
+            .await
+            .unwrap();
+        repo.dispatch_event(&dispatched, &["crm"]).await.unwrap();
+        let undispatched = repo
+            .enqueue_event(
+                "default",
+                WebhookEventType::UserRegistered,
+                WebhookUserData::new(uuid::Uuid::now_v7()),
+            )
+            .await
+            .unwrap();
+
+        // 아직 보내는 중인 전송과 그 이벤트는 보관 기간이 지나도 남김
+        let before = chrono::Utc::now() + Duration::from_secs(1);
+        assert_eq!(repo.delete_expired(before.into()).await.unwrap(), 0);
+        assert!(repo.get_event(dispatched.id).await.unwrap().is_some());
+
+        let deliveries = repo.list_due_deliveries(before.into(), 10).await.unwrap();
+        assert_eq!(deliveries.len(), 1);
+        repo.mark_delivered(deliveries[0].id, 200).await.unwrap();
+
+        assert_eq!(repo.delete_expired(before.into()).await.unwrap(), 2);
+        assert!(repo.get_event(dispatched.id).await.unwrap().is_none());
+        assert!(repo.get_delivery(deliveries[0].id).await.unwrap().is_none());
+        assert!(repo.get_event(undispatched.id).await.unwrap().is_some());
+    }
+}
@@ -2,7 +2,29 @@
 
 pub mod prelude;
 
+pub mod audit_events;
+pub mod backchannel_logouts;
+pub mod group_members;
+pub mod groups;
+pub mod organization_invitations;
+pub mod organization_members;
+pub mod organizations;
+pub mod permissions;
+pub mod refresh_tokens;
+pub mod role_permissions;
+pub mod roles;
+pub mod session_clients;
+pub mod sessions;
+pub mod state_entries;
+pub mod user_identities;
+pub mod user_roles;
 pub mod users;
+pub mod webhook_deliveries;
+pub mod webhook_events;
 
+#[cfg(test)]
+pub mod roles_tests;
+#[cfg(test)]
+pub mod user_identities_tests;
 #[cfg(test)]
 pub mod users_tests;
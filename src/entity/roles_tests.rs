@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    use crate::entity::roles::Model;
+
+    #[test]
+    fn test_role_model_creation() {
+        let role_id = Uuid::now_v7();
+        let now: DateTime<Utc> = Utc::now();
+        let role = Model {
+            id: role_id,
+            realm: "default".to_string(),
+            name: "editor".to_string(),
+            audience: Some("all-for-one".to_string()),
+            description: None,
+            created_at: now.into(),
+        };
+
+        assert_eq!(role.id, role_id);
+        assert_eq!(role.name, "editor");
+        assert_eq!(role.audience, Some("all-for-one".to_string()));
+    }
+
+    #[test]
+    fn test_role_model_deserialization_without_audience() {
+        let role_id = Uuid::now_v7();
+        let now = Utc::now();
+
+        let json = format!(
+            r#"{{
+                "id": "{}",
+                "realm": "default",
+                "name": "viewer",
+                "audience": null,
+                "description": "read only",
+                "created_at": "{}"
+            }}"#,
+            role_id,
+            now.to_rfc3339()
+        );
+
+        let role: Model = sonic_rs::from_str(&json).unwrap();
+        assert_eq!(role.id, role_id);
+        assert_eq!(role.audience, None);
+        assert_eq!(role.description, Some("read only".to_string()));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use chrono::{DateTime, Utc};
+    use uuid::Uuid;
+
+    use crate::entity::user_identities::Model;
+
+    #[test]
+    fn test_user_identity_model_creation() {
+        let identity_id = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        let now: DateTime<Utc> = Utc::now();
+        let identity = Model {
+            id: identity_id,
+            user_id,
+            realm: "default".to_string(),
+            idp: "github".to_string(),
+            idp_uid: "12345".to_string(),
+            created_at: now.into(),
+            updated_at: now.into(),
+        };
+
+        assert_eq!(identity.id, identity_id);
+        assert_eq!(identity.user_id, user_id);
+        assert_eq!(identity.idp, "github");
+        assert_eq!(identity.idp_uid, "12345");
+    }
+
+    #[test]
+    fn test_user_identity_model_serialization() {
+        let user_id = Uuid::now_v7();
+        let now: DateTime<Utc> = Utc::now();
+        let identity = Model {
+            id: Uuid::now_v7(),
+            user_id,
+            realm: "default".to_string(),
+            idp: "github".to_string(),
+            idp_uid: "12345".to_string(),
+            created_at: now.into(),
+            updated_at: now.into(),
+        };
+
+        let serialized = sonic_rs::to_string(&identity).unwrap();
+        assert!(serialized.contains(&user_id.to_string()));
+        assert!(serialized.contains("github"));
+        assert!(serialized.contains("12345"));
+    }
+
+    #[test]
+    fn test_user_identity_model_deserialization() {
+        let identity_id = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        let now = Utc::now();
+
+        let json = format!(
+            r#"{{
+                "id": "{}",
+                "user_id": "{}",
+                "realm": "default",
+                "idp": "github",
+                "idp_uid": "12345",
+                "created_at": "{}",
+                "updated_at": "{}"
+            }}"#,
+            identity_id,
+            user_id,
+            now.to_rfc3339(),
+            now.to_rfc3339()
+        );
+
+        let identity: Model = sonic_rs::from_str(&json).unwrap();
+        assert_eq!(identity.id, identity_id);
+        assert_eq!(identity.user_id, user_id);
+        assert_eq!(identity.idp, "github");
+        assert_eq!(identity.idp_uid, "12345");
+    }
+}
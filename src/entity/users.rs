@@ -8,22 +8,134 @@ use sonic_rs::{Deserialize, Serialize};
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
     pub id: Uuid,
-    #[sea_orm(unique)]
+    /// username, email 은 realm 안에서만 유일
+    pub realm: String,
     pub username: Option<String>,
-    #[sea_orm(unique)]
     pub email: Option<String>,
+    pub display_name: Option<String>,
+    pub avatar_url: Option<String>,
     pub is_active: bool,
+    pub suspended_at: Option<DateTimeWithTimeZone>,
+    pub suspended_until: Option<DateTimeWithTimeZone>,
+    pub suspension_reason: Option<String>,
     pub created_at: DateTimeWithTimeZone,
     pub updated_at: DateTimeWithTimeZone,
-    pub idp: String,
-    pub idp_uid: String,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
-pub enum Relation {}
+pub enum Relation {
+    #[sea_orm(has_many = "super::group_members::Entity")]
+    GroupMembers,
+    #[sea_orm(has_many = "super::organization_invitations::Entity")]
+    OrganizationInvitations,
+    #[sea_orm(has_many = "super::organization_members::Entity")]
+    OrganizationMembers,
+    #[sea_orm(has_many = "super::refresh_tokens::Entity")]
+    RefreshTokens,
+    #[sea_orm(has_many = "super::sessions::Entity")]
+    Sessions,
+    #[sea_orm(has_many = "super::user_identities::Entity")]
+    UserIdentities,
+    #[sea_orm(has_many = "super::user_roles::Entity")]
+    UserRoles,
+}
+
+impl Related<super::group_members::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::GroupMembers.def()
+    }
+}
+
+impl Related<super::organization_invitations::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrganizationInvitations.def()
+    }
+}
+
+impl Related<super::organization_members::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::OrganizationMembers.def()
+    }
+}
+
+impl Related<super::refresh_tokens::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RefreshTokens.def()
+    }
+}
+
+impl Related<super::sessions::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Sessions.def()
+    }
+}
+
+impl Related<super::user_identities::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserIdentities.def()
+    }
+}
+
+impl Related<super::user_roles::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::UserRoles.def()
+    }
+}
+
+impl Related<super::roles::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::user_roles::Relation::Roles.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::user_roles::Relation::Users.def().rev())
+    }
+}
+
+impl Related<super::organizations::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::organization_members::Relation::Organizations.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::organization_members::Relation::Users.def().rev())
+    }
+}
+
+impl Related<super::groups::Entity> for Entity {
+    fn to() -> RelationDef {
+        super::group_members::Relation::Groups.def()
+    }
+    fn via() -> Option<RelationDef> {
+        Some(super::group_members::Relation::Users.def().rev())
+    }
+}
 
 impl ActiveModelBehavior for ActiveModel {}
 
+impl Model {
+    /// 로그인/토큰 발급을 막아야 하는 이유. 사용 가능한 계정이면 `None`
+    pub fn sign_in_denial_reason(&self, now: DateTimeWithTimeZone) -> Option<String> {
+        if !self.is_active {
+            return Some("account is inactive".to_string());
+        }
+
+        // 정지된 적 없는 계정
+        self.suspended_at?;
+
+        match self.suspended_until {
+            Some(until) if until <= now => None,
+            Some(until) => Some(format!(
+                "account is suspended until {}: {}",
+                until.to_rfc3339(),
+                self.suspension_reason.as_deref().unwrap_or("no reason")
+            )),
+            None => Some(format!(
+                "account is suspended: {}",
+                self.suspension_reason.as_deref().unwrap_or("no reason")
+            )),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -36,21 +148,23 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         assert_eq!(user.id, user_id);
         assert_eq!(user.username, Some("testuser".to_string()));
         assert_eq!(user.email, Some("test@example.com".to_string()));
         assert!(user.is_active);
-        assert_eq!(user.idp, "github");
-        assert_eq!(user.idp_uid, "12345");
     }
 
     #[test]
@@ -59,13 +173,17 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: None,
             email: None,
+            display_name: None,
+            avatar_url: None,
             is_active: false,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "67890".to_string(),
         };
 
         assert_eq!(user.username, None);
@@ -79,20 +197,22 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let serialized = sonic_rs::to_string(&user).unwrap();
         assert!(serialized.contains("testuser"));
         assert!(serialized.contains("test@example.com"));
-        assert!(serialized.contains("github"));
-        assert!(serialized.contains("12345"));
     }
 
     #[test]
@@ -102,24 +222,32 @@ mod tests {
 
         let user1 = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let user2 = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         assert_eq!(user1, user2);
@@ -132,17 +260,20 @@ mod tests {
 
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let debug_str = format!("{:?}", user);
         assert!(debug_str.contains("testuser"));
-        assert!(debug_str.contains("github"));
     }
 }
@@ -11,21 +11,23 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         assert_eq!(user.id, user_id);
         assert_eq!(user.username, Some("testuser".to_string()));
         assert_eq!(user.email, Some("test@example.com".to_string()));
         assert!(user.is_active);
-        assert_eq!(user.idp, "github");
-        assert_eq!(user.idp_uid, "12345");
     }
 
     #[test]
@@ -34,13 +36,17 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: None,
             email: None,
+            display_name: None,
+            avatar_url: None,
             is_active: false,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "67890".to_string(),
         };
 
         assert_eq!(user.username, None);
@@ -54,20 +60,22 @@ mod tests {
         let now: DateTime<Utc> = Utc::now();
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let serialized = sonic_rs::to_string(&user).unwrap();
         assert!(serialized.contains("testuser"));
         assert!(serialized.contains("test@example.com"));
-        assert!(serialized.contains("github"));
-        assert!(serialized.contains("12345"));
     }
 
     #[test]
@@ -78,13 +86,14 @@ mod tests {
         let json = format!(
             r#"{{
                 "id": "{}",
+                "realm": "default",
                 "username": "testuser",
                 "email": "test@example.com",
                 "is_active": true,
                 "created_at": "{}",
                 "updated_at": "{}",
-                "idp": "github",
-                "idp_uid": "12345"
+                "display_name": "Test User",
+                "avatar_url": null
             }}"#,
             user_id,
             now.to_rfc3339(),
@@ -95,9 +104,9 @@ mod tests {
         assert_eq!(user.id, user_id);
         assert_eq!(user.username, Some("testuser".to_string()));
         assert_eq!(user.email, Some("test@example.com".to_string()));
+        assert_eq!(user.display_name, Some("Test User".to_string()));
+        assert_eq!(user.avatar_url, None);
         assert!(user.is_active);
-        assert_eq!(user.idp, "github");
-        assert_eq!(user.idp_uid, "12345");
     }
 
     #[test]
@@ -107,24 +116,32 @@ mod tests {
 
         let user1 = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let user2 = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         assert_eq!(user1, user2);
@@ -137,17 +154,87 @@ mod tests {
 
         let user = Model {
             id: user_id,
+            realm: "default".to_string(),
             username: Some("testuser".to_string()),
             email: Some("test@example.com".to_string()),
+            display_name: Some("Test User".to_string()),
+            avatar_url: None,
             is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
             created_at: now.into(),
             updated_at: now.into(),
-            idp: "github".to_string(),
-            idp_uid: "12345".to_string(),
         };
 
         let debug_str = format!("{:?}", user);
         assert!(debug_str.contains("testuser"));
-        assert!(debug_str.contains("github"));
+    }
+
+    fn active_user() -> Model {
+        let now: DateTime<Utc> = Utc::now();
+        Model {
+            id: Uuid::now_v7(),
+            realm: "default".to_string(),
+            username: None,
+            email: None,
+            display_name: None,
+            avatar_url: None,
+            is_active: true,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
+            created_at: now.into(),
+            updated_at: now.into(),
+        }
+    }
+
+    #[test]
+    fn test_sign_in_denial_reason_active_user() {
+        let user = active_user();
+        assert_eq!(user.sign_in_denial_reason(Utc::now().into()), None);
+    }
+
+    #[test]
+    fn test_sign_in_denial_reason_inactive_user() {
+        let mut user = active_user();
+        user.is_active = false;
+
+        let reason = user.sign_in_denial_reason(Utc::now().into()).unwrap();
+        assert!(reason.contains("inactive"));
+    }
+
+    #[test]
+    fn test_sign_in_denial_reason_suspended_without_expiry() {
+        let mut user = active_user();
+        user.suspended_at = Some(Utc::now().into());
+        user.suspension_reason = Some("spam".to_string());
+
+        let reason = user.sign_in_denial_reason(Utc::now().into()).unwrap();
+        assert!(reason.contains("suspended"));
+        assert!(reason.contains("spam"));
+    }
+
+    #[test]
+    fn test_sign_in_denial_reason_suspension_until_future() {
+        let now = Utc::now();
+        let mut user = active_user();
+        user.suspended_at = Some(now.into());
+        user.suspended_until = Some((now + chrono::Duration::hours(1)).into());
+
+        let reason = user.sign_in_denial_reason(now.into()).unwrap();
+        assert!(reason.contains("suspended until"));
+        assert!(reason.contains("no reason"));
+    }
+
+    #[test]
+    fn test_sign_in_denial_reason_suspension_expired() {
+        let now = Utc::now();
+        let mut user = active_user();
+        user.suspended_at = Some((now - chrono::Duration::hours(2)).into());
+        user.suspended_until = Some((now - chrono::Duration::hours(1)).into());
+        user.suspension_reason = Some("spam".to_string());
+
+        assert_eq!(user.sign_in_denial_reason(now.into()), None);
     }
 }
@@ -1,10 +1,13 @@
 use anyhow::Result;
+use sea_orm_migration::MigratorTrait;
 use tracing::info;
 
 use crate::{
     config::read::read_config,
-    db::connect::postgres_connect,
-    memcached::connect::memcached_connect,
+    db::{
+        connect::database_connect,
+        migration::{Migrator, run_migrate_command},
+    },
     utils::{logger::init_logger, types::HTTP_REQUEST_USER_AGENT},
 };
 
@@ -14,6 +17,7 @@ mod db;
 mod entity;
 mod memcached;
 mod provider;
+mod state_store;
 mod utils;
 
 #[tokio::main]
@@ -25,13 +29,23 @@ async fn main() -> Result<()> {
     init_logger(&config)?;
     info!("logger initialized");
 
-    memcached_connect(&config)?;
-    info!("memcached connected");
+    let db = database_connect(&config).await?;
+    info!("database connected");
 
-    postgres_connect(&config).await?;
-    info!("postgres connected");
+    // `AllForOne migrate [up|down|status|fresh]` 는 마이그레이션만 실행하고 종료
+    let args: Vec<String> = std::env::args().collect();
+    if args.get(1).map(String::as_str) == Some("migrate") {
+        run_migrate_command(&db, args.get(2).map(String::as_str)).await?;
+        info!("migration finished");
+        return Ok(());
+    }
+
+    if config.auto_migrate() {
+        Migrator::up(&db, None).await?;
+        info!("migrations applied");
+    }
 
     info!("server will be started");
-    api::server::server_start(config).await?;
+    api::server::server_start(config, db).await?;
     Ok(())
 }
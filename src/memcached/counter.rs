@@ -0,0 +1,28 @@
+use anyhow::{Context, Result};
+use async_memcached::{AsciiProtocol, Error, Status};
+use deadpool::managed::Pool;
+use deadpool_memcached::Manager;
+
+/// `ttl` 초 동안 유지되는 카운터를 1 올린 값. 키가 없으면 1 로 만듦
+/// memcached 의 incr 는 없는 키를 만들지 않아서 add 로 만들고, add 경쟁에서 지면 다시 incr
+pub async fn increment_window_counter(pool: &Pool<Manager>, key: &str, ttl: u64) -> Result<u64> {
+    let mut client = pool
+        .get()
+        .await
+        .context("fail to get memcached client from pool")?;
+
+    match client.increment(key, 1).await {
+        Ok(count) => return Ok(count),
+        Err(Error::Protocol(Status::NotFound)) => {}
+        Err(err) => return Err(err).context("fail to increment counter in memcached"),
+    }
+
+    match client.add(key, "1", Some(ttl as i64), None).await {
+        Ok(()) => Ok(1),
+        Err(Error::Protocol(Status::NotStored)) => client
+            .increment(key, 1)
+            .await
+            .context("fail to increment counter in memcached"),
+        Err(err) => Err(err).context("fail to add counter in memcached"),
+    }
+}
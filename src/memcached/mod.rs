@@ -1,6 +1,2 @@
 pub mod connect;
-pub mod repo;
-pub mod types;
-
-#[cfg(test)]
-mod types_tests;
+pub mod counter;
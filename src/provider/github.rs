@@ -7,12 +7,14 @@ use oauth2::{
 };
 use openidconnect::{ClientId, ClientSecret};
 use reqwest::redirect::Policy;
+use serde::de::DeserializeOwned;
 use url::Url;
 
 use crate::{
     provider::types::{
         config::{AuthRedirectInfo, Authentication, OAuthClientConfig},
-        idp_uid::GithubUid,
+        idp_uid::{GithubEmail, GithubUser},
+        profile::UserProfile,
     },
     utils::types::HTTP_REQUEST_USER_AGENT,
 };
@@ -62,33 +64,56 @@ impl GithubAuthenticator {
 }
 
 impl GithubAuthenticator {
-    pub async fn get_user_info(&self, access_token: String) -> Result<String> {
+    pub async fn get_user_profile(&self, access_token: String) -> Result<UserProfile> {
         let user_info_url = self.resource_url.join("user")?;
+        let user = self
+            .get_resource::<GithubUser>(user_info_url, &access_token)
+            .await
+            .context("fail to get user info")?;
+
+        let emails_url = self.resource_url.join("user/emails")?;
+        let email = self
+            .get_resource::<Vec<GithubEmail>>(emails_url, &access_token)
+            .await
+            .context("fail to get user emails")?
+            .into_iter()
+            .find(|email| email.primary && email.verified)
+            .map(|email| email.email);
+
+        Ok(UserProfile {
+            idp_uid: user.id.to_string(),
+            login: user.login,
+            display_name: user.name,
+            email,
+            avatar_url: user.avatar_url,
+        })
+    }
+
+    async fn get_resource<T: DeserializeOwned>(&self, url: Url, access_token: &str) -> Result<T> {
         let response = self
             .http_client
-            .get(user_info_url)
-            .bearer_auth(access_token.clone())
+            .get(url.clone())
+            .bearer_auth(access_token)
             .header(
                 reqwest::header::USER_AGENT,
                 HTTP_REQUEST_USER_AGENT.get().unwrap(),
             )
             .send()
             .await
-            .context("failed to send request to user info endpoint")?;
+            .with_context(|| format!("failed to send request to {}", url))?;
 
         if !response.status().is_success() {
             return Err(anyhow::anyhow!(
-                "Failed to fetch user info: {}",
+                "Failed to fetch {}: {}",
+                url,
                 response.status()
             ));
         }
 
-        let user = response
-            .json::<GithubUid>()
+        response
+            .json::<T>()
             .await
-            .context("fail to get user uid")?;
-
-        Ok(user.id.to_string())
+            .with_context(|| format!("fail to parse response from {}", url))
     }
 }
 
@@ -99,6 +124,7 @@ impl Authentication for GithubAuthenticator {
             .github_client
             .authorize_url(CsrfToken::new_random)
             .add_scope(Scope::new("read:user".to_string()))
+            .add_scope(Scope::new("user:email".to_string()))
             .set_pkce_challenge(pkce_challenge)
             .url();
 
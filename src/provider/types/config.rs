@@ -1,13 +1,6 @@
 use anyhow::Result;
 use url::Url;
 
-pub struct OIDCClientConfig {
-    pub client_id: String,
-    pub client_secret: String,
-    pub issuer_url: String,
-    pub redirect_uri: Url,
-}
-
 pub struct OAuthClientConfig {
     pub client_id: String,
     pub client_secret: String,
@@ -1,6 +1,19 @@
 use sonic_rs::Deserialize;
 
 #[derive(Deserialize)]
-pub struct GithubUid {
+pub struct GithubUser {
     pub id: i64,
+    #[serde(default)]
+    pub login: Option<String>,
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub struct GithubEmail {
+    pub email: String,
+    pub primary: bool,
+    pub verified: bool,
 }
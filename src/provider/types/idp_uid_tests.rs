@@ -1,39 +1,79 @@
 #[cfg(test)]
 mod tests {
-    use crate::provider::types::idp_uid::GithubUid;
+    use crate::provider::types::idp_uid::{GithubEmail, GithubUser};
 
     #[test]
     fn test_github_uid_deserialization() {
         let json = r#"{"id": 12345}"#;
-        let uid: GithubUid = sonic_rs::from_str(json).unwrap();
+        let uid: GithubUser = sonic_rs::from_str(json).unwrap();
         assert_eq!(uid.id, 12345);
     }
 
     #[test]
     fn test_github_uid_negative_id() {
         let json = r#"{"id": -1}"#;
-        let uid: GithubUid = sonic_rs::from_str(json).unwrap();
+        let uid: GithubUser = sonic_rs::from_str(json).unwrap();
         assert_eq!(uid.id, -1);
     }
 
     #[test]
     fn test_github_uid_zero_id() {
         let json = r#"{"id": 0}"#;
-        let uid: GithubUid = sonic_rs::from_str(json).unwrap();
+        let uid: GithubUser = sonic_rs::from_str(json).unwrap();
         assert_eq!(uid.id, 0);
     }
 
     #[test]
     fn test_github_uid_large_id() {
         let json = r#"{"id": 9223372036854775807}"#; // i64::MAX
-        let uid: GithubUid = sonic_rs::from_str(json).unwrap();
+        let uid: GithubUser = sonic_rs::from_str(json).unwrap();
         assert_eq!(uid.id, 9223372036854775807);
     }
 
     #[test]
     fn test_github_uid_invalid_json() {
         let json = r#"{"invalid": "field"}"#;
-        let result: Result<GithubUid, _> = sonic_rs::from_str(json);
+        let result: Result<GithubUser, _> = sonic_rs::from_str(json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_github_user_profile_fields() {
+        let json = r#"{
+            "id": 12345,
+            "login": "octocat",
+            "name": "The Octocat",
+            "avatar_url": "https://avatars.githubusercontent.com/u/12345"
+        }"#;
+        let user: GithubUser = sonic_rs::from_str(json).unwrap();
+        assert_eq!(user.id, 12345);
+        assert_eq!(user.login, Some("octocat".to_string()));
+        assert_eq!(user.name, Some("The Octocat".to_string()));
+        assert_eq!(
+            user.avatar_url,
+            Some("https://avatars.githubusercontent.com/u/12345".to_string())
+        );
+    }
+
+    #[test]
+    fn test_github_user_null_name() {
+        let json = r#"{"id": 1, "login": "octocat", "name": null}"#;
+        let user: GithubUser = sonic_rs::from_str(json).unwrap();
+        assert_eq!(user.name, None);
+        assert_eq!(user.avatar_url, None);
+    }
+
+    #[test]
+    fn test_github_emails_deserialization() {
+        let json = r#"[
+            {"email": "old@example.com", "primary": false, "verified": true, "visibility": null},
+            {"email": "octocat@example.com", "primary": true, "verified": true, "visibility": "private"}
+        ]"#;
+        let emails: Vec<GithubEmail> = sonic_rs::from_str(json).unwrap();
+        assert_eq!(emails.len(), 2);
+        assert!(!emails[0].primary);
+        assert!(emails[1].primary);
+        assert!(emails[1].verified);
+        assert_eq!(emails[1].email, "octocat@example.com");
+    }
 }
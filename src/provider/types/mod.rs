@@ -1,6 +1,7 @@
 pub mod config;
 pub mod idp;
 pub mod idp_uid;
+pub mod profile;
 
 #[cfg(test)]
 pub mod idp_tests;
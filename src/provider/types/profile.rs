@@ -0,0 +1,10 @@
+/// provider 별 응답을 정규화한 사용자 프로필
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserProfile {
+    pub idp_uid: String,
+    pub login: Option<String>,
+    pub display_name: Option<String>,
+    /// provider 가 검증한 primary email 만 저장
+    pub email: Option<String>,
+    pub avatar_url: Option<String>,
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+
+use anyhow::{Result, anyhow};
+use sea_orm::DatabaseConnection;
+
+use crate::{
+    api::state::types::metrics::Metrics,
+    config::types::Config,
+    memcached::connect::memcached_connect,
+    state_store::{
+        StateStore, database_store::DatabaseStateStore, memcached_store::MemcachedStateStore,
+        memory_store::MemoryStateStore, redis_store::RedisStateStore,
+    },
+};
+
+/// 설정된 backend 로 상태 저장소 생성. database backend 는 주 DB 연결을 같이 씀
+pub async fn state_store_connect(
+    config: &Config,
+    db: Arc<DatabaseConnection>,
+    metrics: &Metrics,
+) -> Result<Arc<dyn StateStore>> {
+    let state_store = &config.state_store;
+    let store: Arc<dyn StateStore> = match state_store.backend.as_str() {
+        "memcached" => {
+            let pool = memcached_connect(config)?;
+            metrics.track_memcached_pool("state_store", pool.clone());
+            Arc::new(MemcachedStateStore::new(pool))
+        }
+        "redis" => {
+            let redis_url = state_store
+                .redis_url
+                .as_deref()
+                .ok_or_else(|| anyhow!("redis_url is not configured"))?;
+            Arc::new(RedisStateStore::connect(redis_url).await?)
+        }
+        "database" => {
+            let store = Arc::new(DatabaseStateStore::new(db));
+            store.start_cleanup();
+            store
+        }
+        "memory" => Arc::new(MemoryStateStore::new()),
+        other => return Err(anyhow!("unknown state store backend: {other}")),
+    };
+
+    Ok(store)
+}
@@ -0,0 +1,158 @@
+use std::{sync::Arc, time::Duration};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, TransactionTrait,
+    sea_query::{Expr, OnConflict},
+};
+
+use tracing::warn;
+
+use crate::{entity::state_entries, state_store::StateStore};
+
+/// 만료된 항목을 지우는 주기. 만료된 값은 읽을 때 걸러지므로 늦게 지워져도 됨
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(300);
+
+/// `state_entries` 테이블에 보관. 메모리 캐시 없이 DB 만으로 운영할 때 사용
+pub struct DatabaseStateStore {
+    conn: Arc<DatabaseConnection>,
+}
+
+impl DatabaseStateStore {
+    pub fn new(conn: Arc<DatabaseConnection>) -> Self {
+        Self { conn }
+    }
+
+    pub async fn delete_expired(&self) -> Result<u64> {
+        let result = state_entries::Entity::delete_many()
+            .filter(state_entries::Column::ExpiresAt.lte(chrono::Utc::now()))
+            .exec(self.conn.as_ref())
+            .await
+            .context("fail to purge expired state")?;
+        Ok(result.rows_affected)
+    }
+
+    /// 쓰기마다 테이블 전체를 지우지 않도록 주기적으로 정리
+    pub fn start_cleanup(self: &Arc<Self>) {
+        let state_store = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(CLEANUP_INTERVAL);
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if let Err(err) = state_store.delete_expired().await {
+                    warn!("fail to delete expired state: {:#}", err);
+                }
+            }
+        });
+    }
+}
+
+#[async_trait]
+impl StateStore for DatabaseStateStore {
+    async fn set(&self, key: &str, value: String, ttl: u64) -> Result<()> {
+        let now = chrono::Utc::now();
+        let entry = state_entries::ActiveModel {
+            key: Set(key.to_string()),
+            value: Set(value),
+            expires_at: Set((now + chrono::Duration::seconds(ttl as i64)).into()),
+        };
+        state_entries::Entity::insert(entry)
+            .on_conflict(
+                OnConflict::column(state_entries::Column::Key)
+                    .update_columns([
+                        state_entries::Column::Value,
+                        state_entries::Column::ExpiresAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(self.conn.as_ref())
+            .await
+            .context("fail to set state in database")?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let entry = state_entries::Entity::find_by_id(key)
+            .filter(state_entries::Column::ExpiresAt.gt(chrono::Utc::now()))
+            .one(self.conn.as_ref())
+            .await
+            .context("fail to get state from database")?;
+        Ok(entry.map(|entry| entry.value))
+    }
+
+    /// 삭제된 행이 있을 때만 값을 돌려줘서 동시에 읽은 쪽 중 하나만 성공
+    async fn take(&self, key: &str) -> Result<Option<String>> {
+        let Some(value) = self.get(key).await? else {
+            return Ok(None);
+        };
+
+        let result = state_entries::Entity::delete_by_id(key)
+            .filter(state_entries::Column::ExpiresAt.gt(chrono::Utc::now()))
+            .exec(self.conn.as_ref())
+            .await
+            .context("fail to take state from database")?;
+        Ok((result.rows_affected == 1).then_some(value))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        state_entries::Entity::delete_by_id(key)
+            .exec(self.conn.as_ref())
+            .await
+            .context("fail to delete state from database")?;
+        Ok(())
+    }
+
+    /// 행을 만들고 올린 뒤 읽는 것을 한 트랜잭션에서 해서, UPDATE 의 행 잠금으로 동시 요청을 줄 세움
+    async fn increment(&self, key: &str, ttl: u64) -> Result<u64> {
+        let now = chrono::Utc::now();
+        let txn = self
+            .conn
+            .begin()
+            .await
+            .context("fail to begin state transaction")?;
+
+        state_entries::Entity::delete_many()
+            .filter(state_entries::Column::Key.eq(key))
+            .filter(state_entries::Column::ExpiresAt.lte(now))
+            .exec(&txn)
+            .await
+            .context("fail to purge expired counter")?;
+        let entry = state_entries::ActiveModel {
+            key: Set(key.to_string()),
+            value: Set("0".to_string()),
+            expires_at: Set((now + chrono::Duration::seconds(ttl as i64)).into()),
+        };
+        state_entries::Entity::insert(entry)
+            .on_conflict(
+                OnConflict::column(state_entries::Column::Key)
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .do_nothing()
+            .exec(&txn)
+            .await
+            .context("fail to create counter in database")?;
+        state_entries::Entity::update_many()
+            .col_expr(
+                state_entries::Column::Value,
+                Expr::cust("CAST(CAST(value AS INTEGER) + 1 AS TEXT)"),
+            )
+            .filter(state_entries::Column::Key.eq(key))
+            .exec(&txn)
+            .await
+            .context("fail to increment counter in database")?;
+        let entry = state_entries::Entity::find_by_id(key)
+            .one(&txn)
+            .await
+            .context("fail to get counter from database")?
+            .ok_or_else(|| anyhow::anyhow!("counter is not found after increment"))?;
+
+        txn.commit()
+            .await
+            .context("fail to commit state transaction")?;
+        entry.value.parse().context("counter is not a number")
+    }
+}
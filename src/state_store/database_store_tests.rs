@@ -0,0 +1,29 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::{
+        db::connect::memory_connect,
+        state_store::{StateStore, database_store::DatabaseStateStore},
+    };
+
+    #[tokio::test]
+    async fn test_database_store_hides_expired_entry_until_cleanup() {
+        let store = DatabaseStateStore::new(Arc::new(memory_connect().await));
+        store.set("expired", "value".to_string(), 0).await.unwrap();
+        store.set("live", "value".to_string(), 60).await.unwrap();
+
+        assert_eq!(store.get("expired").await.unwrap(), None);
+        assert_eq!(store.take("expired").await.unwrap(), None);
+        assert_eq!(store.delete_expired().await.unwrap(), 1);
+        assert_eq!(store.get("live").await.unwrap(), Some("value".to_string()));
+
+        // 만료된 키에 다시 쓰면 새 값과 만료 시각으로 덮어씀
+        store.set("expired", "again".to_string(), 0).await.unwrap();
+        store.set("expired", "again".to_string(), 60).await.unwrap();
+        assert_eq!(
+            store.get("expired").await.unwrap(),
+            Some("again".to_string())
+        );
+    }
+}
@@ -0,0 +1,93 @@
+use anyhow::{Context, Result};
+use async_memcached::{AsciiProtocol, Error, Status};
+use async_trait::async_trait;
+use deadpool::managed::Pool;
+use deadpool_memcached::Manager;
+
+use crate::{memcached::counter::increment_window_counter, state_store::StateStore};
+
+pub struct MemcachedStateStore {
+    pool: Pool<Manager>,
+}
+
+impl MemcachedStateStore {
+    pub fn new(pool: Pool<Manager>) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl StateStore for MemcachedStateStore {
+    async fn set(&self, key: &str, value: String, ttl: u64) -> Result<()> {
+        self.pool
+            .get()
+            .await
+            .context("fail to get memcached client from pool")?
+            .set(key, &value, Some(ttl as i64), None)
+            .await
+            .context("fail to set state in memcached")
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let result = self
+            .pool
+            .get()
+            .await
+            .context("fail to get memcached client from pool")?
+            .get(key)
+            .await
+            .context("fail to get state from memcached")?;
+
+        result
+            .map(|value| {
+                String::from_utf8(value.data.unwrap_or_default())
+                    .context("state is not valid utf-8")
+            })
+            .transpose()
+    }
+
+    /// memcached 에는 원자적 get-and-delete 가 없어서 delete 에 성공한 쪽만 값을 가져감
+    async fn take(&self, key: &str) -> Result<Option<String>> {
+        let mut client = self
+            .pool
+            .get()
+            .await
+            .context("fail to get memcached client from pool")?;
+        let Some(value) = client
+            .get(key)
+            .await
+            .context("fail to get state from memcached")?
+        else {
+            return Ok(None);
+        };
+
+        match client.delete(key).await {
+            Ok(()) => {}
+            Err(Error::Protocol(Status::NotFound)) => return Ok(None),
+            Err(err) => return Err(err).context("fail to delete state from memcached"),
+        }
+
+        String::from_utf8(value.data.unwrap_or_default())
+            .map(Some)
+            .context("state is not valid utf-8")
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let result = self
+            .pool
+            .get()
+            .await
+            .context("fail to get memcached client from pool")?
+            .delete(key)
+            .await;
+
+        match result {
+            Ok(()) | Err(Error::Protocol(Status::NotFound)) => Ok(()),
+            Err(err) => Err(err).context("fail to delete state from memcached"),
+        }
+    }
+
+    async fn increment(&self, key: &str, ttl: u64) -> Result<u64> {
+        increment_window_counter(&self.pool, key, ttl).await
+    }
+}
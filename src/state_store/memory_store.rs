@@ -0,0 +1,84 @@
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, Result, anyhow};
+use async_trait::async_trait;
+
+use crate::state_store::StateStore;
+
+/// 프로세스 안의 TTL 맵. 단일 노드와 테스트용
+#[derive(Default)]
+pub struct MemoryStateStore {
+    entries: Mutex<HashMap<String, (String, Instant)>>,
+}
+
+impl MemoryStateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl StateStore for MemoryStateStore {
+    async fn set(&self, key: &str, value: String, ttl: u64) -> Result<()> {
+        let now = Instant::now();
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| anyhow!("memory state store is poisoned"))?;
+        // 쓰기 때마다 만료된 항목 정리
+        entries.retain(|_, (_, expires_at)| *expires_at > now);
+        entries.insert(key.to_string(), (value, now + Duration::from_secs(ttl)));
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let entries = self
+            .entries
+            .lock()
+            .map_err(|_| anyhow!("memory state store is poisoned"))?;
+        Ok(entries
+            .get(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(value, _)| value.clone()))
+    }
+
+    async fn take(&self, key: &str) -> Result<Option<String>> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| anyhow!("memory state store is poisoned"))?;
+        Ok(entries
+            .remove(key)
+            .filter(|(_, expires_at)| *expires_at > Instant::now())
+            .map(|(value, _)| value))
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        self.entries
+            .lock()
+            .map_err(|_| anyhow!("memory state store is poisoned"))?
+            .remove(key);
+        Ok(())
+    }
+
+    async fn increment(&self, key: &str, ttl: u64) -> Result<u64> {
+        let now = Instant::now();
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|_| anyhow!("memory state store is poisoned"))?;
+        let (count, expires_at) = match entries.get(key) {
+            Some((value, expires_at)) if *expires_at > now => (
+                value.parse::<u64>().context("counter is not a number")? + 1,
+                *expires_at,
+            ),
+            _ => (1, now + Duration::from_secs(ttl)),
+        };
+        entries.insert(key.to_string(), (count.to_string(), expires_at));
+        Ok(count)
+    }
+}
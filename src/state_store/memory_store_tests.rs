@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::state_store::{StateStore, memory_store::MemoryStateStore};
+
+    #[tokio::test]
+    async fn test_memory_store_set_get_delete() {
+        let store = MemoryStateStore::new();
+        assert_eq!(store.get("key").await.unwrap(), None);
+
+        store.set("key", "value".to_string(), 60).await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), Some("value".to_string()));
+
+        store.set("key", "updated".to_string(), 60).await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), Some("updated".to_string()));
+
+        store.delete("key").await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_expired_entry_is_hidden() {
+        let store = MemoryStateStore::new();
+        store.set("key", "value".to_string(), 0).await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_memory_store_take_is_single_use() {
+        let store = MemoryStateStore::new();
+        store.set("key", "value".to_string(), 60).await.unwrap();
+
+        assert_eq!(store.take("key").await.unwrap(), Some("value".to_string()));
+        assert_eq!(store.take("key").await.unwrap(), None);
+        assert_eq!(store.get("key").await.unwrap(), None);
+    }
+}
@@ -0,0 +1,38 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+pub mod connect;
+pub mod database_store;
+pub mod memcached_store;
+pub mod memory_store;
+pub mod redis_store;
+pub mod repo;
+pub mod types;
+
+#[cfg(test)]
+mod database_store_tests;
+#[cfg(test)]
+mod memory_store_tests;
+#[cfg(test)]
+mod repo_tests;
+#[cfg(test)]
+mod types_tests;
+
+/// 로그인 흐름이 잠시 보관하는 상태(PKCE, CSRF, 연결 티켓)의 저장소
+#[async_trait]
+pub trait StateStore: Send + Sync {
+    /// `ttl` 초 뒤 만료되는 값을 저장. 같은 키가 있으면 덮어씀
+    async fn set(&self, key: &str, value: String, ttl: u64) -> Result<()>;
+
+    /// 만료되지 않은 값을 조회
+    async fn get(&self, key: &str) -> Result<Option<String>>;
+
+    /// 값을 읽으면서 지움. 동시에 여러 번 불려도 값은 한 번만 돌려줌
+    async fn take(&self, key: &str) -> Result<Option<String>>;
+
+    async fn delete(&self, key: &str) -> Result<()>;
+
+    /// 카운터를 원자적으로 1 올린 값. 없거나 만료됐으면 1 로 만들고 `ttl` 초 뒤 만료.
+    /// 올릴 때 만료 시각을 늘리지 않으므로 처음 만든 때부터 `ttl` 동안 셈
+    async fn increment(&self, key: &str, ttl: u64) -> Result<u64>;
+}
@@ -0,0 +1,67 @@
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use redis::{AsyncCommands, aio::ConnectionManager};
+
+use crate::state_store::StateStore;
+
+pub struct RedisStateStore {
+    conn: ConnectionManager,
+}
+
+impl RedisStateStore {
+    pub async fn connect(url: &str) -> Result<Self> {
+        let client = redis::Client::open(url).context("invalid redis url")?;
+        let conn = ConnectionManager::new(client)
+            .await
+            .context("fail to redis connection")?;
+        Ok(Self { conn })
+    }
+}
+
+#[async_trait]
+impl StateStore for RedisStateStore {
+    async fn set(&self, key: &str, value: String, ttl: u64) -> Result<()> {
+        let mut conn = self.conn.clone();
+        conn.set_ex::<_, _, ()>(key, value, ttl)
+            .await
+            .context("fail to set state in redis")
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<String>> {
+        let mut conn = self.conn.clone();
+        conn.get(key).await.context("fail to get state from redis")
+    }
+
+    async fn take(&self, key: &str) -> Result<Option<String>> {
+        let mut conn = self.conn.clone();
+        conn.get_del(key)
+            .await
+            .context("fail to take state from redis")
+    }
+
+    async fn delete(&self, key: &str) -> Result<()> {
+        let mut conn = self.conn.clone();
+        conn.del::<_, ()>(key)
+            .await
+            .context("fail to delete state from redis")
+    }
+
+    /// 만료 시각은 키를 만들 때만 정하도록 `SET NX EX` 와 `INCR` 을 한 트랜잭션으로 보냄
+    async fn increment(&self, key: &str, ttl: u64) -> Result<u64> {
+        let mut conn = self.conn.clone();
+        let (count,): (u64,) = redis::pipe()
+            .atomic()
+            .cmd("SET")
+            .arg(key)
+            .arg(0)
+            .arg("NX")
+            .arg("EX")
+            .arg(ttl)
+            .ignore()
+            .incr(key, 1)
+            .query_async(&mut conn)
+            .await
+            .context("fail to increment counter in redis")?;
+        Ok(count)
+    }
+}
@@ -0,0 +1,362 @@
+use anyhow::{Context, Result};
+use uuid::Uuid;
+
+use crate::{
+    state_store::{
+        StateStore,
+        types::{
+            AuthVerifyToken, AuthorizationCode, LockoutRecord, PendingIdentityLink, SsoSession,
+            StateConsumeError,
+        },
+    },
+    utils::token::hash_token,
+};
+
+/// OAuth `state` 로 키를 만들어서 쿠키가 없는 콜백에서도 찾을 수 있음
+pub async fn cache_auth_redirect_info_by_state(
+    store: &dyn StateStore,
+    state: &str,
+    body: &AuthVerifyToken,
+    cache_ttl: u64,
+) -> Result<()> {
+    let body = sonic_rs::to_string(body).context("fail to serialize AuthVerifyToken")?;
+
+    store
+        .set(&auth_redirect_info_key(state), body, cache_ttl)
+        .await
+        .context("fail to cache auth infomation by state")
+}
+
+/// 로그인 상태는 한 번만 꺼낼 수 있음
+pub async fn consume_auth_redirect_info_by_state(
+    store: &dyn StateStore,
+    state: &str,
+    cache_ttl: u64,
+) -> Result<AuthVerifyToken, StateConsumeError> {
+    let value = consume(store, &auth_redirect_info_key(state), cache_ttl).await?;
+    let auth_info = sonic_rs::from_str(&value)
+        .context("fail to parse AuthRedirectInfo from state store json")?;
+    Ok(auth_info)
+}
+
+pub async fn cache_authorization_code(
+    store: &dyn StateStore,
+    code: &str,
+    body: &AuthorizationCode,
+    code_ttl: u64,
+) -> Result<()> {
+    let body = sonic_rs::to_string(body).context("fail to serialize AuthorizationCode")?;
+
+    store
+        .set(&authorization_code_key(code), body, code_ttl)
+        .await
+        .context("fail to cache authorization code")
+}
+
+/// authorization code 도 한 번만 교환할 수 있음
+pub async fn consume_authorization_code(
+    store: &dyn StateStore,
+    code: &str,
+    code_ttl: u64,
+) -> Result<AuthorizationCode, StateConsumeError> {
+    let value = consume(store, &authorization_code_key(code), code_ttl).await?;
+    let authorization_code = sonic_rs::from_str(&value)
+        .context("fail to parse AuthorizationCode from state store json")?;
+    Ok(authorization_code)
+}
+
+pub async fn create_sso_session(
+    store: &dyn StateStore,
+    token: &str,
+    session: &SsoSession,
+    sso_ttl: u64,
+) -> Result<()> {
+    let body = sonic_rs::to_string(session).context("fail to serialize SsoSession")?;
+
+    store
+        .set(&sso_session_key(token), body, sso_ttl)
+        .await
+        .context("fail to create sso session")
+}
+
+pub async fn get_sso_session(store: &dyn StateStore, token: &str) -> Result<Option<SsoSession>> {
+    let result = store
+        .get(&sso_session_key(token))
+        .await
+        .context("fail to get sso session")?;
+
+    result
+        .map(|value| {
+            sonic_rs::from_str(&value).context("fail to parse SsoSession from state store json")
+        })
+        .transpose()
+}
+
+pub async fn delete_sso_session(store: &dyn StateStore, token: &str) -> Result<()> {
+    store
+        .delete(&sso_session_key(token))
+        .await
+        .context("fail to delete sso session")
+}
+
+pub async fn cache_pending_identity_link_by_ticket(
+    store: &dyn StateStore,
+    ticket: Uuid,
+    body: &PendingIdentityLink,
+    cache_ttl: u64,
+) -> Result<()> {
+    let body = sonic_rs::to_string(body).context("fail to serialize PendingIdentityLink")?;
+
+    store
+        .set(&pending_identity_link_key(ticket), body, cache_ttl)
+        .await
+        .context("fail to cache pending identity link by ticket")
+}
+
+pub async fn get_pending_identity_link_by_ticket(
+    store: &dyn StateStore,
+    ticket: Uuid,
+) -> Result<PendingIdentityLink> {
+    let result = store
+        .get(&pending_identity_link_key(ticket))
+        .await
+        .context("fail to get pending identity link by ticket")?;
+
+    match result {
+        Some(value) => {
+            let pending_link = sonic_rs::from_str(&value)
+                .context("fail to parse PendingIdentityLink from state store json")?;
+            Ok(pending_link)
+        }
+        None => Err(anyhow::anyhow!("No pending identity link found by ticket")),
+    }
+}
+
+pub async fn delete_pending_identity_link_by_ticket(
+    store: &dyn StateStore,
+    ticket: Uuid,
+) -> Result<()> {
+    store
+        .delete(&pending_identity_link_key(ticket))
+        .await
+        .context("fail to delete pending identity link by ticket")
+}
+
+/// 최대 실패 횟수를 넘어 잠긴 기록
+pub async fn get_lockout(store: &dyn StateStore, subject: &str) -> Result<Option<LockoutRecord>> {
+    get_lockout_record(store, &lockout_key(subject)).await
+}
+
+pub async fn save_lockout(
+    store: &dyn StateStore,
+    subject: &str,
+    record: &LockoutRecord,
+    ttl: u64,
+) -> Result<()> {
+    save_lockout_record(store, &lockout_key(subject), record, ttl).await
+}
+
+/// 잠금, 지연, 실패 횟수를 모두 지움
+pub async fn delete_lockout(store: &dyn StateStore, subject: &str) -> Result<()> {
+    for key in [
+        lockout_key(subject),
+        lockout_delay_key(subject),
+        lockout_failures_key(subject),
+    ] {
+        store.delete(&key).await.context("fail to delete lockout")?;
+    }
+    Ok(())
+}
+
+/// 잠기기 전의 점진적 지연. 잠금과 키를 나눠서 동시에 쓰여도 잠금을 덮어쓰지 않음
+pub async fn get_lockout_delay(
+    store: &dyn StateStore,
+    subject: &str,
+) -> Result<Option<LockoutRecord>> {
+    get_lockout_record(store, &lockout_delay_key(subject)).await
+}
+
+pub async fn save_lockout_delay(
+    store: &dyn StateStore,
+    subject: &str,
+    record: &LockoutRecord,
+    ttl: u64,
+) -> Result<()> {
+    save_lockout_record(store, &lockout_delay_key(subject), record, ttl).await
+}
+
+/// 실패 window 안의 실패 횟수를 원자적으로 1 올린 값
+pub async fn increment_lockout_failures(
+    store: &dyn StateStore,
+    subject: &str,
+    failure_window: u64,
+) -> Result<u64> {
+    store
+        .increment(&lockout_failures_key(subject), failure_window)
+        .await
+        .context("fail to increment lockout failures")
+}
+
+pub async fn get_lockout_failures(store: &dyn StateStore, subject: &str) -> Result<Option<u64>> {
+    let result = store
+        .get(&lockout_failures_key(subject))
+        .await
+        .context("fail to get lockout failures")?;
+
+    result
+        .map(|value| {
+            value
+                .trim()
+                .parse()
+                .context("lockout failures is not a number")
+        })
+        .transpose()
+}
+
+/// 저장소에서 키를 훑을 수 없어서 잠긴 대상은 realm 별 목록에 따로 모아둠.
+/// 목록은 `bucket` 마다 번호를 원자적으로 받아 칸 하나씩 채우므로 동시에 더해도 빠지지 않음
+pub async fn add_lockout_index_entry(
+    store: &dyn StateStore,
+    realm: &str,
+    bucket: i64,
+    subject: &str,
+    ttl: u64,
+) -> Result<()> {
+    let slot = store
+        .increment(&lockout_index_key(realm, bucket), ttl)
+        .await
+        .context("fail to add lockout index entry")?;
+
+    store
+        .set(
+            &lockout_index_slot_key(realm, bucket, slot),
+            subject.to_string(),
+            ttl,
+        )
+        .await
+        .context("fail to save lockout index entry")
+}
+
+pub async fn get_lockout_index(
+    store: &dyn StateStore,
+    realm: &str,
+    bucket: i64,
+) -> Result<Vec<String>> {
+    let Some(len) = store
+        .get(&lockout_index_key(realm, bucket))
+        .await
+        .context("fail to get lockout index")?
+    else {
+        return Ok(Vec::new());
+    };
+    let len: u64 = len
+        .trim()
+        .parse()
+        .context("lockout index length is not a number")?;
+
+    let mut subjects = Vec::new();
+    for slot in 1..=len {
+        if let Some(subject) = store
+            .get(&lockout_index_slot_key(realm, bucket, slot))
+            .await
+            .context("fail to get lockout index entry")?
+        {
+            subjects.push(subject);
+        }
+    }
+    Ok(subjects)
+}
+
+async fn get_lockout_record(store: &dyn StateStore, key: &str) -> Result<Option<LockoutRecord>> {
+    let result = store.get(key).await.context("fail to get lockout")?;
+
+    result
+        .map(|value| {
+            sonic_rs::from_str(&value).context("fail to parse LockoutRecord from state store json")
+        })
+        .transpose()
+}
+
+async fn save_lockout_record(
+    store: &dyn StateStore,
+    key: &str,
+    record: &LockoutRecord,
+    ttl: u64,
+) -> Result<()> {
+    let body = sonic_rs::to_string(record).context("fail to serialize LockoutRecord")?;
+
+    store
+        .set(key, body, ttl)
+        .await
+        .context("fail to save lockout")
+}
+
+/// 추측할 수 없는 값이지만 그대로 키로 쓰지 않고 해시해서 저장
+fn auth_redirect_info_key(state: &str) -> String {
+    format!("auth:{}", hash_token(state))
+}
+
+/// 값을 꺼낸 뒤에는 표식을 남겨 재사용과 만료를 구분
+async fn consume(
+    store: &dyn StateStore,
+    key: &str,
+    marker_ttl: u64,
+) -> Result<String, StateConsumeError> {
+    let consumed_key = consumed_key(key);
+    let result = store.take(key).await.context("fail to take state")?;
+
+    match result {
+        Some(value) => {
+            store
+                .set(&consumed_key, "1".to_string(), marker_ttl)
+                .await
+                .context("fail to mark state as consumed")?;
+            Ok(value)
+        }
+        None => match store
+            .get(&consumed_key)
+            .await
+            .context("fail to get consumed marker")?
+        {
+            Some(_) => Err(StateConsumeError::Replayed),
+            None => Err(StateConsumeError::NotFound),
+        },
+    }
+}
+
+fn authorization_code_key(code: &str) -> String {
+    format!("code:{}", hash_token(code))
+}
+
+fn sso_session_key(token: &str) -> String {
+    format!("sso:{}", hash_token(token))
+}
+
+/// 대상에 요청 값(client_id)이 들어가서 memcached 키로 쓸 수 있게 해시
+fn lockout_key(subject: &str) -> String {
+    format!("lockout:{}", hash_token(subject))
+}
+
+fn lockout_delay_key(subject: &str) -> String {
+    format!("lockout_delay:{}", hash_token(subject))
+}
+
+fn lockout_failures_key(subject: &str) -> String {
+    format!("lockout_failures:{}", hash_token(subject))
+}
+
+fn lockout_index_key(realm: &str, bucket: i64) -> String {
+    format!("lockout_index:{}:{}", hash_token(realm), bucket)
+}
+
+fn lockout_index_slot_key(realm: &str, bucket: i64, slot: u64) -> String {
+    format!("lockout_index:{}:{}:{}", hash_token(realm), bucket, slot)
+}
+
+fn consumed_key(key: &str) -> String {
+    format!("used:{}", key)
+}
+
+fn pending_identity_link_key(ticket: Uuid) -> String {
+    format!("link:{}", ticket)
+}
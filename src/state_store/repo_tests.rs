@@ -0,0 +1,196 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use uuid::Uuid;
+
+    use crate::{
+        db::connect::memory_connect,
+        provider::types::idp::OAuthProvider,
+        state_store::{
+            StateStore,
+            database_store::DatabaseStateStore,
+            memory_store::MemoryStateStore,
+            repo::{
+                cache_auth_redirect_info_by_state, cache_authorization_code,
+                cache_pending_identity_link_by_ticket, consume_auth_redirect_info_by_state,
+                consume_authorization_code, create_sso_session,
+                delete_pending_identity_link_by_ticket, delete_sso_session,
+                get_pending_identity_link_by_ticket, get_sso_session,
+            },
+            types::{
+                AuthVerifyToken, AuthorizationCode, AuthorizationRequest, PendingIdentityLink,
+                SsoSession, StateConsumeError,
+            },
+        },
+    };
+
+    async fn assert_login_state_roundtrip(store: &dyn StateStore) {
+        let state = "test-csrf-token";
+        let token = AuthVerifyToken {
+            csrf_token: "test-csrf-token".to_string(),
+            pkce_verifier: "test-pkce-verifier".to_string(),
+            nonce: None,
+            link_user_id: None,
+            session_id: None,
+            authorization: None,
+        };
+        cache_auth_redirect_info_by_state(store, state, &token, 60)
+            .await
+            .unwrap();
+
+        let cached = consume_auth_redirect_info_by_state(store, state, 60)
+            .await
+            .unwrap();
+        assert_eq!(cached.csrf_token, "test-csrf-token");
+        assert_eq!(cached.pkce_verifier, "test-pkce-verifier");
+        assert!(matches!(
+            consume_auth_redirect_info_by_state(store, state, 60).await,
+            Err(StateConsumeError::Replayed)
+        ));
+        assert!(matches!(
+            consume_auth_redirect_info_by_state(store, "unknown-state", 60).await,
+            Err(StateConsumeError::NotFound)
+        ));
+
+        let ticket = Uuid::now_v7();
+        let user_id = Uuid::now_v7();
+        let pending_link = PendingIdentityLink {
+            user_id,
+            idp: OAuthProvider::Github,
+            idp_uid: "12345".to_string(),
+        };
+        cache_pending_identity_link_by_ticket(store, ticket, &pending_link, 60)
+            .await
+            .unwrap();
+        let cached = get_pending_identity_link_by_ticket(store, ticket)
+            .await
+            .unwrap();
+        assert_eq!(cached.user_id, user_id);
+
+        delete_pending_identity_link_by_ticket(store, ticket)
+            .await
+            .unwrap();
+        assert!(
+            get_pending_identity_link_by_ticket(store, ticket)
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_state_roundtrip_memory_store() {
+        assert_login_state_roundtrip(&MemoryStateStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn test_login_state_roundtrip_database_store() {
+        let db = Arc::new(memory_connect().await);
+        assert_login_state_roundtrip(&DatabaseStateStore::new(db)).await;
+    }
+
+    #[tokio::test]
+    async fn test_database_store_overwrites_and_expires() {
+        let store = DatabaseStateStore::new(Arc::new(memory_connect().await));
+
+        store.set("key", "value".to_string(), 60).await.unwrap();
+        store.set("key", "updated".to_string(), 60).await.unwrap();
+        assert_eq!(store.get("key").await.unwrap(), Some("updated".to_string()));
+
+        store.set("expired", "value".to_string(), 0).await.unwrap();
+        assert_eq!(store.get("expired").await.unwrap(), None);
+        assert_eq!(store.take("expired").await.unwrap(), None);
+
+        assert_eq!(
+            store.take("key").await.unwrap(),
+            Some("updated".to_string())
+        );
+        assert_eq!(store.take("key").await.unwrap(), None);
+    }
+
+    async fn assert_counter_increments(store: &dyn StateStore) {
+        assert_eq!(store.increment("counter", 60).await.unwrap(), 1);
+        assert_eq!(store.increment("counter", 60).await.unwrap(), 2);
+        assert_eq!(store.increment("counter", 60).await.unwrap(), 3);
+
+        // 만료된 카운터는 다시 1 부터
+        assert_eq!(store.increment("expired", 0).await.unwrap(), 1);
+        assert_eq!(store.increment("expired", 0).await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_counter_increments_memory_store() {
+        assert_counter_increments(&MemoryStateStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn test_counter_increments_database_store() {
+        let db = Arc::new(memory_connect().await);
+        assert_counter_increments(&DatabaseStateStore::new(db)).await;
+    }
+
+    #[tokio::test]
+    async fn test_sso_session_roundtrip() {
+        let store = MemoryStateStore::new();
+        let sso_session = SsoSession {
+            sid: Uuid::now_v7(),
+            user_id: Uuid::now_v7(),
+            idp: OAuthProvider::Github,
+            auth_time: 1700000000,
+        };
+        create_sso_session(&store, "sso-token", &sso_session, 60)
+            .await
+            .unwrap();
+
+        let cached = get_sso_session(&store, "sso-token").await.unwrap().unwrap();
+        assert_eq!(cached.sid, sso_session.sid);
+        assert_eq!(cached.auth_time, 1700000000);
+        assert!(
+            get_sso_session(&store, "other-token")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        delete_sso_session(&store, "sso-token").await.unwrap();
+        assert!(
+            get_sso_session(&store, "sso-token")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_authorization_code_is_consumed_once() {
+        let store = MemoryStateStore::new();
+        let code = AuthorizationCode {
+            realm: "default".to_string(),
+            request: AuthorizationRequest {
+                client_id: "web".to_string(),
+                redirect_uri: "https://app.example.com/callback".to_string(),
+                scope: Some("openid".to_string()),
+                state: None,
+                nonce: Some("nonce".to_string()),
+                code_challenge: "challenge".to_string(),
+            },
+            user_id: Uuid::now_v7(),
+            idp: OAuthProvider::Github,
+            sid: Uuid::now_v7(),
+            auth_time: 1700000000,
+        };
+        cache_authorization_code(&store, "code", &code, 60)
+            .await
+            .unwrap();
+
+        let cached = consume_authorization_code(&store, "code", 60)
+            .await
+            .unwrap();
+        assert_eq!(cached.request.client_id, "web");
+        assert_eq!(cached.user_id, code.user_id);
+        assert!(matches!(
+            consume_authorization_code(&store, "code", 60).await,
+            Err(StateConsumeError::Replayed)
+        ));
+    }
+}
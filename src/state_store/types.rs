@@ -0,0 +1,84 @@
+use sonic_rs::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::provider::types::idp::OAuthProvider;
+
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthVerifyToken {
+    pub csrf_token: String,
+    pub pkce_verifier: String,
+    pub nonce: Option<String>,
+    /// 계정 연결 요청일 때 identity 를 붙일 사용자
+    #[serde(default)]
+    pub link_user_id: Option<Uuid>,
+    /// 로그인을 시작한 브라우저의 세션 쿠키. 콜백에 쿠키가 있으면 같아야 함
+    #[serde(default)]
+    pub session_id: Option<Uuid>,
+    /// `/oauth/authorize` 에서 시작한 로그인이면 끝난 뒤 클라이언트로 돌려보낼 요청
+    #[serde(default)]
+    pub authorization: Option<AuthorizationRequest>,
+}
+
+/// 클라이언트의 authorize 요청 중 code 발급과 교환에 필요한 값
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AuthorizationRequest {
+    pub client_id: String,
+    pub redirect_uri: String,
+    pub scope: Option<String>,
+    pub state: Option<String>,
+    pub nonce: Option<String>,
+    pub code_challenge: String,
+}
+
+/// 토큰으로 한 번 교환할 수 있는 authorization code
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AuthorizationCode {
+    /// code 를 발급한 realm. 다른 realm 의 토큰 엔드포인트에서는 교환할 수 없음
+    #[serde(default)]
+    pub realm: String,
+    pub request: AuthorizationRequest,
+    pub user_id: Uuid,
+    pub idp: OAuthProvider,
+    pub sid: Uuid,
+    pub auth_time: i64,
+}
+
+/// 로그인한 브라우저의 SSO 세션. 쿠키 값의 해시로 찾음
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SsoSession {
+    /// id token 의 `sid`
+    pub sid: Uuid,
+    pub user_id: Uuid,
+    pub idp: OAuthProvider,
+    /// provider 로 마지막으로 로그인한 시각(unix)
+    pub auth_time: i64,
+}
+
+/// 확인을 기다리는 email 기반 계정 연결 요청
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PendingIdentityLink {
+    pub user_id: Uuid,
+    pub idp: OAuthProvider,
+    pub idp_uid: String,
+}
+
+/// 계정이나 클라이언트의 최근 인증 실패
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct LockoutRecord {
+    pub failures: u32,
+    /// 이 시각(unix) 전까지는 시도를 받지 않음
+    pub blocked_until: i64,
+    /// 지연이 아니라 최대 실패 횟수를 넘어 잠긴 상태
+    pub locked: bool,
+}
+
+/// 한 번만 쓸 수 있는 상태를 꺼낼 때의 실패
+#[derive(thiserror::Error, Debug)]
+pub enum StateConsumeError {
+    #[error("state is not found or expired")]
+    NotFound,
+    #[error("state is already used")]
+    Replayed,
+    #[error(transparent)]
+    Store(#[from] anyhow::Error),
+}
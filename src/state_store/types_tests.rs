@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use crate::memcached::types::AuthVerifyToken;
+    use crate::{
+        provider::types::idp::OAuthProvider,
+        state_store::types::{AuthVerifyToken, PendingIdentityLink},
+    };
 
     #[test]
     fn test_auth_verify_token_serialization() {
@@ -8,6 +11,9 @@ mod tests {
             csrf_token: "test-csrf-token".to_string(),
             pkce_verifier: "test-pkce-verifier".to_string(),
             nonce: Some("test-nonce".to_string()),
+            link_user_id: None,
+            session_id: None,
+            authorization: None,
         };
 
         let serialized = sonic_rs::to_string(&token).unwrap();
@@ -50,6 +56,9 @@ mod tests {
             csrf_token: "test-csrf-token".to_string(),
             pkce_verifier: "test-pkce-verifier".to_string(),
             nonce: Some("test-nonce".to_string()),
+            link_user_id: None,
+            session_id: None,
+            authorization: None,
         };
 
         let debug_str = format!("{:?}", token);
@@ -58,6 +67,36 @@ mod tests {
         assert!(debug_str.contains("test-nonce"));
     }
 
+    #[test]
+    fn test_auth_verify_token_link_user_id() {
+        let user_id = uuid::Uuid::now_v7();
+        let token = AuthVerifyToken {
+            csrf_token: "test-csrf-token".to_string(),
+            pkce_verifier: "test-pkce-verifier".to_string(),
+            nonce: None,
+            link_user_id: Some(user_id),
+            session_id: None,
+            authorization: None,
+        };
+
+        let serialized = sonic_rs::to_string(&token).unwrap();
+        let deserialized: AuthVerifyToken = sonic_rs::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.link_user_id, Some(user_id));
+    }
+
+    #[test]
+    fn test_auth_verify_token_without_link_user_id() {
+        let json = r#"{
+            "csrf_token": "test-csrf-token",
+            "pkce_verifier": "test-pkce-verifier",
+            "nonce": null
+        }"#;
+
+        let token: AuthVerifyToken = sonic_rs::from_str(json).unwrap();
+        assert_eq!(token.link_user_id, None);
+        assert_eq!(token.session_id, None);
+    }
+
     #[test]
     fn test_auth_verify_token_missing_fields() {
         let json = r#"{
@@ -67,4 +106,22 @@ mod tests {
         let result: Result<AuthVerifyToken, _> = sonic_rs::from_str(json);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_pending_identity_link_roundtrip() {
+        let user_id = uuid::Uuid::now_v7();
+        let pending_link = PendingIdentityLink {
+            user_id,
+            idp: OAuthProvider::Github,
+            idp_uid: "12345".to_string(),
+        };
+
+        let serialized = sonic_rs::to_string(&pending_link).unwrap();
+        assert!(serialized.contains("\"github\""));
+
+        let deserialized: PendingIdentityLink = sonic_rs::from_str(&serialized).unwrap();
+        assert_eq!(deserialized.user_id, user_id);
+        assert_eq!(deserialized.idp, OAuthProvider::Github);
+        assert_eq!(deserialized.idp_uid, "12345");
+    }
 }
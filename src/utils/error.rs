@@ -1,4 +1,4 @@
-use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
+use axum::extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection};
 
 #[derive(thiserror::Error, Debug)]
 pub enum AllForOneError {
@@ -8,13 +8,55 @@ pub enum AllForOneError {
     Query(#[from] QueryRejection),
     #[error("json extraction error")]
     Json(#[from] JsonRejection),
+    #[error("form extraction error")]
+    Form(#[from] FormRejection),
+
+    #[error("bad request error")]
+    BadRequest(String),
 
     #[error("auth error")]
     Auth(String),
 
+    #[error("forbidden error")]
+    Forbidden(String),
+
+    #[error("not found error")]
+    NotFound(String),
+
+    #[error("conflict error")]
+    Conflict(String),
+
+    /// 이미 사용된 일회성 상태가 다시 제출됨
+    #[error("replay error")]
+    Replay(String),
+
+    /// 요청 한도를 넘음. 값은 다시 시도할 수 있을 때까지의 초
+    #[error("too many requests")]
+    TooManyRequests(u64),
+
     #[error("database error")]
     Db(#[from] sea_orm::DbErr),
 
     #[error("internal error")]
     Internal(#[from] anyhow::Error),
 }
+
+impl AllForOneError {
+    /// 감사 로그에 남길 실패 사유. Display 는 응답에 쓰는 일반적인 문구라서 안쪽 메시지를 돌려줌
+    /// 저장소 오류나 내부 오류는 세부 내용을 남기지 않음
+    pub fn reason(&self) -> String {
+        match self {
+            Self::Path(rejection) => rejection.body_text(),
+            Self::Query(rejection) => rejection.body_text(),
+            Self::Json(rejection) => rejection.body_text(),
+            Self::Form(rejection) => rejection.body_text(),
+            Self::BadRequest(message)
+            | Self::Auth(message)
+            | Self::Forbidden(message)
+            | Self::NotFound(message)
+            | Self::Conflict(message)
+            | Self::Replay(message) => message.clone(),
+            Self::TooManyRequests(_) | Self::Db(_) | Self::Internal(_) => self.to_string(),
+        }
+    }
+}
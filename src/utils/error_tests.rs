@@ -22,6 +22,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_conversion_conflict() {
+        let error = AllForOneError::Conflict("identity is already linked".to_string());
+        assert_eq!(error.to_string(), "conflict error");
+        match error {
+            AllForOneError::Conflict(msg) => assert_eq!(msg, "identity is already linked"),
+            _ => panic!("Expected Conflict error"),
+        }
+    }
+
+    #[test]
+    fn test_error_conversion_bad_request() {
+        let error = AllForOneError::BadRequest("invalid lookup".to_string());
+        assert_eq!(error.to_string(), "bad request error");
+    }
+
+    #[test]
+    fn test_error_conversion_forbidden() {
+        let error = AllForOneError::Forbidden("account is inactive".to_string());
+        assert_eq!(error.to_string(), "forbidden error");
+        match error {
+            AllForOneError::Forbidden(msg) => assert_eq!(msg, "account is inactive"),
+            _ => panic!("Expected Forbidden error"),
+        }
+    }
+
     #[test]
     fn test_error_conversion_internal() {
         let internal_error = anyhow::anyhow!("internal server error");
@@ -66,4 +92,16 @@ mod tests {
             _ => panic!("Expected Internal error"),
         }
     }
+
+    #[test]
+    fn test_error_reason_keeps_inner_message() {
+        let error = AllForOneError::Auth("authorization code is not found".to_string());
+        assert_eq!(error.reason(), "authorization code is not found");
+
+        let error = AllForOneError::TooManyRequests(30);
+        assert_eq!(error.reason(), "too many requests");
+
+        let error = AllForOneError::from(anyhow::anyhow!("connection refused"));
+        assert_eq!(error.reason(), "internal error");
+    }
 }
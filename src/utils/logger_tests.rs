@@ -108,11 +108,12 @@ mod tests {
                 domain: "http://127.0.0.1".to_string(),
                 port: 3000,
                 user_agent: "test".to_string(),
+                trusted_proxies: vec![],
             },
             logger: LoggerConfig {
                 level: level.to_string(),
             },
-            postgres: crate::config::types::PostgresConfig {
+            postgres: Some(crate::config::types::PostgresConfig {
                 connect_info: crate::config::types::PostgresConnectConfig {
                     address: "127.0.0.1".to_string(),
                     port: 5432,
@@ -129,8 +130,10 @@ mod tests {
                     max_lifetime: 8,
                     sqlx_logging: true,
                     log_level: "debug".to_string(),
+                    auto_migrate: false,
                 },
-            },
+            }),
+            sqlite: None,
             memcached: crate::config::types::MemCachedConfig {
                 connect_info: crate::config::types::MemeCachedConnectConfig {
                     address: "127.0.0.1".to_string(),
@@ -143,6 +146,7 @@ mod tests {
                     write_timeout: 60,
                 },
             },
+            state_store: crate::config::types::StateStoreConfig::default(),
             jwks: crate::config::types::JwksConfig {
                 iss: "https://auth.example.com".to_string(),
                 aud: "AllForOne-Project-Service".to_string(),
@@ -158,6 +162,7 @@ mod tests {
                     resource_url: "https://api.github.com".to_string(),
                     auth_url: "https://github.com/login/oauth/authorize".to_string(),
                     token_url: "https://github.com/login/oauth/access_token".to_string(),
+                    email_verified: true,
                 },
             },
             security: crate::config::types::SecurityConfig {
@@ -173,12 +178,15 @@ mod tests {
                     secure_cookies: true,
                     same_site: "Lax".to_string(),
                     http_only: true,
+                    sso_ttl: 28800,
                 },
                 rate_limiting: crate::config::types::RateLimitingConfig {
                     enabled: true,
                     requests_per_minute: 60,
                     burst_size: 10,
                     cleanup_interval: 300,
+                    backend: "local".to_string(),
+                    route_groups: vec![],
                 },
                 cors: crate::config::types::CorsConfig {
                     enabled: true,
@@ -186,6 +194,8 @@ mod tests {
                     allowed_methods: vec!["GET".to_string(), "POST".to_string()],
                     allowed_headers: vec!["Content-Type".to_string(), "Authorization".to_string()],
                     max_age: 3600,
+                    allow_credentials: false,
+                    excluded_paths: vec![],
                 },
                 security_headers: crate::config::types::SecurityHeadersConfig {
                     hsts_enabled: true,
@@ -195,7 +205,16 @@ mod tests {
                     x_frame_options: "DENY".to_string(),
                     x_content_type_options: true,
                 },
+                account_linking: crate::config::types::AccountLinkingSecurityConfig {
+                    policy: "disabled".to_string(),
+                },
+                lockout: crate::config::types::LockoutSecurityConfig::default(),
             },
+            admin: crate::config::types::AdminConfig::default(),
+            realms: vec![],
+            clients: vec![],
+            webhooks: crate::config::types::WebhooksConfig::default(),
+            metrics: crate::config::types::MetricsConfig::default(),
         }
     }
 }
@@ -1,5 +1,6 @@
 pub mod error;
 pub mod logger;
+pub mod token;
 pub mod types;
 
 #[cfg(test)]
@@ -7,3 +8,6 @@ mod error_tests;
 
 #[cfg(test)]
 mod logger_tests;
+
+#[cfg(test)]
+mod token_tests;
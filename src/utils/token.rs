@@ -0,0 +1,20 @@
+use anyhow::{Result, anyhow};
+use base64::{Engine, prelude::BASE64_URL_SAFE_NO_PAD};
+use ring::rand::{SecureRandom, SystemRandom};
+
+const OPAQUE_TOKEN_BYTES: usize = 32;
+
+/// refresh token 처럼 서버에만 의미가 있는 불투명 토큰 생성
+pub fn generate_opaque_token() -> Result<String> {
+    let mut buf = [0u8; OPAQUE_TOKEN_BYTES];
+    SystemRandom::new()
+        .fill(&mut buf)
+        .map_err(|_| anyhow!("fail to generate random token"))?;
+    Ok(BASE64_URL_SAFE_NO_PAD.encode(buf))
+}
+
+/// 원본 토큰 대신 저장할 SHA-256 해시
+pub fn hash_token(token: &str) -> String {
+    let digest = ring::digest::digest(&ring::digest::SHA256, token.as_bytes());
+    BASE64_URL_SAFE_NO_PAD.encode(digest.as_ref())
+}
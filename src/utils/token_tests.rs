@@ -0,0 +1,35 @@
+#[cfg(test)]
+mod tests {
+    use crate::utils::token::{generate_opaque_token, hash_token};
+
+    #[test]
+    fn test_generate_opaque_token_is_unique() {
+        let first = generate_opaque_token().unwrap();
+        let second = generate_opaque_token().unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_generate_opaque_token_is_url_safe() {
+        let token = generate_opaque_token().unwrap();
+        assert_eq!(token.len(), 43);
+        assert!(
+            token
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        );
+    }
+
+    #[test]
+    fn test_hash_token_is_deterministic() {
+        assert_eq!(hash_token("refresh-token"), hash_token("refresh-token"));
+        assert_ne!(hash_token("refresh-token"), hash_token("other-token"));
+    }
+
+    #[test]
+    fn test_hash_token_does_not_contain_token() {
+        let hashed = hash_token("refresh-token");
+        assert!(!hashed.contains("refresh-token"));
+        assert_eq!(hashed.len(), 43);
+    }
+}